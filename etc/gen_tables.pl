@@ -0,0 +1,163 @@
+#!/usr/bin/env perl
+#
+# Generates `src/tables.rs` from the Unicode Character Database bundled with
+# Perl.  Run from the root of the repository:
+#
+#     perl etc/gen_tables.pl > src/tables.rs
+#
+use strict;
+use warnings;
+use feature qw(fc say unicode_strings);
+use Unicode::UCD;
+
+sub esc {
+	my $cp = shift;
+	return sprintf("'\\u{%x}'", $cp);
+}
+
+sub esc_str {
+	my $s = shift;
+	return '"' . join('', map { sprintf("\\u{%x}", ord $_) } split //, $s) . '"';
+}
+
+sub code_points {
+	return grep { $_ < 0xd800 || $_ > 0xdfff } (0 .. 0x10ffff);
+}
+
+# Emits a table of `(start, end, Enum::Value)` ranges for a property, omitting code points whose value is `Other`.
+sub emit_property_table {
+	my ($name, $enum, $prop, @values) = @_;
+	my @regexes = map { my $v = $_->[1]; [$_->[0], qr/\p{$prop=$v}/] } @values;
+
+	say "pub static $name: &'static [(char, char, $enum)] = &[";
+	my ($start, $prev, $cur_val);
+	for my $cp (code_points(), -1) {
+		my $val;
+		if ($cp >= 0) {
+			my $ch = chr $cp;
+			for my $re (@regexes) {
+				if ($ch =~ $re->[1]) {
+					$val = $re->[0];
+					last;
+				}
+			}
+		}
+		if (defined $val && defined $cur_val && $val eq $cur_val && $cp == $prev + 1) {
+			$prev = $cp;
+			next;
+		}
+		if (defined $cur_val) {
+			say "\t(" . esc($start) . ", " . esc($prev) . ", ${enum}::$cur_val),";
+		}
+		($start, $prev, $cur_val) = ($cp, $cp, $val);
+	}
+	say "];";
+}
+
+sub emit_ranges {
+	my ($name, $re) = @_;
+	say "pub static $name: &'static [(char, char)] = &[";
+	my $start;
+	my $prev;
+	for my $cp (code_points(), -1) {
+		my $matches = $cp >= 0 && chr($cp) =~ $re;
+		if ($matches && defined $start && $cp == $prev + 1) {
+			$prev = $cp;
+			next;
+		}
+		if (defined $start) {
+			say "\t(" . esc($start) . ", " . esc($prev) . "),";
+			undef $start;
+		}
+		if ($matches) {
+			$start = $prev = $cp;
+		}
+	}
+	say "];";
+}
+
+say "// This file is generated by `etc/gen_tables.pl`; do not edit it by hand.";
+say "//";
+say "// Unicode version: " . Unicode::UCD::UnicodeVersion() . ".";
+say "";
+say "#![allow(dead_code)]";
+
+# Full case folding (status `C` and `F` in CaseFolding.txt).
+say "";
+say "/**";
+say "Full case folding mappings; that is, those with status `C` or `F` in `CaseFolding.txt`.  Sorted by code point.";
+say "*/";
+say "pub static CASE_FOLDING: &'static [(char, &'static str)] = &[";
+for my $cp (code_points()) {
+	my $ch = chr $cp;
+	my $folded = fc $ch;
+	next if $folded eq $ch;
+	say "\t(" . esc($cp) . ", " . esc_str($folded) . "),";
+}
+say "];";
+
+# Turkic case folding (status `T` in CaseFolding.txt).  There are only two of these, and they are not exposed by Perl.
+say "";
+say "/**";
+say "Turkic-specific case folding mappings; that is, those with status `T` in `CaseFolding.txt`.  These take precedence over `CASE_FOLDING` when Turkic folding is requested.";
+say "*/";
+say "pub static TURKIC_CASE_FOLDING: &'static [(char, &'static str)] = &[";
+say "\t(" . esc(0x49) . ", " . esc_str("\x{131}") . "),";
+say "\t(" . esc(0x130) . ", " . esc_str("i") . "),";
+say "];";
+
+# Non-spacing and enclosing combining marks (general categories `Mn` and `Me`).
+say "";
+say "/**";
+say "Ranges of code points with the general category `Mn` (non-spacing mark) or `Me` (enclosing mark).  Sorted, non-overlapping and inclusive.";
+say "*/";
+emit_ranges("COMBINING_MARKS", qr/[\p{Mn}\p{Me}]/);
+
+# Grapheme cluster break property.
+say "";
+say "/**";
+say "Values of the `Grapheme_Cluster_Break` property, other than `Other`.";
+say "*/";
+say "#[derive(Clone, Copy, Eq, PartialEq, Show)]";
+say "pub enum GraphemeCat {";
+my @gcb = (
+	['CR', 'CR'], ['LF', 'LF'], ['Control', 'Control'], ['Extend', 'Extend'], ['ZWJ', 'ZWJ'],
+	['RegionalIndicator', 'Regional_Indicator'], ['Prepend', 'Prepend'], ['SpacingMark', 'SpacingMark'],
+	['L', 'L'], ['V', 'V'], ['T', 'T'], ['LV', 'LV'], ['LVT', 'LVT'],
+);
+say "\t$_->[0]," for @gcb;
+say "}";
+say "";
+say "/**";
+say "Ranges of code points and their `Grapheme_Cluster_Break` property.  Code points which do not appear have the value `Other`.  Sorted, non-overlapping and inclusive.";
+say "*/";
+emit_property_table("GRAPHEME_CLUSTER_BREAK", "GraphemeCat", "GCB", @gcb);
+
+say "";
+say "/**";
+say "Ranges of code points with the `Extended_Pictographic` property.  Sorted, non-overlapping and inclusive.";
+say "*/";
+emit_ranges("EXTENDED_PICTOGRAPHIC", qr/\p{Extended_Pictographic}/);
+
+# Word break property.
+say "";
+say "/**";
+say "Values of the `Word_Break` property, other than `Other`.";
+say "*/";
+say "#[derive(Clone, Copy, Eq, PartialEq, Show)]";
+say "pub enum WordCat {";
+my @wb = (
+	['CR', 'CR'], ['LF', 'LF'], ['Newline', 'Newline'], ['Extend', 'Extend'], ['ZWJ', 'ZWJ'],
+	['RegionalIndicator', 'Regional_Indicator'], ['Format', 'Format'], ['Katakana', 'Katakana'],
+	['HebrewLetter', 'Hebrew_Letter'], ['ALetter', 'ALetter'], ['SingleQuote', 'Single_Quote'],
+	['DoubleQuote', 'Double_Quote'], ['MidNumLet', 'MidNumLet'], ['MidLetter', 'MidLetter'],
+	['MidNum', 'MidNum'], ['Numeric', 'Numeric'], ['ExtendNumLet', 'ExtendNumLet'],
+	['WSegSpace', 'WSegSpace'],
+);
+say "\t$_->[0]," for @wb;
+say "}";
+say "";
+say "/**";
+say "Ranges of code points and their `Word_Break` property.  Code points which do not appear have the value `Other`.  Sorted, non-overlapping and inclusive.";
+say "*/";
+emit_property_table("WORD_BREAK", "WordCat", "WB", @wb);
@@ -1,10 +1,14 @@
 /*!
 This module provides the `CompareStrs` trait and its implementations.
 
-This trait is used to implement string comparison during scanning.  Specifically, it is used when matching literal tokens (i.e. is "BaNaNa" a suitable match for "banana"?).  It is also provided, through the `Cursor`, to scanners, though they are free to ignore it.
+This trait is used to implement string comparison during scanning.  Specifically, it is used when matching literal tokens (i.e. is "BaNaNa" a suitable match for "banana"?  Is a decomposed "café" a suitable match for a precomposed one?).  It is also provided, through the `Cursor`, to scanners, though they are free to ignore it.
 */
 use std::ascii::AsciiExt;
 use std::fmt::Show;
+use std::str::Chars;
+
+use super::in_ranges;
+use tables::{CASE_FOLDING, COMBINING_MARKS, TURKIC_CASE_FOLDING};
 
 /**
 This trait provides equality comparison for strings.
@@ -14,12 +18,23 @@ pub trait CompareStrs: Eq + Show {
 Compare two strings, returning `true` if they are considered equal under the semantics of the implementing type.
 	*/
 	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool;
+
+	/**
+Returns `true` if `prefix` is equal to some leading portion of `s` under the semantics of the implementing type.  This is used to match abbreviations of keywords.
+
+The default implementation tries `compare_strs` against every prefix of `s` which ends on a code point boundary, including all of `s`.  Implementations may override this with something more efficient.
+	*/
+	fn is_prefix_of(&self, prefix: &str, s: &str) -> bool {
+		s.char_indices().map(|(i,_)| i)
+			.chain(Some(s.len()).into_iter())
+			.any(|end| self.compare_strs(prefix, s.slice_to(end)))
+	}
 }
 
 /**
 Provides exact comparison semantics: two strings are equal if and only if their binary UTF-8 representations are identical.
 
-This *does not* take Unicode normalisation into account.
+This *does not* take Unicode normalisation into account; see `Nfc` for a comparator which does.
 */
 #[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
 pub struct Exact;
@@ -28,6 +43,10 @@ impl CompareStrs for Exact {
 	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
 		a == b
 	}
+
+	fn is_prefix_of(&self, prefix: &str, s: &str) -> bool {
+		s.starts_with(prefix)
+	}
 }
 
 #[test]
@@ -48,6 +67,23 @@ fn test_cs_exact() {
 	assert_eq!(cs(s, "abc ΑΒΓαβΓ"), false);
 }
 
+#[test]
+fn test_cs_is_prefix_of() {
+	assert_eq!(Exact.is_prefix_of("", "show"), true);
+	assert_eq!(Exact.is_prefix_of("sh", "show"), true);
+	assert_eq!(Exact.is_prefix_of("show", "show"), true);
+	assert_eq!(Exact.is_prefix_of("shows", "show"), false);
+	assert_eq!(Exact.is_prefix_of("SH", "show"), false);
+
+	assert_eq!(AsciiCaseInsensitive.is_prefix_of("SH", "show"), true);
+	assert_eq!(AsciiCaseInsensitive.is_prefix_of("sho", "SHOW"), true);
+	assert_eq!(AsciiCaseInsensitive.is_prefix_of("shx", "show"), false);
+
+	assert_eq!(CaseFold.is_prefix_of("STRASS", "straße"), true);
+	assert_eq!(CaseFold.is_prefix_of("STRA", "straße"), true);
+	assert_eq!(CaseFold.is_prefix_of("STRAX", "straße"), false);
+}
+
 /**
 Provides case-insensitive semantics for code points within the ASCII range: two strings are equal if and only if their binary UTF-8 representations are identical, with the exception of the case of latin characters in the ASCII range.
 
@@ -85,7 +121,7 @@ fn test_cs_ascii_case_insensitive() {
 /**
 Provides case-insensitive semantics: two strings are equal if and only if their binary UTF-8 representations are identical, with the exception of the case of code points.
 
-**Note**: this implementation is not entirely correct.  It does not account for cases where a single code point maps to more than one lowercase codepoint, nor is it locale-aware.  This is considered a bug, and may be fixed in future.
+**Note**: this implementation is not entirely correct.  It does not account for cases where a single code point maps to more than one lowercase codepoint, nor is it locale-aware.  This is considered a bug, and may be fixed in future.  If you need correct caseless matching, use `CaseFold` instead.
 
 This *does not* take Unicode normalisation into account.
 */
@@ -118,3 +154,328 @@ fn test_cs_case_insensitive() {
 	assert_eq!(cs(s, "abc ΑΒΓαΒγ"), true);
 	assert_eq!(cs(s, "abc ΑΒΓαβΓ"), true);
 }
+
+/**
+Provides caseless matching semantics using full Unicode case folding: two strings are equal if and only if their case folded forms are identical.
+
+Unlike `CaseInsensitive`, this handles code points which fold to more than one code point; for example, `"STRASSE"` and `"straße"` compare equal, as do `"ΣΑΣ"` and `"σας"`.
+
+This *does not* take Unicode normalisation into account.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct CaseFold;
+
+impl CompareStrs for CaseFold {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		::std::iter::order::eq(case_fold_chars(a.chars(), false), case_fold_chars(b.chars(), false))
+	}
+}
+
+#[test]
+fn test_cs_case_fold() {
+	let cs = |a,b| CaseFold.compare_strs(a, b);
+
+	let s = "abc ΑΒΓαβγ";
+
+	assert_eq!(cs(s, "abc ΑΒΓαβγ"), true);
+	assert_eq!(cs(s, "Abc ΑΒΓαβγ"), true);
+	assert_eq!(cs(s, "abc αβγΑΒΓ"), true);
+	assert_eq!(cs(s, "abc ΑΒΓαβ"), false);
+	assert_eq!(cs(s, "abd ΑΒΓαβγ"), false);
+
+	assert_eq!(cs("STRASSE", "straße"), true);
+	assert_eq!(cs("STRASSE", "STRAẞE"), true);
+	assert_eq!(cs("strasse", "straß"), false);
+	assert_eq!(cs("ΣΑΣ", "σας"), true);
+	assert_eq!(cs("ﬁle", "FILE"), true);
+	assert_eq!(cs("I", "ı"), false);
+	assert_eq!(cs("İ", "i"), false);
+}
+
+/**
+Provides caseless matching semantics using full Unicode case folding, with the Turkic mappings for dotted and dotless I: that is, `I` folds to `ı` and `İ` folds to `i`.
+
+Other than that, this behaves exactly like `CaseFold`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct TurkicCaseFold;
+
+impl CompareStrs for TurkicCaseFold {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		::std::iter::order::eq(case_fold_chars(a.chars(), true), case_fold_chars(b.chars(), true))
+	}
+}
+
+#[test]
+fn test_cs_turkic_case_fold() {
+	let cs = |a,b| TurkicCaseFold.compare_strs(a, b);
+
+	assert_eq!(cs("abc ΑΒΓαβγ", "ABC αβγΑΒΓ"), true);
+	assert_eq!(cs("STRASSE", "straße"), true);
+	assert_eq!(cs("I", "ı"), true);
+	assert_eq!(cs("İ", "i"), true);
+	assert_eq!(cs("I", "i"), false);
+	assert_eq!(cs("DİYARBAKIR", "diyarbakır"), true);
+}
+
+/**
+Provides canonical equivalence semantics: two strings are equal if and only if their NFC normalised forms are identical.
+
+This means that, for example, a precomposed `é` (U+00E9) compares equal to an `e` followed by a combining acute accent (U+0065 U+0301).  Case is significant.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Nfc;
+
+impl CompareStrs for Nfc {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		// Two strings have the same NFC form if and only if they have the same NFD form; this avoids having to recompose.
+		::std::iter::order::eq(a.nfd_chars(), b.nfd_chars())
+	}
+}
+
+#[test]
+fn test_cs_nfc() {
+	let cs = |a,b| Nfc.compare_strs(a, b);
+
+	assert_eq!(cs("abc ΑΒΓαβγ", "abc ΑΒΓαβγ"), true);
+	assert_eq!(cs("abc ΑΒΓαβγ", "Abc ΑΒΓαβγ"), false);
+	assert_eq!(cs("caf\u{e9}", "cafe\u{301}"), true);
+	assert_eq!(cs("caf\u{e9}", "cafe"), false);
+	assert_eq!(cs("\u{1e69}", "s\u{323}\u{307}"), true);
+	assert_eq!(cs("\u{1e69}", "s\u{307}\u{323}"), true);
+	assert_eq!(cs("\u{fb01}le", "file"), false);
+	assert_eq!(cs("\u{212b}", "\u{c5}"), true);
+}
+
+/**
+Provides compatibility equivalence semantics: two strings are equal if and only if their NFKC normalised forms are identical.
+
+In addition to everything `Nfc` considers equal, this folds away compatibility distinctions; for example, the ligature `ﬁ` compares equal to `fi`, and full-width `Ａ` compares equal to `A`.  Case is significant.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Nfkc;
+
+impl CompareStrs for Nfkc {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		::std::iter::order::eq(a.nfkd_chars(), b.nfkd_chars())
+	}
+}
+
+#[test]
+fn test_cs_nfkc() {
+	let cs = |a,b| Nfkc.compare_strs(a, b);
+
+	assert_eq!(cs("abc ΑΒΓαβγ", "abc ΑΒΓαβγ"), true);
+	assert_eq!(cs("abc ΑΒΓαβγ", "Abc ΑΒΓαβγ"), false);
+	assert_eq!(cs("caf\u{e9}", "cafe\u{301}"), true);
+	assert_eq!(cs("\u{fb01}le", "file"), true);
+	assert_eq!(cs("\u{ff21}\u{ff22}", "AB"), true);
+	assert_eq!(cs("x\u{b2}", "x2"), true);
+	assert_eq!(cs("\u{fb01}le", "FILE"), false);
+}
+
+/**
+Provides caseless compatibility matching semantics: two strings are equal if and only if they are equal after NFKC normalisation and full Unicode case folding.  This corresponds to the `NFKC_Casefold` form described by the Unicode Standard, except that default ignorable code points are not removed.
+
+This is the most permissive of the comparators, and is a good choice for matching keywords in text which may have come from a variety of input methods.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct NfkcCaseFold;
+
+impl CompareStrs for NfkcCaseFold {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		let (a, b) = (nfkc_case_fold(a), nfkc_case_fold(b));
+		::std::iter::order::eq(a.nfkd_chars(), b.nfkd_chars())
+	}
+}
+
+#[test]
+fn test_cs_nfkc_case_fold() {
+	let cs = |a,b| NfkcCaseFold.compare_strs(a, b);
+
+	assert_eq!(cs("abc ΑΒΓαβγ", "ABC αβγαβγ"), true);
+	assert_eq!(cs("CAF\u{c9}", "cafe\u{301}"), true);
+	assert_eq!(cs("\u{fb01}le", "FILE"), true);
+	assert_eq!(cs("\u{ff21}\u{ff22}", "ab"), true);
+	assert_eq!(cs("STRASSE", "stra\u{df}e"), true);
+	assert_eq!(cs("\u{212b}", "\u{e5}"), true);
+	assert_eq!(cs("cafe", "caf\u{e9}"), false);
+}
+
+/**
+Computes `toCasefold(NFKD(toCasefold(NFD(s))))`.  The repeated folding is needed because normalisation can expose new code points to be folded, and vice versa.
+*/
+fn nfkc_case_fold(s: &str) -> String {
+	let once: String = case_fold_chars(s.nfd_chars(), false).collect();
+	case_fold_chars(once.as_slice().nfkd_chars(), false).collect()
+}
+
+/**
+Provides accent-insensitive semantics: two strings are equal if and only if they are identical after canonical decomposition and the removal of all non-spacing and enclosing combining marks.
+
+This means that `"cafe"`, `"café"` (in either normalisation form) and `"cafè"` all compare equal.  Case is significant.
+
+Note that only *combining* marks are removed; letters which merely look like they carry a diacritic but have no canonical decomposition (such as `ø` or `ł`) are left alone.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct AccentInsensitive;
+
+impl CompareStrs for AccentInsensitive {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		strip_accents(a) == strip_accents(b)
+	}
+}
+
+#[test]
+fn test_cs_accent_insensitive() {
+	let cs = |a,b| AccentInsensitive.compare_strs(a, b);
+
+	assert_eq!(cs("abc ΑΒΓαβγ", "abc ΑΒΓαβγ"), true);
+	assert_eq!(cs("abc ΑΒΓαβγ", "Abc ΑΒΓαβγ"), false);
+	assert_eq!(cs("cafe", "caf\u{e9}"), true);
+	assert_eq!(cs("cafe", "cafe\u{301}"), true);
+	assert_eq!(cs("caf\u{e8}", "caf\u{e9}"), true);
+	assert_eq!(cs("CAFE", "caf\u{e9}"), false);
+	assert_eq!(cs("na\u{ef}ve", "naive"), true);
+	assert_eq!(cs("\u{3ac}", "\u{3b1}"), true);
+	assert_eq!(cs("\u{f8}", "o"), false);
+}
+
+/**
+Provides accent- and case-insensitive semantics: two strings are equal if and only if they are identical after canonical decomposition, full Unicode case folding, and the removal of all non-spacing and enclosing combining marks.
+
+This combines the behaviour of `AccentInsensitive` and `CaseFold`; for example, `"CAFE"` and `"café"` compare equal.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct AccentCaseInsensitive;
+
+impl CompareStrs for AccentCaseInsensitive {
+	fn compare_strs<'a>(&self, a: &str, b: &str) -> bool {
+		strip_accents(case_fold(a, false).as_slice()) == strip_accents(case_fold(b, false).as_slice())
+	}
+}
+
+#[test]
+fn test_cs_accent_case_insensitive() {
+	let cs = |a,b| AccentCaseInsensitive.compare_strs(a, b);
+
+	assert_eq!(cs("abc ΑΒΓαβγ", "ABC αβγαβγ"), true);
+	assert_eq!(cs("CAFE", "caf\u{e9}"), true);
+	assert_eq!(cs("CAF\u{c9}", "cafe\u{301}"), true);
+	assert_eq!(cs("\u{130}stanbul", "istanbul"), true);
+	assert_eq!(cs("STRASSE", "stra\u{df}e"), true);
+	assert_eq!(cs("\u{386}\u{3a3}", "\u{3b1}\u{3c2}"), true);
+	assert_eq!(cs("cafe", "caff"), false);
+}
+
+/**
+Returns the canonical decomposition of a string, with all non-spacing and enclosing combining marks removed.
+
+For example, `strip_accents("Ångström")` returns `"Angstrom"`.
+*/
+pub fn strip_accents(s: &str) -> String {
+	s.nfd_chars().filter(|&ch| !is_combining_mark(ch)).collect()
+}
+
+/**
+Returns `true` if the given code point is a non-spacing (`Mn`) or enclosing (`Me`) combining mark.
+*/
+pub fn is_combining_mark(ch: char) -> bool {
+	in_ranges(COMBINING_MARKS, ch)
+}
+
+#[test]
+fn test_strip_accents() {
+	assert_eq!(strip_accents("").as_slice(), "");
+	assert_eq!(strip_accents("plain").as_slice(), "plain");
+	assert_eq!(strip_accents("\u{c5}ngstr\u{f6}m").as_slice(), "Angstrom");
+	assert_eq!(strip_accents("e\u{301}\u{20dd}").as_slice(), "e");
+	assert_eq!(strip_accents("\u{f8}").as_slice(), "\u{f8}");
+}
+
+/**
+Returns the full case folding of a string.  If `turkic` is `true`, the Turkic mappings for dotted and dotless I are used.
+*/
+pub fn case_fold(s: &str, turkic: bool) -> String {
+	case_fold_chars(s.chars(), turkic).collect()
+}
+
+/**
+Returns an iterator which yields the full case folding of the code points yielded by `iter`.  If `turkic` is `true`, the Turkic mappings for dotted and dotless I are used.
+*/
+pub fn case_fold_chars<I: Iterator<Item=char>>(iter: I, turkic: bool) -> CaseFoldChars<I> {
+	CaseFoldChars {
+		iter: iter,
+		turkic: turkic,
+		pending: "".chars(),
+	}
+}
+
+/**
+Iterator returned by `case_fold_chars`.
+*/
+pub struct CaseFoldChars<I> {
+	iter: I,
+	turkic: bool,
+	pending: Chars<'static>,
+}
+
+impl<I: Iterator<Item=char>> Iterator for CaseFoldChars<I> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		if let Some(ch) = self.pending.next() {
+			return Some(ch);
+		}
+
+		let ch = match self.iter.next() {
+			Some(ch) => ch,
+			None => return None
+		};
+
+		let folded = if self.turkic {
+			lookup_fold(TURKIC_CASE_FOLDING, ch).or_else(|| lookup_fold(CASE_FOLDING, ch))
+		} else {
+			lookup_fold(CASE_FOLDING, ch)
+		};
+
+		match folded {
+			Some(s) => {
+				self.pending = s.chars();
+				self.pending.next()
+			},
+			None => Some(ch)
+		}
+	}
+
+	fn size_hint(&self) -> (uint, Option<uint>) {
+		let (lo, _) = self.iter.size_hint();
+		(lo, None)
+	}
+}
+
+fn lookup_fold(table: &'static [(char, &'static str)], ch: char) -> Option<&'static str> {
+	let (mut lo, mut hi) = (0, table.len());
+	while lo < hi {
+		let mid = lo + (hi - lo) / 2;
+		let (key, folded) = table[mid];
+		if key == ch {
+			return Some(folded);
+		} else if key < ch {
+			lo = mid + 1;
+		} else {
+			hi = mid;
+		}
+	}
+	None
+}
+
+#[test]
+fn test_case_fold() {
+	assert_eq!(case_fold("", false).as_slice(), "");
+	assert_eq!(case_fold("Hello, World!", false).as_slice(), "hello, world!");
+	assert_eq!(case_fold("Straße", false).as_slice(), "strasse");
+	assert_eq!(case_fold("ΌΣΟΣ", false).as_slice(), "όσοσ");
+	assert_eq!(case_fold("İI", false).as_slice(), "i\u{307}i");
+	assert_eq!(case_fold("İI", true).as_slice(), "iı");
+}
@@ -4,9 +4,18 @@ This module provides the `ScanCursor` trait, and its concrete `Cursor` implement
 These are used by the generated code as a way to track scanning progress through an input string, and to centralise various bits of functionality.
 */
 use super::{Tokenizer, Whitespace, CompareStrs};
+use super::tokenizer::TokenKind;
 use super::{ScanError, OtherScanError};
-
+use compare_strs::Exact;
+use packrat::PackratTable;
+use segment::grapheme_len;
+use span::{self, Span};
+use suggest::closest_match;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::{self, Show, Formatter};
+use std::rc::Rc;
 use std::str::CharRange;
 
 /**
@@ -36,6 +45,97 @@ If there are no further tokens in the input, returns `None`.
 	*/
 	fn pop_token(&self) -> Option<(&'scanee str, Self)>;
 
+	/**
+Pop the next token, returning a slice of the input, what kind of token it is, and the successor cursor.
+
+If there are no further tokens in the input, returns `None`.  The default implementation defers to `pop_token`, and reports every token as `TokenKind::Other`.
+	*/
+	fn pop_typed_token(&self) -> Option<(&'scanee str, TokenKind, Self)> {
+		self.pop_token().map(|(tok, cur)| (tok, TokenKind::Other, cur))
+	}
+
+	/**
+Return the next token without consuming it.
+
+If there are no further tokens in the input, returns `None`.
+	*/
+	fn peek_token(&self) -> Option<&'scanee str> {
+		self.pop_token().map(|(tok, _)| tok)
+	}
+
+	/**
+Return up to the next `k` tokens without consuming them.  If fewer than `k` tokens remain, only those which remain are returned.
+	*/
+	fn peek_n(&self, k: uint) -> Vec<&'scanee str> {
+		let mut toks = Vec::with_capacity(k);
+		let mut cur = self.clone();
+		while toks.len() < k {
+			match cur.pop_token() {
+				Some((tok, next)) => {
+					toks.push(tok);
+					cur = next;
+				},
+				None => break
+			}
+		}
+		toks
+	}
+
+	/**
+Return the span of input between this cursor and `other`.  `other` should be a successor of this cursor.
+	*/
+	fn span_to(&self, other: &Self) -> Span {
+		Span { start: self.consumed(), end: other.consumed() }
+	}
+
+	/**
+Return the one-based line and column of the cursor's position within the input, if known.  Columns are counted in code points.
+
+The default implementation returns `None`.
+	*/
+	fn line_col(&self) -> Option<(uint, uint)> {
+		None
+	}
+
+	/**
+Return the table used by `packrat::memoise` to record the outcome of scanning rules at each offset, if this cursor has one.
+
+The default implementation returns `None`, which disables memoisation.
+	*/
+	fn packrat_table(&self) -> Option<Rc<RefCell<PackratTable>>> {
+		None
+	}
+
+	/**
+Return an iterator over the remaining tokens, starting from this cursor.  Each token is paired with the span of the input it was taken from; leading whitespace is not included in the span.
+
+Note that a token produced by the whitespace policy need not be a slice of the input (for example, it might be a static `"\n"`), but its span will still cover the input it replaced.
+	*/
+	fn tokens(&self) -> Tokens<Self> {
+		Tokens { cur: self.clone() }
+	}
+
+	/**
+Returns `true` if the next tokens match `toks`, in order, using the cursor's string comparator.  Nothing is consumed; use `expect_tok` to actually move past the tokens.
+
+For example, `cur.starts_with_toks(&["end", "loop"])` checks for an `end loop` terminator without committing to it.
+	*/
+	fn starts_with_toks(&self, toks: &[&str]) -> bool {
+		let mut cur = self.clone();
+		for tok in toks.iter() {
+			match cur.pop_token() {
+				Some((got, next)) => {
+					if !self.compare_strs(*tok, got) {
+						return false;
+					}
+					cur = next;
+				},
+				None => return false
+			}
+		}
+		true
+	}
+
 	/**
 Return a successor cursor with all leading, irrelevant whitespace skipped.  This will always succeed.
 	*/
@@ -73,6 +173,69 @@ Compare two strings for equality, using the cursor's string comparator.
 	*/
 	fn compare_strs(&self, a: &str, b: &str) -> bool;
 
+	/**
+Returns `true` if `prefix` matches a leading portion of `s`, using the cursor's string comparator.
+
+The default implementation tries `compare_strs` against every prefix of `s` which ends on a code point boundary.
+	*/
+	fn compare_prefix(&self, prefix: &str, s: &str) -> bool {
+		s.char_indices().map(|(i,_)| i)
+			.chain(Some(s.len()).into_iter())
+			.any(|end| self.compare_strs(prefix, s.slice_to(end)))
+	}
+
+	/**
+Match the next token against a set of keywords, accepting any unambiguous abbreviation.  Each keyword is given along with the minimum number of code points an abbreviation of it must have.  On success, returns the index of the matched keyword and the successor cursor.
+
+A token which exactly matches a keyword is always accepted, regardless of whether it is also a prefix of other keywords.  Otherwise, the token must be a prefix of exactly one keyword (subject to that keyword's minimum length); if it is a prefix of more than one, the error will list the candidates.
+
+For example, given `[("show", 2), ("shutdown", 3), ("interface", 3)]`, the token `sh` matches `show`, `int` matches `interface`, and `s` matches nothing.
+	*/
+	fn expect_abbrev(&self, words: &[(&str, uint)]) -> Result<(uint, Self), ScanError> {
+		let (tok, cur) = match self.pop_token() {
+			Some(tc) => tc,
+			None => {
+				let toks: Vec<&str> = words.iter().map(|&(w,_)| w).collect();
+				return Err(self.expected_one_of(toks.as_slice()));
+			}
+		};
+
+		if let Some(i) = words.iter().position(|&(w,_)| self.compare_strs(tok, w)) {
+			return Ok((i, cur));
+		}
+
+		let tok_len = tok.chars().count();
+		let candidates: Vec<uint> = words.iter().enumerate()
+			.filter(|&(_, &(w, min_len))| tok_len >= min_len && self.compare_prefix(tok, w))
+			.map(|(i,_)| i)
+			.collect();
+
+		match candidates.len() {
+			1 => Ok((candidates[0], cur)),
+			0 => {
+				let toks: Vec<&str> = words.iter().map(|&(w,_)| w).collect();
+				Err(self.expected_one_of(toks.as_slice()))
+			},
+			_ => {
+				let mut cands = candidates.iter().map(|&i| format!("`{}`", words[i].0.escape_default()));
+				let first = cands.next().unwrap();
+				let cands = cands.fold(first, |a,b| format!("{}, {}", a, b));
+				let msg = format!("ambiguous abbreviation `{}`; could be any of {}", tok.escape_default(), cands);
+				Err(OtherScanError(msg, self.consumed()))
+			}
+		}
+	}
+
+	/**
+Pop the next token if it is of the given kind, returning a slice of the input and the successor cursor.  Otherwise, the error will describe the kind of token expected.
+	*/
+	fn expect_kind(&self, kind: TokenKind) -> Result<(&'scanee str, Self), ScanError> {
+		match self.pop_typed_token() {
+			Some((tok, tok_kind, cur)) if tok_kind == kind => Ok((tok, cur)),
+			_ => Err(self.expected(kind.description()))
+		}
+	}
+
 	/**
 Returns a nil result if there are no remaining tokens in the input.
 
@@ -117,10 +280,15 @@ Create a `ScanError` tied to the current position, indicating that you expected
 Create a `ScanError` tied to the current position, indicating that you expected one of a specific set of tokens, `toks`.  The generated message will include the next token which was not what you expected.
 
 When a single token is provided, this is equivalent to `expected_tok`.  When no tokens are provided, this is equivalent to `expected_eof`.
+
+If the next token is a plausible misspelling of one of `toks` (as determined by `suggest::closest_match` using the cursor's string comparator), the message will also suggest that token.
 	*/
 	fn expected_one_of(&self, toks: &[&str]) -> ScanError {
 		use std::borrow::ToOwned;
 
+		let got = self.pop_token().map(|(got, _)| got);
+		let suggestion = got.and_then(|got| closest_match(got, toks, |a, b| self.compare_strs(a, b)));
+
 		let mut toks = toks.iter().map(|s| format!("`{}`", s.escape_default()));
 		let toks = {
 			if let Some(first) = toks.next() {
@@ -130,10 +298,13 @@ When a single token is provided, this is equivalent to `expected_tok`.  When no
 			}
 		};
 
-		let msg = match (toks, self.pop_token()) {
-			(Some(exp), Some((got, _))) => format!("expected {}, got `{}`", exp, got.escape_default()),
+		let msg = match (toks, got) {
+			(Some(exp), Some(got)) => match suggestion {
+				Some(sugg) => format!("expected {}, got `{}`, did you mean `{}`?", exp, got.escape_default(), sugg.escape_default()),
+				None => format!("expected {}, got `{}`", exp, got.escape_default())
+			},
 			(Some(exp), None) => format!("expected {}, got end of input", exp),
-			(None, Some((got, _))) => format!("expected end of input, got `{}`", got.escape_default()),
+			(None, Some(got)) => format!("expected end of input, got `{}`", got.escape_default()),
 			(None, None) => "expected end of input".to_owned()
 		};
 
@@ -148,6 +319,62 @@ Create a `ScanError` tied to the current position, indicating that you expected
 	}
 }
 
+/**
+An iterator over the tokens remaining after a cursor; see `ScanCursor::tokens`.
+*/
+#[derive(Clone, Show)]
+pub struct Tokens<Cur> {
+	cur: Cur,
+}
+
+impl<'a, Cur: ScanCursor<'a>> Tokens<Cur> {
+	/**
+Returns a cursor positioned just after the last token produced.
+	*/
+	pub fn cursor(&self) -> Cur {
+		self.cur.clone()
+	}
+}
+
+impl<'a, Cur: ScanCursor<'a>> Iterator for Tokens<Cur> {
+	type Item = (&'a str, Span);
+
+	fn next(&mut self) -> Option<(&'a str, Span)> {
+		let start = self.cur.pop_ws().consumed();
+		match self.cur.pop_token() {
+			Some((tok, next)) => {
+				let span = Span { start: start, end: next.consumed() };
+				self.cur = next;
+				Some((tok, span))
+			},
+			None => None
+		}
+	}
+}
+
+/**
+Splits `s` into tokens using the given tokeniser and whitespace policy, returning each token along with its span.  This is intended as a debugging aid, for checking how a particular combination of policies will tokenise real input.
+*/
+pub fn collect_tokens<'a, Tok, Sp>(s: &'a str, tc: Tok, sp: Sp) -> Vec<(&'a str, Span)>
+where Tok: Tokenizer + Clone, Sp: Whitespace + Clone {
+	Cursor::new(s, tc, sp, Exact).tokens().collect()
+}
+
+/**
+Determines what `Cursor::pop_token` produces when neither the whitespace policy nor the tokeniser recognise a token, but there is input remaining.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub enum Fallback {
+	/**
+Produce a token containing the next single code point.  This is the default.
+	*/
+	CodePoint,
+	/**
+Produce a token containing the next extended grapheme cluster, as defined by Unicode Standard Annex #29.  This keeps things like an `e` followed by a combining accent, or an emoji with a skin tone modifier, together as a single token.
+	*/
+	Grapheme,
+}
+
 /**
 This structure implements the `ScanCursor` trait.
 */
@@ -158,6 +385,7 @@ pub struct Cursor<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> {
 	tc: Tok,
 	sp: Sp,
 	cs: Cs,
+	fallback: Fallback,
 }
 
 impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> Cursor<'a, Tok, Sp, Cs> {
@@ -168,13 +396,24 @@ impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> Cursor<'a, Tok, Sp, Cs
 			tc: tc,
 			sp: sp,
 			cs: cs,
+			fallback: Fallback::CodePoint,
+		}
+	}
+
+	/**
+Returns this cursor with its fallback mode changed.  See `Fallback`.
+	*/
+	pub fn with_fallback(self, fallback: Fallback) -> Cursor<'a, Tok, Sp, Cs> {
+		Cursor {
+			fallback: fallback,
+			..self
 		}
 	}
 }
 
 impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> Show for Cursor<'a, Tok, Sp, Cs> {
 	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-		try!(write!(f, "Cursor<{}, {}, {}> {{ offset: {}, .. }}", self.tc, self.sp, self.cs, self.offset));
+		try!(write!(f, "Cursor<{}, {}, {}> {{ offset: {}, fallback: {}, .. }}", self.tc, self.sp, self.cs, self.offset, self.fallback));
 		Ok(())
 	}
 }
@@ -193,37 +432,47 @@ impl<'a, Tok: Tokenizer + Clone, Sp: Whitespace + Clone, Cs: CompareStrs + Clone
 	}
 
 	fn pop_token(&self) -> Option<(&'a str, Cursor<'a, Tok, Sp, Cs>)> {
-		debug!("{}.pop_token()", self);
+		self.pop_typed_token().map(|(tok, _, cur)| (tok, cur))
+	}
+
+	fn pop_typed_token(&self) -> Option<(&'a str, TokenKind, Cursor<'a, Tok, Sp, Cs>)> {
+		debug!("{}.pop_typed_token()", self);
 		// First, strip out leading whitespace.  It's up to the whitespace policy to *not* strip characters it wants to turn into a token.
 		let cur = self.pop_ws();
 
 		// Next, check to see if there is a whitespace token.  This allows the space policy to do things like ignore most whitespace, but turn line breaks into explicit tokens.  Note that unlike the regular Tokenizer, the Whitespace policy is responsible for returning the str slice itself.  This is used to do things like map all whitespace to a single `" "` token.
 		match self.sp.token_len(cur.tail_str()) {
 			Some((end, s)) => {
-				debug!("{}.pop_token - sp token `{}`", self, s.escape_default());
-				return Some((s, cur.slice_from(end)));
+				debug!("{}.pop_typed_token - sp token `{}`", self, s.escape_default());
+				return Some((s, TokenKind::Space, cur.slice_from(end)));
 			},
 			None => ()
 		}
 
 		// Do not assume that empty input means we can't match a token; the token class might, for example, turn end-of-input into an explicit token.
 		let tail_str = cur.tail_str();
-		match self.tc.token_len(tail_str) {
-			Some(end) => {
+		match self.tc.typed_token_len(tail_str) {
+			Some((end, kind)) => {
 				let tok = cur.str_slice_to(end);
-				debug!("{}.pop_token - token `{}`", self, tok.escape_default());
-				Some((tok, cur.slice_from(end)))
+				debug!("{}.pop_typed_token - token `{}` ({})", self, tok.escape_default(), kind);
+				Some((tok, kind, cur.slice_from(end)))
 			},
 			None => {
 				// One of two things: either we have some input left and will thus return a single-character token, or there is nothing left whereby we return None.
 				if cur.is_empty() {
-					debug!("{}.pop_token - no token", self);
+					debug!("{}.pop_typed_token - no token", self);
 					return None;
 				} else {
-					let CharRange { ch: _, next } = tail_str.char_range_at(0);
+					let next = match self.fallback {
+						Fallback::CodePoint => {
+							let CharRange { ch: _, next } = tail_str.char_range_at(0);
+							next
+						},
+						Fallback::Grapheme => grapheme_len(tail_str).unwrap_or(tail_str.len()),
+					};
 					let tok = cur.str_slice_to(next);
-					debug!("{}.pop_token - def token `{}`", self, tok.escape_default());
-					Some((tok, cur.slice_from(next)))
+					debug!("{}.pop_typed_token - def token `{}`", self, tok.escape_default());
+					Some((tok, TokenKind::Fallback, cur.slice_from(next)))
 				}
 			},
 		}
@@ -261,4 +510,337 @@ impl<'a, Tok: Tokenizer + Clone, Sp: Whitespace + Clone, Cs: CompareStrs + Clone
 	fn compare_strs(&self, a: &str, b: &str) -> bool {
 		self.cs.compare_strs(a, b)
 	}
+
+	fn compare_prefix(&self, prefix: &str, s: &str) -> bool {
+		self.cs.is_prefix_of(prefix, s)
+	}
+
+	fn line_col(&self) -> Option<(uint, uint)> {
+		Some(span::line_col(self.slice, self.offset))
+	}
+}
+
+/**
+A wrapper around `Cursor` which remembers the token found at each offset.  Popping a token at an offset which has already been visited does not run the whitespace policy or the tokeniser again.
+
+This is useful for scanners which backtrack heavily, or which try many alternatives at the same position; every failed `expect_tok` and every call to `expected` pops the next token.  All cursors derived from a `MemoCursor` share the same cache.  Other than the caching, it behaves exactly like the `Cursor` it wraps.
+
+A `MemoCursor` also provides a `PackratTable`, enabling memoisation of entire scanners; see the `packrat` module.
+*/
+pub struct MemoCursor<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> {
+	inner: Cursor<'a, Tok, Sp, Cs>,
+	cache: Rc<RefCell<HashMap<uint, Option<(&'a str, TokenKind, Cursor<'a, Tok, Sp, Cs>)>>>>,
+	packrat: Rc<RefCell<PackratTable>>,
+}
+
+impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> MemoCursor<'a, Tok, Sp, Cs> {
+	/**
+Wraps the given cursor, starting with an empty cache.
+	*/
+	pub fn new(inner: Cursor<'a, Tok, Sp, Cs>) -> MemoCursor<'a, Tok, Sp, Cs> {
+		MemoCursor {
+			inner: inner,
+			cache: Rc::new(RefCell::new(HashMap::new())),
+			packrat: Rc::new(RefCell::new(PackratTable::new())),
+		}
+	}
+
+	/**
+Returns the number of offsets for which a token has been cached.
+	*/
+	pub fn cached_offsets(&self) -> uint {
+		self.cache.borrow().len()
+	}
+
+	fn wrap(&self, inner: Cursor<'a, Tok, Sp, Cs>) -> MemoCursor<'a, Tok, Sp, Cs> {
+		MemoCursor {
+			inner: inner,
+			cache: self.cache.clone(),
+			packrat: self.packrat.clone(),
+		}
+	}
+}
+
+impl<'a, Tok: Tokenizer + Clone, Sp: Whitespace + Clone, Cs: CompareStrs + Clone> Clone for MemoCursor<'a, Tok, Sp, Cs> {
+	fn clone(&self) -> MemoCursor<'a, Tok, Sp, Cs> {
+		self.wrap(self.inner.clone())
+	}
+}
+
+impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> PartialEq for MemoCursor<'a, Tok, Sp, Cs> {
+	fn eq(&self, other: &MemoCursor<'a, Tok, Sp, Cs>) -> bool {
+		self.inner == other.inner
+	}
+}
+
+impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> Eq for MemoCursor<'a, Tok, Sp, Cs> {}
+
+impl<'a, Tok: Tokenizer, Sp: Whitespace, Cs: CompareStrs> Show for MemoCursor<'a, Tok, Sp, Cs> {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+		write!(f, "MemoCursor({})", self.inner)
+	}
+}
+
+impl<'a, Tok: Tokenizer + Clone, Sp: Whitespace + Clone, Cs: CompareStrs + Clone> ScanCursor<'a> for MemoCursor<'a, Tok, Sp, Cs> {
+	fn expect_tok(&self, s: &str) -> Result<MemoCursor<'a, Tok, Sp, Cs>, ScanError> {
+		debug!("{}.expect_tok({})", self, s);
+		match self.pop_token() {
+			Some((tok, ref cur)) if self.compare_strs(s, tok) => Ok(cur.clone()),
+			_ => Err(self.expected_tok(s))
+		}
+	}
+
+	fn consumed(&self) -> uint {
+		self.inner.consumed()
+	}
+
+	fn pop_token(&self) -> Option<(&'a str, MemoCursor<'a, Tok, Sp, Cs>)> {
+		self.pop_typed_token().map(|(tok, _, cur)| (tok, cur))
+	}
+
+	fn pop_typed_token(&self) -> Option<(&'a str, TokenKind, MemoCursor<'a, Tok, Sp, Cs>)> {
+		let offset = self.inner.offset;
+
+		let cached = self.cache.borrow().get(&offset).map(|hit| hit.clone());
+		let result = match cached {
+			Some(hit) => {
+				debug!("{}.pop_typed_token - cached", self);
+				hit
+			},
+			None => {
+				let result = self.inner.pop_typed_token();
+				self.cache.borrow_mut().insert(offset, result.clone());
+				result
+			}
+		};
+
+		result.map(|(tok, kind, cur)| (tok, kind, self.wrap(cur)))
+	}
+
+	fn pop_ws(&self) -> MemoCursor<'a, Tok, Sp, Cs> {
+		self.wrap(self.inner.pop_ws())
+	}
+
+	fn slice_from(&self, from: uint) -> MemoCursor<'a, Tok, Sp, Cs> {
+		self.wrap(self.inner.slice_from(from))
+	}
+
+	fn str_slice_to(&self, to: uint) -> &'a str {
+		self.inner.str_slice_to(to)
+	}
+
+	fn str_slice_to_cur(&self, to: &MemoCursor<'a, Tok, Sp, Cs>) -> &'a str {
+		self.inner.str_slice_to_cur(&to.inner)
+	}
+
+	fn tail_str(&self) -> &'a str {
+		self.inner.tail_str()
+	}
+
+	fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+
+	fn compare_strs(&self, a: &str, b: &str) -> bool {
+		self.inner.compare_strs(a, b)
+	}
+
+	fn compare_prefix(&self, prefix: &str, s: &str) -> bool {
+		self.inner.compare_prefix(prefix, s)
+	}
+
+	fn line_col(&self) -> Option<(uint, uint)> {
+		self.inner.line_col()
+	}
+
+	fn packrat_table(&self) -> Option<Rc<RefCell<PackratTable>>> {
+		Some(self.packrat.clone())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Cursor, ScanCursor};
+	use compare_strs::AsciiCaseInsensitive;
+	use tokenizer::IdentsAndInts;
+	use whitespace::Ignore;
+
+	fn cur<'a>(s: &'a str) -> Cursor<'a, IdentsAndInts, Ignore, AsciiCaseInsensitive> {
+		Cursor::new(s, IdentsAndInts, Ignore, AsciiCaseInsensitive)
+	}
+
+	#[test]
+	fn test_fallback() {
+		use super::Fallback;
+
+		let s = "e\u{301}\u{1f44d}\u{1f3fd}x";
+		// Note that the tokeniser already treats `e` + combining accent as an identifier; only the emoji is affected by the fallback mode.
+		let toks = |cur: Cursor<IdentsAndInts, Ignore, AsciiCaseInsensitive>| {
+			let mut toks = vec![];
+			let mut cur = cur;
+			while let Some((tok, next)) = cur.pop_token() {
+				toks.push(tok);
+				cur = next;
+			}
+			toks
+		};
+
+		assert_eq!(toks(cur("+\u{301}")), vec!["+", "\u{301}"]);
+		assert_eq!(toks(cur("+\u{301}").with_fallback(Fallback::Grapheme)), vec!["+\u{301}"]);
+		assert_eq!(toks(cur(s)), vec!["e\u{301}", "\u{1f44d}", "\u{1f3fd}", "x"]);
+		assert_eq!(toks(cur(s).with_fallback(Fallback::Grapheme)), vec!["e\u{301}", "\u{1f44d}\u{1f3fd}", "x"]);
+	}
+
+	#[test]
+	fn test_expect_abbrev() {
+		let words = [("show", 2), ("shutdown", 3), ("interface", 3), ("in", 2)];
+		let ab = |s: &str| cur(s).expect_abbrev(&words).map(|(i,c)| (i, c.consumed()));
+
+		assert_eq!(ab("show"), Ok((0, 4)));
+		assert_eq!(ab("SH int"), Ok((0, 2)));
+		assert_eq!(ab("sho"), Ok((0, 3)));
+		assert_eq!(ab("shu"), Ok((1, 3)));
+		assert_eq!(ab("  shutdown"), Ok((1, 10)));
+		assert_eq!(ab("int"), Ok((2, 3)));
+		assert_eq!(ab("in"), Ok((3, 2)));
+		assert!(ab("i").is_err());
+		assert!(ab("s").is_err());
+		assert!(ab("shows").is_err());
+		assert!(ab("").is_err());
+	}
+
+	#[test]
+	fn test_expected_one_of_suggestion() {
+		let msg = |s: &str, toks: &[&str]| format!("{}", cur(s).expected_one_of(toks));
+
+		assert_eq!(msg("fasle", &["true", "false"]), "at offset 0: expected `true`, `false`, got `fasle`, did you mean `false`?");
+		assert_eq!(msg("FASLE", &["true", "false"]), "at offset 0: expected `true`, `false`, got `FASLE`, did you mean `false`?");
+		assert_eq!(msg("yes", &["true", "false"]), "at offset 0: expected `true`, `false`, got `yes`");
+		assert_eq!(msg("", &["true", "false"]), "at offset 0: expected `true`, `false`, got end of input");
+		assert_eq!(msg("lisen", &["listen"]), "at offset 0: expected `listen`, got `lisen`, did you mean `listen`?");
+		assert_eq!(msg("x", &[]), "at offset 0: expected end of input, got `x`");
+	}
+
+	#[test]
+	fn test_pop_typed_token() {
+		use tokenizer::TokenKind;
+		use whitespace::ExplicitNewline;
+
+		let mut cur = Cursor::new("foo 12\n+", IdentsAndInts, ExplicitNewline, AsciiCaseInsensitive);
+		let mut toks = vec![];
+		while let Some((tok, kind, next)) = cur.pop_typed_token() {
+			toks.push((tok, kind));
+			cur = next;
+		}
+
+		assert_eq!(toks, vec![
+			("foo", TokenKind::Ident),
+			("12", TokenKind::Number),
+			("\n", TokenKind::Space),
+			("+", TokenKind::Fallback),
+		]);
+	}
+
+	#[test]
+	fn test_expect_kind() {
+		use tokenizer::TokenKind;
+
+		let ek = |s: &str, kind| cur(s).expect_kind(kind).map(|(t,c)| (t, c.consumed()));
+
+		assert_eq!(ek(" foo 12", TokenKind::Ident), Ok(("foo", 4)));
+		assert_eq!(ek("12 foo", TokenKind::Number), Ok(("12", 2)));
+		assert_eq!(format!("{}", ek("foo", TokenKind::Number).err().unwrap()), "at offset 0: expected number, got `foo`");
+		assert_eq!(format!("{}", ek("", TokenKind::Ident).err().unwrap()), "at offset 0: expected identifier, got end of input");
+	}
+
+	#[test]
+	fn test_peek() {
+		let c = cur("end  LOOP 42");
+
+		assert_eq!(c.peek_token(), Some("end"));
+		assert_eq!(c.peek_n(0), Vec::<&str>::new());
+		assert_eq!(c.peek_n(2), vec!["end", "LOOP"]);
+		assert_eq!(c.peek_n(5), vec!["end", "LOOP", "42"]);
+		assert_eq!(cur("  ").peek_token(), None);
+		assert_eq!(c.consumed(), 0);
+
+		assert!(c.starts_with_toks(&[]));
+		assert!(c.starts_with_toks(&["end"]));
+		assert!(c.starts_with_toks(&["END", "loop"]));
+		assert!(c.starts_with_toks(&["end", "loop", "42"]));
+		assert!(!c.starts_with_toks(&["end", "if"]));
+		assert!(!c.starts_with_toks(&["end", "loop", "42", "x"]));
+		assert!(!c.starts_with_toks(&["en"]));
+	}
+
+	#[test]
+	fn test_tokens() {
+		use span::Span;
+
+		let sp = |start, end| Span { start: start, end: end };
+		let c = cur("  foo 12+bar ");
+		let toks: Vec<_> = c.tokens().collect();
+
+		assert_eq!(toks, vec![
+			("foo", sp(2, 5)),
+			("12", sp(6, 8)),
+			("+", sp(8, 9)),
+			("bar", sp(9, 12)),
+		]);
+
+		let mut it = c.tokens();
+		it.next();
+		assert_eq!(it.cursor().consumed(), 5);
+		assert_eq!(cur(" ").tokens().next(), None);
+	}
+
+	#[test]
+	fn test_collect_tokens() {
+		use super::collect_tokens;
+		use span::Span;
+		use tokenizer::SpaceDelimited;
+		use whitespace::ExplicitNewline;
+
+		assert_eq!(collect_tokens("a b\r\nc", SpaceDelimited, ExplicitNewline), vec![
+			("a", Span { start: 0, end: 1 }),
+			("b", Span { start: 2, end: 3 }),
+			("\n", Span { start: 3, end: 5 }),
+			("c", Span { start: 5, end: 6 }),
+		]);
+	}
+
+	#[test]
+	fn test_memo_cursor() {
+		use super::MemoCursor;
+		use tokenizer::TokenKind;
+
+		let plain = cur("  foo 12 + bar");
+		let memo = MemoCursor::new(plain.clone());
+
+		let plain_toks: Vec<_> = plain.tokens().collect();
+		let memo_toks: Vec<_> = memo.tokens().collect();
+		assert_eq!(memo_toks, plain_toks);
+		assert_eq!(memo.cached_offsets(), 5);
+
+		// Trying several alternatives at the same offset only tokenises once.
+		let memo = MemoCursor::new(plain.clone());
+		assert!(memo.expect_tok("bar").is_err());
+		assert!(memo.expect_tok("baz").is_err());
+		let next = memo.expect_tok("FOO").ok().unwrap();
+		assert_eq!(memo.cached_offsets(), 1);
+		assert_eq!(next.consumed(), 5);
+
+		assert_eq!(next.pop_typed_token().map(|(t,k,c)| (t, k, c.consumed())), Some(("12", TokenKind::Number, 8)));
+		assert_eq!(next.clone().cached_offsets(), 2);
+		assert_eq!(format!("{}", next.expected("word")), "at offset 5: expected word, got `12`");
+		assert_eq!(memo.cached_offsets(), 2);
+		assert!(next == MemoCursor::new(plain.slice_from(5)));
+	}
+
+	#[test]
+	fn test_expect_abbrev_ambiguous() {
+		let words = [("show", 1), ("shutdown", 1)];
+		let err = cur("sh").expect_abbrev(&words).err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 0: ambiguous abbreviation `sh`; could be any of `show`, `shutdown`");
+	}
 }
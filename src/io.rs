@@ -4,6 +4,9 @@ This module provides some miscellaneous IO support routines.
 
 use std::io::{IoError, IoResult, OtherIoError};
 
+use super::{ScanResult, ScanIoError};
+use tokenizer::shell::{is_incomplete, split_words};
+
 /**
 Reads a line of input from the given `Reader`.  This does not require a push-back buffer.  It returns the line *with* the line terminator.
 
@@ -45,6 +48,43 @@ fn test_read_line() {
 	assert_eq!(read_line(&mut r), oks("line three\n"));
 }
 
+/**
+Reads a command from the given `Reader` and splits it into arguments according to POSIX shell word-splitting rules, as described in `tokenizer::shell::split_words`.
+
+A command is normally a single line, as read by `read_line`.  However, if the line ends inside a quoted string or with a line continuation (a backslash immediately before the line feed), further lines are read until the command is complete.
+*/
+pub fn read_argv<R: Reader>(r: &mut R) -> ScanResult<Vec<String>> {
+	let mut line = match read_line(r) {
+		Ok(line) => line,
+		Err(err) => return Err(ScanIoError(err))
+	};
+
+	while is_incomplete(line.as_slice()) {
+		match read_line(r) {
+			Ok(more) => line.push_str(more.as_slice()),
+			Err(ref err) if err.kind == ::std::io::EndOfFile => break,
+			Err(err) => return Err(ScanIoError(err))
+		}
+	}
+
+	split_words(line.as_slice())
+}
+
+#[test]
+fn test_read_argv() {
+	use std::borrow::ToOwned;
+
+	let s = "ls -l\nset name 'John\nSmith'\necho a \\\n  b\nprint \"oops\n";
+	let mut r = ::std::io::BufReader::new(s.as_bytes());
+	let oks = |v: &[&str]| -> ScanResult<Vec<String>> { Ok(v.iter().map(|&s| s.to_owned()).collect()) };
+
+	assert_eq!(read_argv(&mut r), oks(&["ls", "-l"]));
+	assert_eq!(read_argv(&mut r), oks(&["set", "name", "John\nSmith"]));
+	assert_eq!(read_argv(&mut r), oks(&["echo", "a", "b"]));
+	assert!(read_argv(&mut r).is_err());
+	assert!(read_argv(&mut r).is_err());
+}
+
 /**
 Reads a single UTF-8 encoded Unicode code point from a `Reader`.
 */
@@ -120,3 +160,10 @@ Reads a single line from standard input.
 pub fn stdin_read_line() -> IoResult<String> {
 	read_line(&mut ::std::io::stdio::stdin_raw())
 }
+
+/**
+Reads a single command from standard input, and splits it into arguments.  See `read_argv`.
+*/
+pub fn stdin_read_argv() -> ScanResult<Vec<String>> {
+	read_argv(&mut ::std::io::stdio::stdin_raw())
+}
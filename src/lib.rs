@@ -10,13 +10,19 @@ This is the runtime support library for the [`rust-scan`](https://github.com/Dan
 
 As a brief overview of where to look for things:
 
-- `compare_strs`: contains the `CompareStrs` trait and its implementations.  These are used for comparing scanned tokens for equality, and is how case-sensitive/case-insensitive comparisons are implemented.
+- `compare_strs`: contains the `CompareStrs` trait and its implementations.  These are used for comparing scanned tokens for equality, and is how case-sensitive/case-insensitive comparisons (including full Unicode case folding) are implemented.
 - `cursor`: contains the `ScanCursor` trait and the concrete `Cursor` type.  These are used to track scanning progress through an input string, and provide tokenisation, whitespace skipping and string comparison to scanners.
-- `io`: contains some IO support routines.  Most notably, a `read_line` function that does not require buffering.
+- `io`: contains some IO support routines.  Most notably, a `read_line` function that does not require buffering, and a `read_argv` function which reads a line and splits it into shell words.
+- `packrat`: contains routines for memoising recursive scanners, giving packrat parsing guarantees and support for left recursion.
+- `pratt`: contains `Pratt`, a configurable operator-precedence scanner for expressions.
+- `recovery`: contains support for recovering from scanning errors by skipping to a synchronisation token, so that several errors can be reported at once.
 - `scan_error`: contains the `ScanError` enumeration, which is (unsurprisingly) used to represent scanning errors.
 - `scanner`: contains the `Scanner` trait and the default implementations of it for various basic types.  These are how the `scan*` macros capture values.
-- `tokenizer`: contains the `Tokenizer` trait and its implementations.  These are used for extracting a token from an input string.
-- `whitespace`: contains the `Whitespace` trait and its implementations.  These are used for both skipping whitespace and turning whitespace into tokens.
+- `segment`: contains routines for Unicode text segmentation, such as finding the extent of a grapheme cluster.
+- `span`: contains the `Span` type, which records the range of the input something came from, and the `Spanned` scanner, which attaches a span to a scanned value.
+- `suggest`: contains routines for suggesting likely alternatives to mistyped tokens; used for "did you mean" hints in error messages.
+- `tokenizer`: contains the `Tokenizer` trait and its implementations.  These are used for extracting a token (and, optionally, what kind of token it is) from an input string.  If the `regex` feature is enabled, this also includes `RegexTokenizer`, which is configured using regular expressions at runtime.
+- `whitespace`: contains the `Whitespace` trait and its implementations.  These are used for both skipping whitespace (and, optionally, comments) and turning whitespace into tokens.
 
 ## License
 
@@ -27,22 +33,32 @@ This package is provided under the MIT license.
 #![feature(phase)]
 
 #[phase(plugin, link)] extern crate log;
+#[cfg(feature = "regex")] extern crate regex;
 
 pub use compare_strs::CompareStrs;
-pub use cursor::{Cursor, ScanCursor};
-pub use scan_error::{ScanResult, ScanError, OtherScanError, ScanIoError};
+pub use cursor::{Cursor, Fallback, MemoCursor, ScanCursor};
+pub use scan_error::{ScanResult, ScanError, OtherScanError, ScanIoError, ContextScanError, SpanScanError};
 pub use scanner::Scanner;
-pub use tokenizer::Tokenizer;
+pub use span::Span;
+pub use tokenizer::{TokenKind, Tokenizer};
 pub use whitespace::Whitespace;
 
 pub mod compare_strs;
 pub mod cursor;
 pub mod io;
+pub mod packrat;
+pub mod pratt;
+pub mod recovery;
 pub mod scan_error;
 pub mod scanner;
+pub mod segment;
+pub mod span;
+pub mod suggest;
 pub mod tokenizer;
 pub mod whitespace;
 
+mod tables;
+
 fn len_while(s: &str, pred: |char| -> bool) -> Option<uint> {
 	s.char_indices()
 		.take_while(|&(_,ch)| pred(ch))
@@ -52,3 +68,35 @@ fn len_while(s: &str, pred: |char| -> bool) -> Option<uint> {
 			next
 		})
 }
+
+fn in_ranges(table: &[(char, char)], ch: char) -> bool {
+	let (mut lo, mut hi) = (0, table.len());
+	while lo < hi {
+		let mid = lo + (hi - lo) / 2;
+		let (start, end) = table[mid];
+		if ch < start {
+			hi = mid;
+		} else if ch > end {
+			lo = mid + 1;
+		} else {
+			return true;
+		}
+	}
+	false
+}
+
+fn range_value<T: Copy>(table: &[(char, char, T)], ch: char) -> Option<T> {
+	let (mut lo, mut hi) = (0, table.len());
+	while lo < hi {
+		let mid = lo + (hi - lo) / 2;
+		let (start, end, value) = table[mid];
+		if ch < start {
+			hi = mid;
+		} else if ch > end {
+			lo = mid + 1;
+		} else {
+			return Some(value);
+		}
+	}
+	None
+}
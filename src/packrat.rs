@@ -0,0 +1,446 @@
+/*!
+This module provides packrat memoisation for recursive scanners.
+
+A recursive-descent scanner which backtracks can end up scanning the same rule at the same offset many times over; in the worst case, this takes exponential time.  Wrapping the body of a `Scanner::scan` implementation in `memoise` records the outcome of each rule at each offset, so that every rule is scanned at most once per offset.
+
+Memoisation requires a cursor which provides a `PackratTable` (see `ScanCursor::packrat_table`); at present, this is `MemoCursor`.  With any other cursor, `memoise` simply runs the scanner.
+
+`memoise` also supports left recursion (such as `Expr = Expr "-" Int | Int`) using seed growing: the left-recursive invocation initially fails, which lets a non-recursive alternative provide a seed, after which the rule is re-run repeatedly for as long as it consumes more input.  This only works with a cursor which provides a table; otherwise, a left-recursive scanner will recurse forever.
+
+Indirect left recursion (such as `Expr = Term; Term = Expr "-" Int | Int`) is handled the same way, following Warth, Douglass and Millstein's "Packrat Parsers Can Support Left Recursion".  The outcomes of the other memoised rules involved in the recursion depend on the seed, so they are re-evaluated on each round of growing, and forgotten once growing has finished.
+*/
+use std::any::{Any, TypeId};
+use std::borrow::ToOwned;
+use std::collections::{HashMap, HashSet};
+
+use super::{OtherScanError, ScanCursor, ScanError};
+
+/**
+The outcome of scanning a rule at a particular offset.
+*/
+enum Outcome {
+	/// The rule produced a value, and the offset at which it finished.
+	Success(Box<Any>, uint),
+	/// The rule failed.
+	Failure(ScanError),
+}
+
+/**
+What is known about a rule at a particular offset.
+*/
+enum Entry {
+	/// The rule is currently being scanned for the first time at this offset.
+	InProgress,
+	/// The rule was scanned as part of the left recursion of the given rule, which has not finished its first scan yet.  The outcome depends on that rule's seed, and will be forgotten once it is done.
+	Involved(TypeId, Outcome),
+	/// The rule has been scanned.
+	Done(Outcome),
+}
+
+/**
+A rule which is being scanned for the first time at some offset.
+*/
+struct Frame {
+	key: (TypeId, uint),
+	/// The left-recursive rule which this rule turned out to be part of, if any.  This may be the rule itself.
+	head: Option<TypeId>,
+	/// If this rule is left recursive, the other rules involved in the recursion.
+	involved: HashSet<TypeId>,
+}
+
+/**
+A left-recursive rule whose seed is being grown.
+*/
+struct Head {
+	rule: TypeId,
+	/// The other rules involved in the recursion.
+	involved: HashSet<TypeId>,
+	/// The involved rules which have not yet been re-evaluated in the current round of growing.
+	eval: HashSet<TypeId>,
+}
+
+/**
+Records the outcome of scanning each memoised rule at each offset.  Rules are identified by the type they produce.
+*/
+pub struct PackratTable {
+	entries: HashMap<(TypeId, uint), Entry>,
+	/// The rules which are currently being scanned for the first time at their offset, innermost last.
+	stack: Vec<Frame>,
+	/// The left-recursive rule being grown at each offset, if any.
+	heads: HashMap<uint, Head>,
+}
+
+impl PackratTable {
+	/**
+Construct an empty table.
+	*/
+	pub fn new() -> PackratTable {
+		PackratTable {
+			entries: HashMap::new(),
+			stack: vec![],
+			heads: HashMap::new(),
+		}
+	}
+
+	/**
+Returns the number of (rule, offset) pairs which have been recorded.
+	*/
+	pub fn len(&self) -> uint {
+		self.entries.len()
+	}
+
+	/**
+Marks every rule which has started scanning since the rule identified by `key` as being involved in its left recursion.
+	*/
+	fn involve(&mut self, key: (TypeId, uint)) {
+		let mut involved = HashSet::new();
+		for frame in self.stack.iter_mut().rev() {
+			frame.head = Some(key.0);
+			if frame.key == key {
+				frame.involved.extend(involved.into_iter());
+				return;
+			}
+			involved.insert(frame.key.0);
+		}
+	}
+
+	/**
+Forgets the outcomes of the given rules at `at`.
+	*/
+	fn forget(&mut self, rules: &HashSet<TypeId>, at: uint) {
+		for &rule in rules.iter() {
+			self.entries.remove(&(rule, at));
+		}
+	}
+}
+
+/**
+Scans a `T` using `scan`, memoising the outcome against the type of `T` and the cursor's offset.  This is intended to be used to wrap the entire body of a `Scanner::scan` implementation:
+
+```ignore
+impl<'a> Scanner<'a> for Expr {
+	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Expr, Cur), ScanError> {
+		packrat::memoise(cursor, |cur| scan_expr(cur))
+	}
+}
+```
+
+Because rules are identified by the type they produce, each memoised type should be produced by only one rule.
+*/
+pub fn memoise<'a, T, Cur, F>(cursor: &Cur, scan: F) -> Result<(T, Cur), ScanError>
+where T: Clone + 'static, Cur: ScanCursor<'a>, F: Fn(&Cur) -> Result<(T, Cur), ScanError> {
+	let table = match cursor.packrat_table() {
+		Some(table) => table,
+		None => return scan(cursor)
+	};
+
+	let start = cursor.consumed();
+	let key = (TypeId::of::<T>(), start);
+
+	// Note that the table must not remain borrowed while `scan` runs, since it will almost certainly want to use the table itself.
+	let reeval = {
+		let mut table = table.borrow_mut();
+		let table = &mut *table;
+
+		// While a left-recursive rule is being grown here, every other rule scanned here depends on its seed.
+		let reeval = match table.heads.get_mut(&start) {
+			Some(head) => {
+				if head.rule != key.0 && !table.entries.contains_key(&key) {
+					head.involved.insert(key.0);
+				}
+				head.eval.remove(&key.0)
+			},
+			None => false
+		};
+
+		if !reeval {
+			let hit = match table.entries.get(&key) {
+				Some(&Entry::Done(ref outcome)) => return replay(cursor, outcome),
+				Some(&Entry::Involved(head, ref outcome)) => Some((head, replay(cursor, outcome))),
+				Some(&Entry::InProgress) => Some((key.0, Err(left_recursion(start)))),
+				None => None
+			};
+			if let Some((head, result)) = hit {
+				table.involve((head, start));
+				return result;
+			}
+
+			table.entries.insert(key, Entry::InProgress);
+			table.stack.push(Frame { key: key, head: None, involved: HashSet::new() });
+		}
+
+		reeval
+	};
+
+	let mut result = scan(cursor);
+
+	if reeval {
+		record(&mut *table.borrow_mut(), key, &result);
+		return result;
+	}
+
+	let frame = table.borrow_mut().stack.pop().unwrap();
+	match frame.head {
+		None => {
+			record(&mut *table.borrow_mut(), key, &result);
+			return result;
+		},
+		Some(head) if head != key.0 => {
+			table.borrow_mut().entries.insert(key, Entry::Involved(head, outcome(&result)));
+			return result;
+		},
+		Some(_) => ()
+	}
+
+	// The rule is left recursive, and `result` is its seed.
+	let involved = frame.involved;
+	let mut end = match result {
+		Ok((_, ref cur)) => cur.consumed(),
+		Err(ref err) => {
+			// The placeholder failure is only meaningful to the recursive invocation; if it is all the rule could come up with, report that there was nothing for the rule to start from instead.
+			let err = if *err == left_recursion(start) {
+				cursor.expected("a non-left-recursive alternative")
+			} else {
+				err.clone()
+			};
+
+			// The involved rules only ever saw the placeholder failure.
+			let mut table = table.borrow_mut();
+			table.forget(&involved, start);
+			table.entries.insert(key, Entry::Done(Outcome::Failure(err.clone())));
+			return Err(err);
+		}
+	};
+
+	// Grow the seed: the left-recursive invocation will now see the previous result, so re-run the rule until it stops making progress.
+	let outer = {
+		let mut table = table.borrow_mut();
+		record(&mut *table, key, &result);
+		table.heads.insert(start, Head { rule: key.0, involved: involved, eval: HashSet::new() })
+	};
+
+	loop {
+		{
+			let mut table = table.borrow_mut();
+			let head = table.heads.get_mut(&start).unwrap();
+			head.eval = head.involved.clone();
+		}
+
+		let next = scan(cursor);
+		match next {
+			Ok((_, ref cur)) if cur.consumed() > end => end = cur.consumed(),
+			_ => break
+		}
+
+		result = next;
+		record(&mut *table.borrow_mut(), key, &result);
+	}
+
+	let mut table = table.borrow_mut();
+	let head = table.heads.remove(&start).unwrap();
+	table.forget(&head.involved, start);
+	if let Some(outer) = outer {
+		table.heads.insert(start, outer);
+	}
+
+	result
+}
+
+/**
+The failure seen by a left-recursive invocation of a rule before it has a seed.
+*/
+fn left_recursion(at: uint) -> ScanError {
+	OtherScanError("left recursion".to_owned(), at)
+}
+
+fn outcome<'a, T, Cur>(result: &Result<(T, Cur), ScanError>) -> Outcome
+where T: Clone + 'static, Cur: ScanCursor<'a> {
+	match *result {
+		Ok((ref value, ref cur)) => Outcome::Success(Box::new(value.clone()) as Box<Any>, cur.consumed()),
+		Err(ref err) => Outcome::Failure(err.clone())
+	}
+}
+
+fn record<'a, T, Cur>(table: &mut PackratTable, key: (TypeId, uint), result: &Result<(T, Cur), ScanError>)
+where T: Clone + 'static, Cur: ScanCursor<'a> {
+	table.entries.insert(key, Entry::Done(outcome(result)));
+}
+
+fn replay<'a, T, Cur>(cursor: &Cur, outcome: &Outcome) -> Result<(T, Cur), ScanError>
+where T: Clone + 'static, Cur: ScanCursor<'a> {
+	match *outcome {
+		Outcome::Success(ref value, end) => {
+			let value: &T = value.downcast_ref().unwrap();
+			Ok((value.clone(), cursor.slice_from(end - cursor.consumed())))
+		},
+		Outcome::Failure(ref err) => Err(err.clone())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::cell::Cell;
+
+	use Cursor;
+	use MemoCursor;
+	use ScanCursor;
+	use ScanError;
+	use Scanner;
+	use compare_strs::Exact;
+	use tokenizer::WordsAndInts;
+	use whitespace::Ignore;
+	use super::memoise;
+
+	/// `Diff = Diff "-" Int | Int`, rendered with explicit parentheses so that associativity can be checked.
+	#[derive(Clone, Eq, PartialEq, Show)]
+	struct Diff(String);
+
+	impl<'a> Scanner<'a> for Diff {
+		fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Diff, Cur), ScanError> {
+			fn minus<'a, Cur: ScanCursor<'a>>(cur: &Cur) -> Result<(Diff, Cur), ScanError> {
+				let (Diff(lhs), cur): (Diff, Cur) = try!(Scanner::scan(cur));
+				let cur = try!(cur.expect_tok("-"));
+				let (rhs, cur): (uint, Cur) = try!(Scanner::scan(&cur));
+				Ok((Diff(format!("({}-{})", lhs, rhs)), cur))
+			}
+
+			fn int<'a, Cur: ScanCursor<'a>>(cur: &Cur) -> Result<(Diff, Cur), ScanError> {
+				let (n, cur): (uint, Cur) = try!(Scanner::scan(cur));
+				Ok((Diff(n.to_string()), cur))
+			}
+
+			memoise(cursor, |cur| minus(cur).or_else(|_| int(cur)))
+		}
+	}
+
+	fn memo<'a>(s: &'a str) -> MemoCursor<'a, WordsAndInts, Ignore, Exact> {
+		MemoCursor::new(Cursor::new(s, WordsAndInts, Ignore, Exact))
+	}
+
+	#[test]
+	fn test_left_recursion() {
+		let diff = |s: &str| {
+			let r: Result<(Diff, _), _> = Scanner::scan(&memo(s));
+			r.map(|(Diff(d), c)| (d, c.consumed()))
+		};
+
+		assert_eq!(diff("1"), Ok(("1".to_string(), 1)));
+		assert_eq!(diff("1-2"), Ok(("(1-2)".to_string(), 3)));
+		assert_eq!(diff("1-2-3"), Ok(("((1-2)-3)".to_string(), 5)));
+		assert_eq!(diff("1-2-x"), Ok(("(1-2)".to_string(), 3)));
+		assert!(diff("x").is_err());
+	}
+
+	/// `Outer = Inner; Inner = Outer "-" Int | Int`, which is indirectly left recursive.
+	#[derive(Clone, Eq, PartialEq, Show)]
+	struct Outer(String);
+
+	#[derive(Clone, Eq, PartialEq, Show)]
+	struct Inner(String);
+
+	impl<'a> Scanner<'a> for Outer {
+		fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Outer, Cur), ScanError> {
+			memoise(cursor, |cur| {
+				let (Inner(s), cur): (Inner, Cur) = try!(Scanner::scan(cur));
+				Ok((Outer(s), cur))
+			})
+		}
+	}
+
+	impl<'a> Scanner<'a> for Inner {
+		fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Inner, Cur), ScanError> {
+			fn minus<'a, Cur: ScanCursor<'a>>(cur: &Cur) -> Result<(Inner, Cur), ScanError> {
+				let (Outer(lhs), cur): (Outer, Cur) = try!(Scanner::scan(cur));
+				let cur = try!(cur.expect_tok("-"));
+				let (rhs, cur): (uint, Cur) = try!(Scanner::scan(&cur));
+				Ok((Inner(format!("({}-{})", lhs, rhs)), cur))
+			}
+
+			fn int<'a, Cur: ScanCursor<'a>>(cur: &Cur) -> Result<(Inner, Cur), ScanError> {
+				let (n, cur): (uint, Cur) = try!(Scanner::scan(cur));
+				Ok((Inner(n.to_string()), cur))
+			}
+
+			memoise(cursor, |cur| minus(cur).or_else(|_| int(cur)))
+		}
+	}
+
+	#[test]
+	fn test_indirect_left_recursion() {
+		let outer = |s: &str| {
+			let r: Result<(Outer, _), _> = Scanner::scan(&memo(s));
+			r.map(|(Outer(d), c)| (d, c.consumed()))
+		};
+
+		assert_eq!(outer("1"), Ok(("1".to_string(), 1)));
+		assert_eq!(outer("1-2"), Ok(("(1-2)".to_string(), 3)));
+		assert_eq!(outer("1-2-3"), Ok(("((1-2)-3)".to_string(), 5)));
+		assert_eq!(outer("1-2-x"), Ok(("(1-2)".to_string(), 3)));
+		assert!(outer("x").is_err());
+	}
+
+	#[test]
+	fn test_left_recursion_error() {
+		// `Sum = Sum "+" Int | Int`, which prefers the error from the left-recursive alternative.  Before there is a seed, that is only a placeholder.
+		#[derive(Clone, Eq, PartialEq, Show)]
+		struct Sum(uint);
+
+		impl<'a> Scanner<'a> for Sum {
+			fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Sum, Cur), ScanError> {
+				memoise(cursor, |cur| {
+					let plus = || -> Result<(Sum, Cur), ScanError> {
+						let (Sum(lhs), cur): (Sum, Cur) = try!(Scanner::scan(cur));
+						let cur = try!(cur.expect_tok("+"));
+						let (rhs, cur): (uint, Cur) = try!(Scanner::scan(&cur));
+						Ok((Sum(lhs + rhs), cur))
+					};
+					let int = || -> Result<(Sum, Cur), ScanError> {
+						let (n, cur): (uint, Cur) = try!(Scanner::scan(cur));
+						Ok((Sum(n), cur))
+					};
+					plus().or_else(|e| int().map_err(|f| f.or(e)))
+				})
+			}
+		}
+
+		let sum = |s: &str| {
+			let r: Result<(Sum, _), _> = Scanner::scan(&memo(s));
+			r.map(|(Sum(n), c)| (n, c.consumed())).map_err(|e| format!("{}", e))
+		};
+
+		assert_eq!(sum("1+2+3"), Ok((6, 5)));
+		assert_eq!(sum("x"), Err("at offset 0: expected a non-left-recursive alternative, got `x`".to_string()));
+	}
+
+	#[test]
+	fn test_memoise() {
+		let calls = Cell::new(0u);
+		let scan_int = |cur: &MemoCursor<WordsAndInts, Ignore, Exact>| {
+			calls.set(calls.get() + 1);
+			let r: Result<(uint, _), _> = Scanner::scan(cur);
+			r
+		};
+
+		let cur = memo("42 x");
+		assert_eq!(memoise(&cur, |c| scan_int(c)).map(|(n,c)| (n, c.consumed())), Ok((42, 2)));
+		assert_eq!(memoise(&cur, |c| scan_int(c)).map(|(n,c)| (n, c.consumed())), Ok((42, 2)));
+		assert_eq!(calls.get(), 1);
+
+		let cur = cur.slice_from(3);
+		assert!(memoise(&cur, |c| scan_int(c)).is_err());
+		assert!(memoise(&cur, |c| scan_int(c)).is_err());
+		assert_eq!(calls.get(), 2);
+		assert_eq!(cur.packrat_table().unwrap().borrow().len(), 2);
+
+		// Without a table, nothing is memoised.
+		let cur = Cursor::new("42", WordsAndInts, Ignore, Exact);
+		let scan_plain = |c: &Cursor<WordsAndInts, Ignore, Exact>| {
+			calls.set(calls.get() + 1);
+			let r: Result<(uint, _), _> = Scanner::scan(c);
+			r
+		};
+		assert!(memoise(&cur, |c| scan_plain(c)).is_ok());
+		assert!(memoise(&cur, |c| scan_plain(c)).is_ok());
+		assert_eq!(calls.get(), 4);
+	}
+}
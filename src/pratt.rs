@@ -0,0 +1,236 @@
+/*!
+This module provides `Pratt`, a reusable operator-precedence scanner for expressions.
+
+Rather than hand-writing a precedence climbing scanner for each expression grammar, construct a `Pratt` describing the operators, and call its `scan` method from within your own `Scanner` implementation:
+
+```ignore
+fn num(n: i64) -> Expr { Expr::Num(n) }
+fn neg(e: Expr) -> Expr { Expr::Neg(box e) }
+fn add(a: Expr, b: Expr) -> Expr { Expr::Add(box a, box b) }
+fn mul(a: Expr, b: Expr) -> Expr { Expr::Mul(box a, box b) }
+
+let pratt = Pratt::new(num)
+	.prefix("-", 3, neg)
+	.infix("+", 1, Assoc::Left, add)
+	.infix("*", 2, Assoc::Left, mul)
+	.group("(", ")");
+
+let (expr, cursor) = try!(pratt.scan(cursor));
+```
+*/
+use std::borrow::ToOwned;
+
+use super::{ScanCursor, ScanError, Scanner};
+
+/**
+The associativity of an infix operator; that is, which way a chain of operators with the same precedence groups.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub enum Assoc {
+	/// `a - b - c` is `(a - b) - c`.
+	Left,
+	/// `a ^ b ^ c` is `a ^ (b ^ c)`.
+	Right,
+}
+
+/**
+An operator-precedence expression scanner.  Operands are scanned as an `L`, and converted into an expression of type `T`; operators combine expressions using the callbacks they were registered with.
+
+Each operator has a precedence; operators with a higher precedence bind more tightly.  Operators are matched as single tokens using the cursor's string comparator, so operators made of several code points (such as `**`) need a tokeniser which produces them as single tokens, such as `tokenizer::Punctuated`.
+*/
+pub struct Pratt<L, T> {
+	leaf: fn(L) -> T,
+	prefix: Vec<(String, uint, fn(T) -> T)>,
+	infix: Vec<(String, uint, Assoc, fn(T, T) -> T)>,
+	postfix: Vec<(String, uint, fn(T) -> T)>,
+	groups: Vec<(String, String)>,
+}
+
+impl<L, T> Pratt<L, T> {
+	/**
+Construct a scanner with no operators, which converts operands into expressions using `leaf`.
+	*/
+	pub fn new(leaf: fn(L) -> T) -> Pratt<L, T> {
+		Pratt {
+			leaf: leaf,
+			prefix: vec![],
+			infix: vec![],
+			postfix: vec![],
+			groups: vec![],
+		}
+	}
+
+	/**
+Add a prefix operator, such as unary minus.  `f` is called with the operand.
+	*/
+	pub fn prefix(mut self, op: &str, prec: uint, f: fn(T) -> T) -> Pratt<L, T> {
+		self.prefix.push((op.to_owned(), prec, f));
+		self
+	}
+
+	/**
+Add an infix operator, such as addition.  `f` is called with the left and right operands.
+	*/
+	pub fn infix(mut self, op: &str, prec: uint, assoc: Assoc, f: fn(T, T) -> T) -> Pratt<L, T> {
+		self.infix.push((op.to_owned(), prec, assoc, f));
+		self
+	}
+
+	/**
+Add a postfix operator, such as factorial.  `f` is called with the operand.
+
+If the same token is registered as both a postfix and an infix operator, the postfix operator takes precedence.
+	*/
+	pub fn postfix(mut self, op: &str, prec: uint, f: fn(T) -> T) -> Pratt<L, T> {
+		self.postfix.push((op.to_owned(), prec, f));
+		self
+	}
+
+	/**
+Add a pair of grouping tokens, such as parentheses.  Any expression may appear between `open` and `close`, regardless of the surrounding operators.
+	*/
+	pub fn group(mut self, open: &str, close: &str) -> Pratt<L, T> {
+		self.groups.push((open.to_owned(), close.to_owned()));
+		self
+	}
+
+	/**
+Scan an expression.  This stops at the first token which cannot continue the expression; it is up to the caller to decide whether anything may follow.
+
+Leading whitespace is skipped before each operand is scanned.
+	*/
+	pub fn scan<'a, Cur: ScanCursor<'a>>(&self, cursor: &Cur) -> Result<(T, Cur), ScanError>
+	where L: Scanner<'a> {
+		self.scan_bp(cursor, 0)
+	}
+
+	/**
+Scans an expression containing only operators which bind at least as tightly as `min_bp`.
+
+Internally, precedences are converted into binding powers: an infix operator of precedence `p` binds with `2p` on one side and `2p + 1` on the other, depending on its associativity.  Prefix operators bind their operand with `2p`, and postfix operators bind to the left with `2p`.
+	*/
+	fn scan_bp<'a, Cur: ScanCursor<'a>>(&self, cursor: &Cur, min_bp: uint) -> Result<(T, Cur), ScanError>
+	where L: Scanner<'a> {
+		let (mut lhs, mut cur) = try!(self.scan_operand(cursor));
+
+		'ops: loop {
+			for &(ref op, prec, f) in self.postfix.iter() {
+				if 2*prec < min_bp {
+					continue;
+				}
+				if let Ok(next) = cur.expect_tok(op.as_slice()) {
+					lhs = f(lhs);
+					cur = next;
+					continue 'ops;
+				}
+			}
+
+			for &(ref op, prec, assoc, f) in self.infix.iter() {
+				let (l_bp, r_bp) = match assoc {
+					Assoc::Left => (2*prec, 2*prec + 1),
+					Assoc::Right => (2*prec + 1, 2*prec),
+				};
+				if l_bp < min_bp {
+					continue;
+				}
+				if let Ok(next) = cur.expect_tok(op.as_slice()) {
+					let (rhs, next) = try!(self.scan_bp(&next, r_bp));
+					lhs = f(lhs, rhs);
+					cur = next;
+					continue 'ops;
+				}
+			}
+
+			break;
+		}
+
+		Ok((lhs, cur))
+	}
+
+	/// Scans a prefix operator and its operand, a group, or a leaf.
+	fn scan_operand<'a, Cur: ScanCursor<'a>>(&self, cursor: &Cur) -> Result<(T, Cur), ScanError>
+	where L: Scanner<'a> {
+		for &(ref op, prec, f) in self.prefix.iter() {
+			if let Ok(next) = cursor.expect_tok(op.as_slice()) {
+				let (operand, next) = try!(self.scan_bp(&next, 2*prec));
+				return Ok((f(operand), next));
+			}
+		}
+
+		for &(ref open, ref close) in self.groups.iter() {
+			if let Ok(next) = cursor.expect_tok(open.as_slice()) {
+				let (expr, next) = try!(self.scan_bp(&next, 0));
+				let next = try!(next.expect_tok(close.as_slice()));
+				return Ok((expr, next));
+			}
+		}
+
+		let cursor = cursor.pop_ws();
+		match Scanner::scan(&cursor) {
+			Ok((leaf, next)) => Ok(((self.leaf)(leaf), next)),
+			// Prefer a generic message unless the leaf scanner got further than the start of the operand.
+			Err(err) => Err(err.or(cursor.expected("expression")))
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use Cursor;
+	use ScanCursor;
+	use compare_strs::Exact;
+	use tokenizer::{Punctuated, WordsAndInts};
+	use whitespace::Ignore;
+	use super::{Assoc, Pratt};
+
+	fn leaf(n: uint) -> String { n.to_string() }
+	fn neg(a: String) -> String { format!("(-{})", a) }
+	fn fact(a: String) -> String { format!("({}!)", a) }
+	fn add(a: String, b: String) -> String { format!("({}+{})", a, b) }
+	fn sub(a: String, b: String) -> String { format!("({}-{})", a, b) }
+	fn mul(a: String, b: String) -> String { format!("({}*{})", a, b) }
+	fn pow(a: String, b: String) -> String { format!("({}**{})", a, b) }
+
+	fn pratt() -> Pratt<uint, String> {
+		Pratt::new(leaf)
+			.prefix("-", 3, neg)
+			.infix("+", 1, Assoc::Left, add)
+			.infix("-", 1, Assoc::Left, sub)
+			.infix("*", 2, Assoc::Left, mul)
+			.infix("**", 4, Assoc::Right, pow)
+			.postfix("!", 5, fact)
+			.group("(", ")")
+	}
+
+	fn scan(s: &str) -> Result<(String, uint), String> {
+		let tok = Punctuated::new(WordsAndInts, &["**"]);
+		let cur = Cursor::new(s, tok, Ignore, Exact);
+		pratt().scan(&cur)
+			.map(|(e, c)| (e, c.consumed()))
+			.map_err(|e| format!("{}", e))
+	}
+
+	#[test]
+	fn test_pratt() {
+		let ok = |s: &str| scan(s).ok().unwrap().0;
+
+		assert_eq!(ok("1").as_slice(), "1");
+		assert_eq!(ok("1 + 2").as_slice(), "(1+2)");
+		assert_eq!(ok("1 + 2 * 3").as_slice(), "(1+(2*3))");
+		assert_eq!(ok("1 * 2 + 3").as_slice(), "((1*2)+3)");
+		assert_eq!(ok("1 - 2 - 3").as_slice(), "((1-2)-3)");
+		assert_eq!(ok("2 ** 3 ** 4").as_slice(), "(2**(3**4))");
+		assert_eq!(ok("-2 ** 2").as_slice(), "(-(2**2))");
+		assert_eq!(ok("-2 * 3").as_slice(), "((-2)*3)");
+		assert_eq!(ok("- -2").as_slice(), "(-(-2))");
+		assert_eq!(ok("3! * 2").as_slice(), "((3!)*2)");
+		assert_eq!(ok("-3!").as_slice(), "(-(3!))");
+		assert_eq!(ok("(1 + 2) * 3").as_slice(), "((1+2)*3)");
+		assert_eq!(ok("2 * (3 - (4))").as_slice(), "(2*(3-4))");
+
+		assert_eq!(scan("1 + 2 x"), Ok(("(1+2)".to_string(), 5)));
+		assert_eq!(scan(""), Err("at offset 0: expected expression, got end of input".to_string()));
+		assert_eq!(scan("1 + x"), Err("at offset 4: expected expression, got `x`".to_string()));
+		assert_eq!(scan("(1 + 2"), Err("at offset 6: expected `)`, got end of input".to_string()));
+	}
+}
@@ -0,0 +1,158 @@
+/*!
+This module provides support for recovering from scanning errors, so that several errors can be reported at once.
+
+The general approach is to divide the input into items separated by synchronisation tokens (such as line breaks or `;`).  When scanning an item fails, the error is recorded, the rest of the item is skipped, and scanning continues with the next item.  For the common case of a sequence of items of a single type, use `scan_recovering`; for anything more involved, use a `Recovery` directly.
+*/
+use super::{ScanCursor, ScanError, Scanner};
+
+/**
+Collects errors from a scan which recovers from them.
+*/
+#[derive(Clone, Eq, PartialEq, Show)]
+pub struct Recovery {
+	errors: Vec<ScanError>,
+}
+
+impl Recovery {
+	/**
+Construct a collector with no errors.
+	*/
+	pub fn new() -> Recovery {
+		Recovery {
+			errors: vec![],
+		}
+	}
+
+	/**
+Record an error.
+	*/
+	pub fn record(&mut self, err: ScanError) {
+		self.errors.push(err);
+	}
+
+	/**
+Takes the result of scanning an item starting at `cursor`.  On success, returns the value and the successor cursor.  On failure, records the error and returns a cursor just past the next synchronisation token (see `skip_past`), starting from where the error occurred.
+	*/
+	pub fn recover<'a, T, Cur: ScanCursor<'a>>(&mut self, cursor: &Cur, result: Result<(T, Cur), ScanError>, sync: &[&str]) -> (Option<T>, Cur) {
+		match result {
+			Ok((value, cur)) => (Some(value), cur),
+			Err(err) => {
+				let from = match err.offset() {
+					Some(at) if at >= cursor.consumed() => cursor.slice_from(at - cursor.consumed()),
+					_ => cursor.clone()
+				};
+				self.record(err);
+				(None, skip_past(&from, sync))
+			}
+		}
+	}
+
+	/**
+Returns the errors recorded so far, in the order they were recorded.
+	*/
+	pub fn errors(&self) -> &[ScanError] {
+		self.errors.as_slice()
+	}
+
+	/**
+Returns `value` if no errors were recorded, or all of the recorded errors otherwise.
+	*/
+	pub fn finish<T>(self, value: T) -> Result<T, Vec<ScanError>> {
+		if self.errors.len() == 0 {
+			Ok(value)
+		} else {
+			Err(self.errors)
+		}
+	}
+}
+
+/**
+Returns a cursor just past the next token which matches any of `sync` (using the cursor's string comparator), or at the end of the input if there is no such token.
+*/
+pub fn skip_past<'a, Cur: ScanCursor<'a>>(cursor: &Cur, sync: &[&str]) -> Cur {
+	let mut cur = cursor.clone();
+	while let Some((tok, next)) = cur.pop_token() {
+		cur = next;
+		if sync.iter().any(|s| cur.compare_strs(*s, tok)) {
+			break;
+		}
+	}
+	cur
+}
+
+#[test]
+fn test_skip_past() {
+	use Cursor;
+	use compare_strs::Exact;
+	use tokenizer::WordsAndInts;
+	use whitespace::ExplicitNewline;
+
+	let cur = Cursor::new("a b; c\nd", WordsAndInts, ExplicitNewline, Exact);
+	assert_eq!(skip_past(&cur, &[";"]).consumed(), 4);
+	assert_eq!(skip_past(&cur, &["\n"]).consumed(), 7);
+	assert_eq!(skip_past(&cur, &["\n", ";"]).consumed(), 4);
+	assert_eq!(skip_past(&cur, &["x"]).consumed(), 8);
+	assert_eq!(skip_past(&cur, &[]).consumed(), 8);
+}
+
+/**
+Scans a sequence of `T`s separated (or terminated) by any of the `sync` tokens, recovering from errors.  Leading whitespace is skipped before each item, and empty items are ignored.
+
+Each item must be followed by a synchronisation token or the end of input.  If scanning an item fails, or it is followed by anything else, the error is recorded and scanning resumes after the next synchronisation token.  The sequence extends to the end of input.
+
+Returns the scanned values if there were no errors, or every error encountered otherwise.
+*/
+pub fn scan_recovering<'a, T: Scanner<'a>, Cur: ScanCursor<'a>>(cursor: &Cur, sync: &[&str]) -> Result<(Vec<T>, Cur), Vec<ScanError>> {
+	let is_sync = |cur: &Cur, tok: &str| sync.iter().any(|s| cur.compare_strs(*s, tok));
+
+	let mut recovery = Recovery::new();
+	let mut values = vec![];
+	let mut cur = cursor.clone();
+
+	loop {
+		match cur.pop_token() {
+			None => break,
+			Some((tok, next)) => if is_sync(&cur, tok) {
+				cur = next;
+				continue;
+			}
+		}
+
+		let result = Scanner::scan(&cur.pop_ws()).and_then(|(value, next): (T, Cur)| {
+			match next.pop_token() {
+				None => Ok((value, next)),
+				Some((tok, after)) if is_sync(&next, tok) => Ok((value, after)),
+				_ => Err(next.expected_one_of(sync))
+			}
+		});
+
+		let (value, next) = recovery.recover(&cur, result, sync);
+		values.extend(value.into_iter());
+		cur = next;
+	}
+
+	recovery.finish((values, cur))
+}
+
+#[test]
+fn test_scan_recovering() {
+	use Cursor;
+	use compare_strs::Exact;
+	use tokenizer::WordsAndInts;
+	use whitespace::ExplicitNewline;
+
+	let scan = |s: &str| {
+		let cur = Cursor::new(s, WordsAndInts, ExplicitNewline, Exact);
+		let r: Result<(Vec<uint>, _), _> = scan_recovering(&cur, &["\n", ";"]);
+		r.map(|(vs, c)| (vs, c.consumed()))
+			.map_err(|es| es.iter().map(|e| format!("{}", e)).collect::<Vec<String>>())
+	};
+
+	assert_eq!(scan(""), Ok((vec![], 0)));
+	assert_eq!(scan("1; 2\n\n3;"), Ok((vec![1, 2, 3], 8)));
+	assert_eq!(scan("1; 2\nx; 3; y z; 4 5\n6"), Err(vec![
+		"at offset 5: expected unsigned integer, got `x`".to_string(),
+		"at offset 11: expected unsigned integer, got `y`".to_string(),
+		"at offset 17: expected `\\n`, `;`, got `5`".to_string(),
+	]));
+}
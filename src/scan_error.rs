@@ -4,7 +4,9 @@ This module provides the `ScanError` type, which encodes the various kinds of er
 use std::fmt;
 use std::fmt::Formatter;
 
-pub use self::ScanError::{OtherScanError, ScanIoError};
+use span::Span;
+
+pub use self::ScanError::{ContextScanError, OtherScanError, ScanIoError, SpanScanError};
 
 pub type ScanResult<T> = Result<T, ScanError>;
 
@@ -21,6 +23,14 @@ Some other scan error occurred.  The `String` is the message describing the prob
 Indicates that an underlying IO operation failed.
 	*/
 	ScanIoError(::std::io::IoError),
+	/**
+Indicates that another error occurred while scanning something in particular.  The `String` is a label describing what was being scanned, such as ``"`port`"``; see `in_context`.
+	*/
+	ContextScanError(String, Box<ScanError>),
+	/**
+A scan error which concerns a particular range of the input, such as a value which was scanned successfully but failed validation.  The `String` is the message describing the problem, the `Span` is the range of input concerned.
+	*/
+	SpanScanError(String, Span),
 }
 
 impl ScanError {
@@ -31,24 +41,94 @@ Takes two `ScanError` values and returns the "most interesting" one.  The genera
 * Scan errors which happened further along the input take precedence.  This should hopefully be the error from the most relevant arm.
 	*/
 	pub fn or(self, other: ScanError) -> ScanError {
-		match (self, other) {
-			(ScanIoError(ioerr), _) | (_, ScanIoError(ioerr)) => ScanIoError(ioerr),
-			(OtherScanError(msga, offa), OtherScanError(msgb, offb)) => {
-				if offa > offb {
-					OtherScanError(msga, offa)
-				} else {
-					OtherScanError(msgb, offb)
-				}
-			}
+		match (self.offset(), other.offset()) {
+			(None, _) => self,
+			(_, None) => other,
+			(Some(offa), Some(offb)) => if offa > offb { self } else { other }
+		}
+	}
+
+	/**
+Wraps this error to indicate that it occurred while scanning `label`.  Labels are shown from the innermost outwards, so an error wrapped first with ``"`port`"`` and then with ``"`listen` directive"`` is displayed as:
+
+```text
+at offset 12: while scanning `port` in `listen` directive: expected 16-bit unsigned integer, got `x`
+```
+
+This is intended to be used with `map_err` on the result of scanning the thing in question.
+	*/
+	pub fn in_context(self, label: &str) -> ScanError {
+		use std::borrow::ToOwned;
+		ContextScanError(label.to_owned(), Box::new(self))
+	}
+
+	/**
+Returns the offset within the input at which the error occurred, or `None` for an IO error.
+	*/
+	pub fn offset(&self) -> Option<uint> {
+		match *self {
+			OtherScanError(_, at) => Some(at),
+			ScanIoError(_) => None,
+			ContextScanError(_, ref err) => err.offset(),
+			SpanScanError(_, span) => Some(span.start),
+		}
+	}
+
+	/**
+Returns the range of input the error concerns, if it is known.
+	*/
+	pub fn span(&self) -> Option<Span> {
+		match *self {
+			OtherScanError(..) | ScanIoError(_) => None,
+			ContextScanError(_, ref err) => err.span(),
+			SpanScanError(_, span) => Some(span),
 		}
 	}
 }
 
+#[test]
+fn test_or() {
+	let a = OtherScanError("a".to_string(), 1);
+	let b = OtherScanError("b".to_string(), 2);
+
+	assert_eq!(a.clone().or(b.clone()), b);
+	assert_eq!(b.clone().or(a.clone()), b);
+	assert_eq!(a.clone().or(a.clone().in_context("x")), a.clone().in_context("x"));
+	assert_eq!(b.clone().in_context("x").or(a.clone()), b.clone().in_context("x"));
+}
+
 impl fmt::Show for ScanError {
 	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
 		match self {
 			&OtherScanError(ref msg, at) => write!(f, "at offset {}: {}", at, msg),
 			&ScanIoError(ref err) => write!(f, "io error: {}", err),
+			&SpanScanError(ref msg, span) => write!(f, "at offset {}: {}", span.start, msg),
+			&ContextScanError(ref label, ref err) => {
+				// Collect the labels from the innermost outwards.
+				let mut labels = label.clone();
+				let mut err = &**err;
+				while let &ContextScanError(ref label, ref inner) = err {
+					labels = format!("{} in {}", label, labels);
+					err = &**inner;
+				}
+
+				match err {
+					&OtherScanError(ref msg, at) => write!(f, "at offset {}: while scanning {}: {}", at, labels, msg),
+					&SpanScanError(ref msg, span) => write!(f, "at offset {}: while scanning {}: {}", span.start, labels, msg),
+					_ => write!(f, "while scanning {}: {}", labels, err),
+				}
+			},
 		}
 	}
 }
+
+#[test]
+fn test_in_context() {
+	let err = OtherScanError("expected 16-bit unsigned integer, got `x`".to_string(), 12);
+
+	assert_eq!(format!("{}", err.clone().in_context("`port`")),
+		"at offset 12: while scanning `port`: expected 16-bit unsigned integer, got `x`");
+	assert_eq!(format!("{}", err.clone().in_context("`port`").in_context("`listen` directive")),
+		"at offset 12: while scanning `port` in `listen` directive: expected 16-bit unsigned integer, got `x`");
+	assert_eq!(err.clone().in_context("`port`").offset(), Some(12));
+}
@@ -4,7 +4,12 @@ This module provides both the `Scanner` trait, and the implementations for vario
 If you want to implement your own, the simplest way is to use the `scanner!` macro from the main `scan` package.  However, you can also implement a scanner by hand.
 */
 
-use super::{ScanCursor, ScanError};
+use std::cmp::Ordering;
+
+use super::{ScanCursor, ScanError, SpanScanError};
+use segment::grapheme_len;
+use span::Span;
+use tokenizer::quoted::unquote;
 
 /**
 This macro is a shortcut used in this module.  It implements a scanner for the type `T` given two constraints:
@@ -56,7 +61,7 @@ impl<'a> Scanner<'a> for bool {
 	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(bool, Cur), ScanError> {
 		cursor.expect_tok("true").map(|c| (true, c))
 			.or_else(|_| cursor.expect_tok("false").map(|c| (false, c)))
-			.or_else(|_| Err(cursor.expected("`true` or `false`")))
+			.or_else(|_| Err(cursor.expected_one_of(&["true", "false"])))
 	}
 }
 
@@ -72,6 +77,23 @@ impl<'a> Scanner<'a> for char {
 	}
 }
 
+/**
+An extended grapheme cluster, as defined by Unicode Standard Annex #29.  This is what a user would generally consider to be a single "character"; for example, an `e` followed by a combining acute accent, or an emoji followed by a skin tone modifier.
+
+Like the scanner for `char`, this does *not* skip leading whitespace, and does not use the tokeniser.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub struct Grapheme<'a>(pub &'a str);
+
+impl<'a> Scanner<'a> for Grapheme<'a> {
+	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Grapheme<'a>, Cur), ScanError> {
+		match grapheme_len(cursor.tail_str()) {
+			Some(end) => Ok((Grapheme(cursor.str_slice_to(end)), cursor.slice_from(end))),
+			None => Err(cursor.expected("character"))
+		}
+	}
+}
+
 impl<'a> Scanner<'a> for &'a str {
 	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(&'a str, Cur), ScanError> {
 		cursor.pop_token().map(|sc| Ok(sc))
@@ -87,6 +109,23 @@ impl<'a> Scanner<'a> for String {
 	}
 }
 
+/**
+Scans a single token and, if it is a quoted string (as recognised by `tokenizer::Quoted`), removes the quotes and processes any escapes.  Any other token is taken as-is.
+
+This is intended to be used with a tokeniser which produces quoted strings as single tokens, such as `tokenizer::QuotedOrSpaceDelimited`.
+*/
+#[derive(Clone, Eq, PartialEq, Show)]
+pub struct Unquoted(pub String);
+
+impl<'a> Scanner<'a> for Unquoted {
+	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Unquoted, Cur), ScanError> {
+		use std::borrow::ToOwned;
+		cursor.pop_token()
+			.map(|(s,c)| Ok((Unquoted(unquote(s).unwrap_or_else(|| s.to_owned())), c)))
+			.unwrap_or_else(|| Err(cursor.expected("any token")))
+	}
+}
+
 impl<'a> Scanner<'a> for () {
 	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<((), Cur), ScanError> {
 		Ok(((), cursor.clone()))
@@ -106,6 +145,131 @@ from_str_slice_scanner! { scan_uint -> u32 as "32-bit unsigned integer" }
 from_str_slice_scanner! { scan_uint -> u64 as "64-bit unsigned integer" }
 from_str_slice_scanner! { scan_uint -> uint as "unsigned integer" }
 
+/**
+Scans a value which must lie within an inclusive range; for example, a port number (`Bounded::new(1u16, 65535)`) or a percentage (`Bounded::new(0u8, 100)`).  This is intended for use with the numeric types.
+
+Leading whitespace is skipped before the value is scanned.  If the value is out of range, or is an integer literal too large to fit in `T` at all, the error describes the range and covers the offending literal:
+
+```text
+at offset 7: value 70000 out of range 1..=65535
+```
+
+Because the range is a value rather than part of the type, `Bounded` does not implement `Scanner`, and so cannot be used as a type in `scan!` patterns.  Instead, call its `scan` method from a `Scanner` implementation of your own:
+
+```ignore
+impl<'a> Scanner<'a> for Port {
+	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Port, Cur), ScanError> {
+		let (n, cur) = try!(Bounded::new(1u16, 65535).scan(cursor));
+		Ok((Port(n), cur))
+	}
+}
+```
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub struct Bounded<T> {
+	pub min: T,
+	pub max: T,
+}
+
+impl<T: PartialOrd + ::std::fmt::Show> Bounded<T> {
+	/**
+Construct a scanner for values between `min` and `max`, inclusive.
+	*/
+	pub fn new(min: T, max: T) -> Bounded<T> {
+		Bounded {
+			min: min,
+			max: max,
+		}
+	}
+
+	/**
+Scans a `T`, checking that it lies within the range.
+	*/
+	pub fn scan<'a, Cur: ScanCursor<'a>>(&self, cursor: &Cur) -> Result<(T, Cur), ScanError>
+	where T: Scanner<'a> {
+		let cursor = cursor.pop_ws();
+		match Scanner::scan(&cursor) {
+			Ok((value, cur)) => {
+				if self.min <= value && value <= self.max {
+					Ok((value, cur))
+				} else {
+					Err(self.out_of_range(value, cursor.span_to(&cur)))
+				}
+			},
+			Err(err) => match scan_int(cursor.tail_str()) {
+				// The input might be an integer which doesn't fit in a `T` at all; only report it as out of range if it really is.  Otherwise, something like `-0` for an unsigned type would be reported as out of the range which contains it.
+				Some(end) => {
+					let lit = cursor.str_slice_to(end);
+					let min = format!("{}", self.min);
+					let max = format!("{}", self.max);
+					let below = cmp_int_literals(lit, min.as_slice()) == Some(Ordering::Less);
+					let above = cmp_int_literals(lit, max.as_slice()) == Some(Ordering::Greater);
+					if below || above {
+						Err(self.out_of_range(lit, cursor.span_to(&cursor.slice_from(end))))
+					} else {
+						Err(err)
+					}
+				},
+				None => Err(err)
+			}
+		}
+	}
+
+	fn out_of_range<V: ::std::fmt::Show>(&self, value: V, span: Span) -> ScanError {
+		SpanScanError(format!("value {} out of range {}..={}", value, self.min, self.max), span)
+	}
+}
+
+/**
+Numerically compares two decimal integer literals, each with an optional leading `-`, regardless of their magnitude.  Returns `None` if either is not such a literal.
+*/
+fn cmp_int_literals(a: &str, b: &str) -> Option<Ordering> {
+	fn split(s: &str) -> Option<(bool, &str)> {
+		let (neg, digits) = if s.starts_with("-") { (true, s.slice_from(1)) } else { (false, s) };
+		if digits.len() == 0 || !digits.chars().all(|ch| '0' <= ch && ch <= '9') {
+			return None;
+		}
+		let digits = digits.slice_from(digits.find(|ch: char| ch != '0').unwrap_or(digits.len()));
+		// Negative zero is just zero.
+		Some((neg && digits.len() > 0, digits))
+	}
+
+	// With leading zeroes removed, a longer string of digits is a larger magnitude.
+	fn cmp_mag(a: &str, b: &str) -> Ordering {
+		match a.len().cmp(&b.len()) {
+			Ordering::Equal => a.cmp(b),
+			ord => ord
+		}
+	}
+
+	let ((a_neg, a), (b_neg, b)) = match (split(a), split(b)) {
+		(Some(a), Some(b)) => (a, b),
+		_ => return None
+	};
+
+	Some(match (a_neg, b_neg) {
+		(false, true) => Ordering::Greater,
+		(true, false) => Ordering::Less,
+		(false, false) => cmp_mag(a, b),
+		(true, true) => cmp_mag(b, a),
+	})
+}
+
+#[test]
+fn test_cmp_int_literals() {
+	use std::cmp::Ordering::{Less, Equal, Greater};
+
+	assert_eq!(cmp_int_literals("1", "2"), Some(Less));
+	assert_eq!(cmp_int_literals("70000", "65535"), Some(Greater));
+	assert_eq!(cmp_int_literals("007", "7"), Some(Equal));
+	assert_eq!(cmp_int_literals("-0", "0"), Some(Equal));
+	assert_eq!(cmp_int_literals("-5", "3"), Some(Less));
+	assert_eq!(cmp_int_literals("-50", "-6"), Some(Less));
+	assert_eq!(cmp_int_literals("99999999999999999999999", "18446744073709551615"), Some(Greater));
+	assert_eq!(cmp_int_literals("1", "1.5"), None);
+	assert_eq!(cmp_int_literals("-", "1"), None);
+}
+
 /**
 This function is just a short-hand way of accessing the byte offset *after* the code point at a given position in a string.
 */
@@ -221,6 +385,11 @@ mod test {
 		assert!(scan_a::<bool>("off").err().is_some());
 		assert!(scan_a::<bool>("1").err().is_some());
 		assert!(scan_a::<bool>("0").err().is_some());
+
+		let msg = |s: &str| format!("{}", scan_a::<bool>(s).err().unwrap());
+		assert_eq!(msg("fasle").as_slice(), "at offset 0: expected `true`, `false`, got `fasle`, did you mean `false`?");
+		assert_eq!(msg("yes").as_slice(), "at offset 0: expected `true`, `false`, got `yes`");
+		assert_eq!(msg("").as_slice(), "at offset 0: expected `true`, `false`, got end of input");
 	}
 
 	#[test]
@@ -231,6 +400,21 @@ mod test {
 		assert!(scan_a::<char>("日本語").unwrap().0 == '日');
 	}
 
+	#[test]
+	fn test_grapheme() {
+		use super::Grapheme;
+
+		assert!(scan_a::<Grapheme>("").err().is_some());
+		assert!(scan_a::<Grapheme>("x").unwrap().0 == Grapheme("x"));
+		assert!(scan_a::<Grapheme>("xy").unwrap().0 == Grapheme("x"));
+		assert!(scan_a::<Grapheme>(" x").unwrap().0 == Grapheme(" "));
+		assert!(scan_a::<Grapheme>("日本語").unwrap().0 == Grapheme("日"));
+		assert!(scan_a::<Grapheme>("e\u{301}x").unwrap().0 == Grapheme("e\u{301}"));
+		assert!(scan_a::<Grapheme>("\u{1f44d}\u{1f3fd}!").unwrap().0 == Grapheme("\u{1f44d}\u{1f3fd}"));
+		assert!(scan_a::<Grapheme>("\r\nx").unwrap().0 == Grapheme("\r\n"));
+		assert!(scan_a::<char>("e\u{301}x").unwrap().0 == 'e');
+	}
+
 	#[test]
 	fn test_floats() {
 		use std::str::FromStr;
@@ -317,6 +501,71 @@ mod test {
 		assert!(scan_a::<uint>("-1_234").err().is_some());
 	}
 
+	#[test]
+	fn test_bounded() {
+		use super::Bounded;
+		use span::Span;
+		use ScanCursor;
+
+		let port = Bounded::new(1u16, 65535);
+		let scan_port = |s: &str| port.scan(&cur(s)).map(|(v, c)| (v, c.consumed()));
+
+		assert_eq!(scan_port("80"), Ok((80, 2)));
+		assert_eq!(scan_port("1 x"), Ok((1, 1)));
+		assert_eq!(scan_port("65535"), Ok((65535, 5)));
+
+		let err = scan_port("0").err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 0: value 0 out of range 1..=65535");
+		assert_eq!(err.span(), Some(Span { start: 0, end: 1 }));
+
+		let err = port.scan(&cur("port 70000").slice_from(4)).err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 5: value 70000 out of range 1..=65535");
+		assert_eq!(err.span(), Some(Span { start: 5, end: 10 }));
+
+		assert_eq!(scan_port(" 80"), Ok((80, 3)));
+		let err = scan_port(" 70000").err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 1: value 70000 out of range 1..=65535");
+		assert_eq!(err.span(), Some(Span { start: 1, end: 6 }));
+
+		let err = Bounded::new(0u8, 255).scan(&cur("-0")).err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 0: expected 8-bit unsigned integer, got `-`");
+		assert_eq!(err.span(), None);
+
+		let err = scan_port("-1").err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 0: value -1 out of range 1..=65535");
+
+		let err = scan_port("x").err().unwrap();
+		assert_eq!(format!("{}", err), "at offset 0: expected 16-bit unsigned integer, got `x`");
+		assert_eq!(err.span(), None);
+
+		let percent = Bounded::new(0i32, 100);
+		assert_eq!(percent.scan(&cur("-5")).err().map(|e| format!("{}", e)), Some("at offset 0: value -5 out of range 0..=100".to_string()));
+		assert_eq!(percent.scan(&cur("100")).ok().map(|(v,_)| v), Some(100));
+	}
+
+	#[test]
+	fn test_unquoted() {
+		use super::Unquoted;
+		use tokenizer::QuotedOrSpaceDelimited;
+
+		use ScanCursor;
+
+		fn scan<'a>(s: &'a str) -> Result<(Unquoted, Cursor<'a, QuotedOrSpaceDelimited, Ignore, CaseInsensitive>), ScanError> {
+			Scanner::scan(&Cursor::new(s, QuotedOrSpaceDelimited, Ignore, CaseInsensitive))
+		}
+
+		fn unq(s: &str) -> String {
+			let Unquoted(v) = scan(s).ok().unwrap().0;
+			v
+		}
+
+		assert!(scan("").err().is_some());
+		assert_eq!(unq("abc def").as_slice(), "abc");
+		assert_eq!(unq("\"abc def\" ghi").as_slice(), "abc def");
+		assert_eq!(unq("  'it\\'s' ghi").as_slice(), "it's");
+		assert_eq!(scan("\"abc def\" ghi").ok().unwrap().1.consumed(), 9);
+	}
+
 	#[test]
 	fn test_str() {
 		assert!(scan_a::<&str>("").err().is_some());
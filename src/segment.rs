@@ -0,0 +1,241 @@
+/*!
+This module provides Unicode text segmentation, as described by Unicode Standard Annex #29.
+
+These are used by the `Grapheme` scanner, the `Fallback::Grapheme` cursor mode and the `UnicodeWords` tokeniser, but are also available for use by custom tokenisers and scanners.
+*/
+use super::{in_ranges, range_value};
+use tables::{EXTENDED_PICTOGRAPHIC, GRAPHEME_CLUSTER_BREAK, GraphemeCat, WORD_BREAK, WordCat};
+use tables::GraphemeCat::{CR, LF, Control, Extend, ZWJ, RegionalIndicator, Prepend, SpacingMark, L, V, T, LV, LVT};
+
+/**
+Returns the length, in bytes, of the extended grapheme cluster at the start of `s`, or `None` if `s` is empty.
+*/
+pub fn grapheme_len(s: &str) -> Option<uint> {
+	let mut chars = s.char_indices();
+	let first = match chars.next() {
+		Some((_, ch)) => ch,
+		None => return None
+	};
+
+	let mut prev = gcb(first);
+	// Number of consecutive regional indicators ending at `prev`.
+	let mut ri_run = if prev == Some(RegionalIndicator) { 1u } else { 0 };
+	// Does the cluster so far end with `Extended_Pictographic Extend*`?
+	let mut pict = is_ext_pict(first);
+	// Does the cluster so far end with `Extended_Pictographic Extend* ZWJ`?
+	let mut pict_zwj = false;
+
+	for (i, ch) in chars {
+		let cur = gcb(ch);
+
+		let boundary = match (prev, cur) {
+			// GB3
+			(Some(CR), Some(LF)) => false,
+			// GB4, GB5
+			(Some(CR), _) | (Some(LF), _) | (Some(Control), _) => true,
+			(_, Some(CR)) | (_, Some(LF)) | (_, Some(Control)) => true,
+			// GB6, GB7, GB8
+			(Some(L), Some(L)) | (Some(L), Some(V)) | (Some(L), Some(LV)) | (Some(L), Some(LVT)) => false,
+			(Some(LV), Some(V)) | (Some(LV), Some(T)) | (Some(V), Some(V)) | (Some(V), Some(T)) => false,
+			(Some(LVT), Some(T)) | (Some(T), Some(T)) => false,
+			// GB9, GB9a, GB9b
+			(_, Some(Extend)) | (_, Some(ZWJ)) | (_, Some(SpacingMark)) => false,
+			(Some(Prepend), _) => false,
+			// GB11
+			(Some(ZWJ), _) if pict_zwj && is_ext_pict(ch) => false,
+			// GB12, GB13
+			(Some(RegionalIndicator), Some(RegionalIndicator)) => ri_run % 2 == 0,
+			// GB999
+			_ => true
+		};
+
+		if boundary {
+			return Some(i);
+		}
+
+		pict_zwj = pict && cur == Some(ZWJ);
+		pict = is_ext_pict(ch) || (pict && cur == Some(Extend));
+		ri_run = if cur == Some(RegionalIndicator) { ri_run + 1 } else { 0 };
+		prev = cur;
+	}
+
+	Some(s.len())
+}
+
+#[test]
+fn test_grapheme_len() {
+	let gl = |s:&str| grapheme_len(s);
+
+	assert_eq!(gl(""), None);
+	assert_eq!(gl("x"), Some(1));
+	assert_eq!(gl("xy"), Some(1));
+	assert_eq!(gl("日本"), Some(3));
+	assert_eq!(gl("\r\nx"), Some(2));
+	assert_eq!(gl("\n\rx"), Some(1));
+	assert_eq!(gl("e\u{301}x"), Some(3));
+	assert_eq!(gl("e\u{301}\u{302}x"), Some(5));
+	assert_eq!(gl("\u{301}x"), Some(2));
+	assert_eq!(gl("\u{1100}\u{1161}\u{11a8}x"), Some(9));
+	assert_eq!(gl("\u{ac00}\u{11a8}x"), Some(6));
+	assert_eq!(gl("\u{1f44d}\u{1f3fd}x"), Some(8));
+	assert_eq!(gl("\u{1f469}\u{200d}\u{1f4bb}x"), Some(11));
+	assert_eq!(gl("a\u{200d}\u{1f4bb}"), Some(4));
+	assert_eq!(gl("\u{1f1e6}\u{1f1fa}\u{1f1e6}\u{1f1fa}"), Some(8));
+	assert_eq!(gl("\u{1f1e6}\u{1f1fa}\u{1f1e6}"), Some(8));
+	assert_eq!(gl("\u{600}1"), Some(3));
+	assert_eq!(gl("\u{915}\u{93f}x"), Some(6));
+}
+
+fn gcb(ch: char) -> Option<GraphemeCat> {
+	range_value(GRAPHEME_CLUSTER_BREAK, ch)
+}
+
+fn is_ext_pict(ch: char) -> bool {
+	in_ranges(EXTENDED_PICTOGRAPHIC, ch)
+}
+
+/**
+Returns the length, in bytes, of the word segment at the start of `s`, or `None` if `s` is empty.  This uses the default word boundary rules; that is, no dictionary-based segmentation is done.
+
+Note that a "word segment" is not necessarily a word: runs of spaces, individual punctuation marks and so on are also segments.  For example, `"don't stop"` is segmented as `"don't"`, `" "`, `"stop"`, and `"3.14, or so"` as `"3.14"`, `","`, `" "`, `"or"`, `" "`, `"so"`.
+*/
+pub fn word_len(s: &str) -> Option<uint> {
+	use tables::WordCat::{CR, LF, Newline, Extend, ZWJ, RegionalIndicator, Format, Katakana,
+		HebrewLetter, ALetter, SingleQuote, DoubleQuote, MidNumLet, MidLetter, MidNum,
+		Numeric, ExtendNumLet, WSegSpace};
+
+	fn is_ah_letter(c: Option<WordCat>) -> bool {
+		c == Some(ALetter) || c == Some(HebrewLetter)
+	}
+
+	fn is_mid_num_let_q(c: Option<WordCat>) -> bool {
+		c == Some(MidNumLet) || c == Some(SingleQuote)
+	}
+
+	fn is_ignored(c: Option<WordCat>) -> bool {
+		c == Some(Extend) || c == Some(Format) || c == Some(ZWJ)
+	}
+
+	// Returns the category of the next code point at or after `at` which isn't ignored under WB4.
+	let next_significant = |&: at: uint| -> Option<WordCat> {
+		s.slice_from(at).chars().map(wb).find(|&c| !is_ignored(c)).unwrap_or(None)
+	};
+
+	let mut chars = s.char_indices();
+	let first = match chars.next() {
+		Some((_, ch)) => ch,
+		None => return None
+	};
+
+	// The category of the immediately preceding code point.
+	let mut prev_raw = wb(first);
+	// The categories of the two preceding code points, skipping those ignored under WB4.
+	let mut prev = prev_raw;
+	let mut prev2 = None;
+	// Number of consecutive regional indicators ending at `prev`.
+	let mut ri_run = if prev == Some(RegionalIndicator) { 1u } else { 0 };
+
+	for (i, ch) in chars {
+		let cur = wb(ch);
+
+		// WB3 - WB3d
+		let boundary = match (prev_raw, cur) {
+			(Some(CR), Some(LF)) => Some(false),
+			(Some(CR), _) | (Some(LF), _) | (Some(Newline), _) => Some(true),
+			(_, Some(CR)) | (_, Some(LF)) | (_, Some(Newline)) => Some(true),
+			(Some(ZWJ), _) if is_ext_pict(ch) => Some(false),
+			(Some(WSegSpace), Some(WSegSpace)) => Some(false),
+			_ => None
+		};
+
+		let boundary = match boundary {
+			Some(b) => b,
+			// WB4
+			None if is_ignored(cur) => false,
+			None => {
+				let next = |&:| next_significant(i + ch.len_utf8());
+				match (prev, cur) {
+					// WB5
+					(p, c) if is_ah_letter(p) && is_ah_letter(c) => false,
+					// WB6
+					(p, c) if is_ah_letter(p) && (c == Some(MidLetter) || is_mid_num_let_q(c)) && is_ah_letter(next()) => false,
+					// WB7
+					(p, c) if is_ah_letter(prev2) && (p == Some(MidLetter) || is_mid_num_let_q(p)) && is_ah_letter(c) => false,
+					// WB7a
+					(Some(HebrewLetter), Some(SingleQuote)) => false,
+					// WB7b
+					(Some(HebrewLetter), Some(DoubleQuote)) if next() == Some(HebrewLetter) => false,
+					// WB7c
+					(Some(DoubleQuote), Some(HebrewLetter)) if prev2 == Some(HebrewLetter) => false,
+					// WB8, WB9, WB10
+					(Some(Numeric), Some(Numeric)) => false,
+					(p, Some(Numeric)) if is_ah_letter(p) => false,
+					(Some(Numeric), c) if is_ah_letter(c) => false,
+					// WB11
+					(p, Some(Numeric)) if prev2 == Some(Numeric) && (p == Some(MidNum) || is_mid_num_let_q(p)) => false,
+					// WB12
+					(Some(Numeric), c) if (c == Some(MidNum) || is_mid_num_let_q(c)) && next() == Some(Numeric) => false,
+					// WB13
+					(Some(Katakana), Some(Katakana)) => false,
+					// WB13a
+					(p, Some(ExtendNumLet)) if is_ah_letter(p) || p == Some(Numeric) || p == Some(Katakana) || p == Some(ExtendNumLet) => false,
+					// WB13b
+					(Some(ExtendNumLet), c) if is_ah_letter(c) || c == Some(Numeric) || c == Some(Katakana) => false,
+					// WB15, WB16
+					(Some(RegionalIndicator), Some(RegionalIndicator)) => ri_run % 2 == 0,
+					// WB999
+					_ => true
+				}
+			}
+		};
+
+		if boundary {
+			return Some(i);
+		}
+
+		prev_raw = cur;
+		if !is_ignored(cur) {
+			ri_run = if cur == Some(RegionalIndicator) { ri_run + 1 } else { 0 };
+			prev2 = prev;
+			prev = cur;
+		}
+	}
+
+	Some(s.len())
+}
+
+#[test]
+fn test_word_len() {
+	let wl = |s:&str| word_len(s);
+
+	assert_eq!(wl(""), None);
+	assert_eq!(wl("x"), Some(1));
+	assert_eq!(wl("abc def"), Some(3));
+	assert_eq!(wl("   def"), Some(3));
+	assert_eq!(wl("\r\nx"), Some(2));
+	assert_eq!(wl(", x"), Some(1));
+	assert_eq!(wl("don't stop"), Some(5));
+	assert_eq!(wl("don\u{2019}t stop"), Some(7));
+	assert_eq!(wl("can't."), Some(5));
+	assert_eq!(wl("dogs' bone"), Some(4));
+	assert_eq!(wl("e.g. x"), Some(3));
+	assert_eq!(wl("3.14, or so"), Some(4));
+	assert_eq!(wl("1,000.5 x"), Some(7));
+	assert_eq!(wl("1,,000"), Some(1));
+	assert_eq!(wl("abc123 x"), Some(6));
+	assert_eq!(wl("123abc x"), Some(6));
+	assert_eq!(wl("snake_case x"), Some(10));
+	assert_eq!(wl("caf\u{e9} x"), Some(5));
+	assert_eq!(wl("cafe\u{301} x"), Some(6));
+	assert_eq!(wl("\u{65e5}\u{672c}\u{8a9e}"), Some(3));
+	assert_eq!(wl("\u{30ab}\u{30bf}\u{30ab}\u{30ca}\u{3067}"), Some(12));
+	assert_eq!(wl("\u{5d0}\"\u{5d1} x"), Some(5));
+	assert_eq!(wl("\u{1f44d}\u{1f3fd}x"), Some(8));
+	assert_eq!(wl("\u{1f469}\u{200d}\u{1f4bb}x"), Some(11));
+	assert_eq!(wl("\u{1f1e6}\u{1f1fa}\u{1f1e6}\u{1f1fa}"), Some(8));
+	assert_eq!(wl("a\u{ad}b x"), Some(4));
+}
+
+fn wb(ch: char) -> Option<WordCat> {
+	range_value(WORD_BREAK, ch)
+}
@@ -0,0 +1,114 @@
+/*!
+This module provides the `Span` type, which records where in the input something came from, along with the `Spanned` scanner, which attaches a span to any scanned value.
+*/
+use super::{ScanCursor, ScanError, Scanner};
+
+/**
+A range of byte offsets within the input.  The range is left inclusive, right exclusive; that is, `start` is the offset of the first byte covered, and `end` is the offset just past the last byte covered.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Span {
+	pub start: uint,
+	pub end: uint,
+}
+
+impl Span {
+	/**
+Returns the number of bytes covered by this span.
+	*/
+	pub fn len(&self) -> uint {
+		self.end - self.start
+	}
+
+	/**
+Returns `true` if and only if this span covers no bytes at all.
+	*/
+	pub fn is_empty(&self) -> bool {
+		self.start == self.end
+	}
+}
+
+#[test]
+fn test_span() {
+	let sp = Span { start: 3, end: 7 };
+	assert_eq!(sp.len(), 4);
+	assert!(!sp.is_empty());
+	assert!(Span { start: 5, end: 5 }.is_empty());
+}
+
+/**
+Computes the line and column of the given byte offset within `s`.  Both are one-based, lines are terminated by `"\n"` (so `"\r\n"` is also handled), and the column counts code points, not bytes.
+*/
+pub fn line_col(s: &str, offset: uint) -> (uint, uint) {
+	let before = s.slice_to(offset);
+	let line = before.chars().filter(|&ch| ch == '\n').count() + 1;
+	let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+	let col = before.slice_from(line_start).chars().count() + 1;
+	(line, col)
+}
+
+#[test]
+fn test_line_col() {
+	let s = "ab\ncd\r\n日本語";
+	assert_eq!(line_col(s, 0), (1, 1));
+	assert_eq!(line_col(s, 2), (1, 3));
+	assert_eq!(line_col(s, 3), (2, 1));
+	assert_eq!(line_col(s, 5), (2, 3));
+	assert_eq!(line_col(s, 7), (3, 1));
+	assert_eq!(line_col(s, 13), (3, 3));
+	assert_eq!(line_col(s, s.len()), (3, 4));
+}
+
+/**
+Scans a `T`, and records where in the input it came from.
+
+The span covers exactly the input consumed by the inner scanner, except that any leading whitespace which the cursor would skip is excluded.  `line_col` is the line and column at which the span starts, if the cursor is able to provide it (see `ScanCursor::line_col`).
+*/
+#[derive(Clone, Eq, PartialEq, Show)]
+pub struct Spanned<T> {
+	pub value: T,
+	pub span: Span,
+	pub line_col: Option<(uint, uint)>,
+}
+
+impl<'a, T: Scanner<'a>> Scanner<'a> for Spanned<T> {
+	fn scan<Cur: ScanCursor<'a>>(cursor: &Cur) -> Result<(Spanned<T>, Cur), ScanError> {
+		let (value, end): (T, Cur) = try!(Scanner::scan(cursor));
+
+		// Only exclude the whitespace if the inner scanner actually went past it; scanners such as the one for `char` do not skip whitespace, and may consume it themselves.
+		let ws = cursor.pop_ws();
+		let start = if ws.consumed() < end.consumed() { ws } else { cursor.clone() };
+
+		let spanned = Spanned {
+			value: value,
+			span: start.span_to(&end),
+			line_col: start.line_col(),
+		};
+		Ok((spanned, end))
+	}
+}
+
+#[test]
+fn test_spanned() {
+	use Cursor;
+	use compare_strs::Exact;
+	use tokenizer::WordsAndInts;
+	use whitespace::Ignore;
+
+	let cur = Cursor::new("  foo\n   42 x", WordsAndInts, Ignore, Exact);
+
+	let (sp, cur): (Spanned<&str>, _) = Scanner::scan(&cur).ok().unwrap();
+	assert_eq!(sp, Spanned { value: "foo", span: Span { start: 2, end: 5 }, line_col: Some((1, 3)) });
+
+	let (sp, _): (Spanned<&str>, _) = Scanner::scan(&cur).ok().unwrap();
+	assert_eq!(sp, Spanned { value: "42", span: Span { start: 9, end: 11 }, line_col: Some((2, 4)) });
+
+	let (sp, _): (Spanned<char>, _) = Scanner::scan(&cur).ok().unwrap();
+	assert_eq!(sp, Spanned { value: '\n', span: Span { start: 5, end: 6 }, line_col: Some((1, 6)) });
+
+	let (sp, _): (Spanned<()>, _) = Scanner::scan(&cur).ok().unwrap();
+	assert_eq!(sp.span, Span { start: 5, end: 5 });
+
+	let r: Result<(Spanned<uint>, _), _> = Scanner::scan(&cur.slice_from(1));
+	assert!(r.is_err());
+}
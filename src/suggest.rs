@@ -0,0 +1,99 @@
+/*!
+This module provides routines for suggesting likely alternatives to mistyped tokens.
+
+These are used by `ScanCursor::expected_one_of` to attach "did you mean" hints to error messages, but are also available for use by scanners which construct their own errors.
+*/
+
+/**
+Computes the edit distance between two strings, counting insertions, deletions, substitutions and transpositions of adjacent code points as a single edit each.  This is sometimes called the "optimal string alignment" distance.
+
+Individual code points are compared using `eq`, which is given each code point as a string slice.  This allows the distance to respect the semantics of a `CompareStrs` implementation; for example, under case-insensitive comparison, `"False"` and `"fasle"` are a single edit apart.
+*/
+pub fn edit_distance<F>(a: &str, b: &str, eq: F) -> uint where F: Fn(&str, &str) -> bool {
+	let a: Vec<String> = a.chars().map(|ch| ch.to_string()).collect();
+	let b: Vec<String> = b.chars().map(|ch| ch.to_string()).collect();
+	let (n, m) = (a.len(), b.len());
+
+	// `d[i][j]` is the distance between the first `i` code points of `a` and the first `j` code points of `b`.
+	let mut d: Vec<Vec<uint>> = range(0, n+1).map(|_| range(0, m+1).map(|_| 0u).collect()).collect();
+	for i in range(0, n+1) { d[i][0] = i; }
+	for j in range(0, m+1) { d[0][j] = j; }
+
+	for i in range(1, n+1) {
+		for j in range(1, m+1) {
+			let cost = if eq(a[i-1].as_slice(), b[j-1].as_slice()) { 0 } else { 1 };
+			let mut best = ::std::cmp::min(d[i-1][j] + 1, ::std::cmp::min(d[i][j-1] + 1, d[i-1][j-1] + cost));
+			if i > 1 && j > 1
+				&& eq(a[i-1].as_slice(), b[j-2].as_slice())
+				&& eq(a[i-2].as_slice(), b[j-1].as_slice())
+			{
+				best = ::std::cmp::min(best, d[i-2][j-2] + 1);
+			}
+			d[i][j] = best;
+		}
+	}
+
+	d[n][m]
+}
+
+#[test]
+fn test_edit_distance() {
+	use std::ascii::AsciiExt;
+
+	let ed = |a,b| edit_distance(a, b, |a,b| a == b);
+
+	assert_eq!(ed("", ""), 0);
+	assert_eq!(ed("abc", ""), 3);
+	assert_eq!(ed("", "abc"), 3);
+	assert_eq!(ed("false", "false"), 0);
+	assert_eq!(ed("fasle", "false"), 1);
+	assert_eq!(ed("flase", "false"), 1);
+	assert_eq!(ed("fals", "false"), 1);
+	assert_eq!(ed("falsey", "false"), 1);
+	assert_eq!(ed("fakse", "false"), 1);
+	assert_eq!(ed("False", "false"), 1);
+	assert_eq!(ed("true", "false"), 4);
+	assert_eq!(ed("日本語", "日語"), 1);
+
+	let ed_ci = |a,b| edit_distance(a, b, |a: &str, b: &str| a.eq_ignore_ascii_case(b));
+	assert_eq!(ed_ci("FASLE", "false"), 1);
+}
+
+/**
+Returns the candidate which is closest to `got`, provided it is close enough to plausibly be what was intended.  Code points are compared using `eq`, as with `edit_distance`.
+
+A candidate is considered close enough if it is within one edit for every three code points in the candidate (but always allowing at least one edit), *and* it isn't so distant that it would require replacing all of `got`.  Where several candidates are equally close, the first is returned.
+*/
+pub fn closest_match<'a, F>(got: &str, candidates: &[&'a str], eq: F) -> Option<&'a str> where F: Fn(&str, &str) -> bool {
+	let got_len = got.chars().count();
+	let mut best: Option<(uint, &'a str)> = None;
+
+	for &cand in candidates.iter() {
+		let dist = edit_distance(got, cand, |a, b| eq(a, b));
+		let limit = ::std::cmp::max(1, cand.chars().count() / 3);
+		if dist == 0 || dist > limit || dist >= got_len {
+			continue;
+		}
+		match best {
+			Some((best_dist, _)) if best_dist <= dist => (),
+			_ => best = Some((dist, cand))
+		}
+	}
+
+	best.map(|(_, cand)| cand)
+}
+
+#[test]
+fn test_closest_match() {
+	let cm = |got, cands: &[&'static str]| closest_match(got, cands, |a,b| a == b);
+
+	assert_eq!(cm("fasle", &["true", "false"]), Some("false"));
+	assert_eq!(cm("ture", &["true", "false"]), Some("true"));
+	assert_eq!(cm("yes", &["true", "false"]), None);
+	assert_eq!(cm("x", &["y", "z"]), None);
+	assert_eq!(cm("false", &["true", "false"]), None);
+	assert_eq!(cm("", &["true", "false"]), None);
+	assert_eq!(cm("lisen", &["listen", "server", "location"]), Some("listen"));
+	assert_eq!(cm("locaton", &["listen", "server", "location"]), Some("location"));
+	assert_eq!(cm("cat", &["bat", "cab"]), Some("bat"));
+}
@@ -0,0 +1,4415 @@
+// This file is generated by `etc/gen_tables.pl`; do not edit it by hand.
+//
+// Unicode version: 14.0.0.
+
+#![allow(dead_code)]
+
+/**
+Full case folding mappings; that is, those with status `C` or `F` in `CaseFolding.txt`.  Sorted by code point.
+*/
+pub static CASE_FOLDING: &'static [(char, &'static str)] = &[
+	('\u{41}', "\u{61}"),
+	('\u{42}', "\u{62}"),
+	('\u{43}', "\u{63}"),
+	('\u{44}', "\u{64}"),
+	('\u{45}', "\u{65}"),
+	('\u{46}', "\u{66}"),
+	('\u{47}', "\u{67}"),
+	('\u{48}', "\u{68}"),
+	('\u{49}', "\u{69}"),
+	('\u{4a}', "\u{6a}"),
+	('\u{4b}', "\u{6b}"),
+	('\u{4c}', "\u{6c}"),
+	('\u{4d}', "\u{6d}"),
+	('\u{4e}', "\u{6e}"),
+	('\u{4f}', "\u{6f}"),
+	('\u{50}', "\u{70}"),
+	('\u{51}', "\u{71}"),
+	('\u{52}', "\u{72}"),
+	('\u{53}', "\u{73}"),
+	('\u{54}', "\u{74}"),
+	('\u{55}', "\u{75}"),
+	('\u{56}', "\u{76}"),
+	('\u{57}', "\u{77}"),
+	('\u{58}', "\u{78}"),
+	('\u{59}', "\u{79}"),
+	('\u{5a}', "\u{7a}"),
+	('\u{b5}', "\u{3bc}"),
+	('\u{c0}', "\u{e0}"),
+	('\u{c1}', "\u{e1}"),
+	('\u{c2}', "\u{e2}"),
+	('\u{c3}', "\u{e3}"),
+	('\u{c4}', "\u{e4}"),
+	('\u{c5}', "\u{e5}"),
+	('\u{c6}', "\u{e6}"),
+	('\u{c7}', "\u{e7}"),
+	('\u{c8}', "\u{e8}"),
+	('\u{c9}', "\u{e9}"),
+	('\u{ca}', "\u{ea}"),
+	('\u{cb}', "\u{eb}"),
+	('\u{cc}', "\u{ec}"),
+	('\u{cd}', "\u{ed}"),
+	('\u{ce}', "\u{ee}"),
+	('\u{cf}', "\u{ef}"),
+	('\u{d0}', "\u{f0}"),
+	('\u{d1}', "\u{f1}"),
+	('\u{d2}', "\u{f2}"),
+	('\u{d3}', "\u{f3}"),
+	('\u{d4}', "\u{f4}"),
+	('\u{d5}', "\u{f5}"),
+	('\u{d6}', "\u{f6}"),
+	('\u{d8}', "\u{f8}"),
+	('\u{d9}', "\u{f9}"),
+	('\u{da}', "\u{fa}"),
+	('\u{db}', "\u{fb}"),
+	('\u{dc}', "\u{fc}"),
+	('\u{dd}', "\u{fd}"),
+	('\u{de}', "\u{fe}"),
+	('\u{df}', "\u{73}\u{73}"),
+	('\u{100}', "\u{101}"),
+	('\u{102}', "\u{103}"),
+	('\u{104}', "\u{105}"),
+	('\u{106}', "\u{107}"),
+	('\u{108}', "\u{109}"),
+	('\u{10a}', "\u{10b}"),
+	('\u{10c}', "\u{10d}"),
+	('\u{10e}', "\u{10f}"),
+	('\u{110}', "\u{111}"),
+	('\u{112}', "\u{113}"),
+	('\u{114}', "\u{115}"),
+	('\u{116}', "\u{117}"),
+	('\u{118}', "\u{119}"),
+	('\u{11a}', "\u{11b}"),
+	('\u{11c}', "\u{11d}"),
+	('\u{11e}', "\u{11f}"),
+	('\u{120}', "\u{121}"),
+	('\u{122}', "\u{123}"),
+	('\u{124}', "\u{125}"),
+	('\u{126}', "\u{127}"),
+	('\u{128}', "\u{129}"),
+	('\u{12a}', "\u{12b}"),
+	('\u{12c}', "\u{12d}"),
+	('\u{12e}', "\u{12f}"),
+	('\u{130}', "\u{69}\u{307}"),
+	('\u{132}', "\u{133}"),
+	('\u{134}', "\u{135}"),
+	('\u{136}', "\u{137}"),
+	('\u{139}', "\u{13a}"),
+	('\u{13b}', "\u{13c}"),
+	('\u{13d}', "\u{13e}"),
+	('\u{13f}', "\u{140}"),
+	('\u{141}', "\u{142}"),
+	('\u{143}', "\u{144}"),
+	('\u{145}', "\u{146}"),
+	('\u{147}', "\u{148}"),
+	('\u{149}', "\u{2bc}\u{6e}"),
+	('\u{14a}', "\u{14b}"),
+	('\u{14c}', "\u{14d}"),
+	('\u{14e}', "\u{14f}"),
+	('\u{150}', "\u{151}"),
+	('\u{152}', "\u{153}"),
+	('\u{154}', "\u{155}"),
+	('\u{156}', "\u{157}"),
+	('\u{158}', "\u{159}"),
+	('\u{15a}', "\u{15b}"),
+	('\u{15c}', "\u{15d}"),
+	('\u{15e}', "\u{15f}"),
+	('\u{160}', "\u{161}"),
+	('\u{162}', "\u{163}"),
+	('\u{164}', "\u{165}"),
+	('\u{166}', "\u{167}"),
+	('\u{168}', "\u{169}"),
+	('\u{16a}', "\u{16b}"),
+	('\u{16c}', "\u{16d}"),
+	('\u{16e}', "\u{16f}"),
+	('\u{170}', "\u{171}"),
+	('\u{172}', "\u{173}"),
+	('\u{174}', "\u{175}"),
+	('\u{176}', "\u{177}"),
+	('\u{178}', "\u{ff}"),
+	('\u{179}', "\u{17a}"),
+	('\u{17b}', "\u{17c}"),
+	('\u{17d}', "\u{17e}"),
+	('\u{17f}', "\u{73}"),
+	('\u{181}', "\u{253}"),
+	('\u{182}', "\u{183}"),
+	('\u{184}', "\u{185}"),
+	('\u{186}', "\u{254}"),
+	('\u{187}', "\u{188}"),
+	('\u{189}', "\u{256}"),
+	('\u{18a}', "\u{257}"),
+	('\u{18b}', "\u{18c}"),
+	('\u{18e}', "\u{1dd}"),
+	('\u{18f}', "\u{259}"),
+	('\u{190}', "\u{25b}"),
+	('\u{191}', "\u{192}"),
+	('\u{193}', "\u{260}"),
+	('\u{194}', "\u{263}"),
+	('\u{196}', "\u{269}"),
+	('\u{197}', "\u{268}"),
+	('\u{198}', "\u{199}"),
+	('\u{19c}', "\u{26f}"),
+	('\u{19d}', "\u{272}"),
+	('\u{19f}', "\u{275}"),
+	('\u{1a0}', "\u{1a1}"),
+	('\u{1a2}', "\u{1a3}"),
+	('\u{1a4}', "\u{1a5}"),
+	('\u{1a6}', "\u{280}"),
+	('\u{1a7}', "\u{1a8}"),
+	('\u{1a9}', "\u{283}"),
+	('\u{1ac}', "\u{1ad}"),
+	('\u{1ae}', "\u{288}"),
+	('\u{1af}', "\u{1b0}"),
+	('\u{1b1}', "\u{28a}"),
+	('\u{1b2}', "\u{28b}"),
+	('\u{1b3}', "\u{1b4}"),
+	('\u{1b5}', "\u{1b6}"),
+	('\u{1b7}', "\u{292}"),
+	('\u{1b8}', "\u{1b9}"),
+	('\u{1bc}', "\u{1bd}"),
+	('\u{1c4}', "\u{1c6}"),
+	('\u{1c5}', "\u{1c6}"),
+	('\u{1c7}', "\u{1c9}"),
+	('\u{1c8}', "\u{1c9}"),
+	('\u{1ca}', "\u{1cc}"),
+	('\u{1cb}', "\u{1cc}"),
+	('\u{1cd}', "\u{1ce}"),
+	('\u{1cf}', "\u{1d0}"),
+	('\u{1d1}', "\u{1d2}"),
+	('\u{1d3}', "\u{1d4}"),
+	('\u{1d5}', "\u{1d6}"),
+	('\u{1d7}', "\u{1d8}"),
+	('\u{1d9}', "\u{1da}"),
+	('\u{1db}', "\u{1dc}"),
+	('\u{1de}', "\u{1df}"),
+	('\u{1e0}', "\u{1e1}"),
+	('\u{1e2}', "\u{1e3}"),
+	('\u{1e4}', "\u{1e5}"),
+	('\u{1e6}', "\u{1e7}"),
+	('\u{1e8}', "\u{1e9}"),
+	('\u{1ea}', "\u{1eb}"),
+	('\u{1ec}', "\u{1ed}"),
+	('\u{1ee}', "\u{1ef}"),
+	('\u{1f0}', "\u{6a}\u{30c}"),
+	('\u{1f1}', "\u{1f3}"),
+	('\u{1f2}', "\u{1f3}"),
+	('\u{1f4}', "\u{1f5}"),
+	('\u{1f6}', "\u{195}"),
+	('\u{1f7}', "\u{1bf}"),
+	('\u{1f8}', "\u{1f9}"),
+	('\u{1fa}', "\u{1fb}"),
+	('\u{1fc}', "\u{1fd}"),
+	('\u{1fe}', "\u{1ff}"),
+	('\u{200}', "\u{201}"),
+	('\u{202}', "\u{203}"),
+	('\u{204}', "\u{205}"),
+	('\u{206}', "\u{207}"),
+	('\u{208}', "\u{209}"),
+	('\u{20a}', "\u{20b}"),
+	('\u{20c}', "\u{20d}"),
+	('\u{20e}', "\u{20f}"),
+	('\u{210}', "\u{211}"),
+	('\u{212}', "\u{213}"),
+	('\u{214}', "\u{215}"),
+	('\u{216}', "\u{217}"),
+	('\u{218}', "\u{219}"),
+	('\u{21a}', "\u{21b}"),
+	('\u{21c}', "\u{21d}"),
+	('\u{21e}', "\u{21f}"),
+	('\u{220}', "\u{19e}"),
+	('\u{222}', "\u{223}"),
+	('\u{224}', "\u{225}"),
+	('\u{226}', "\u{227}"),
+	('\u{228}', "\u{229}"),
+	('\u{22a}', "\u{22b}"),
+	('\u{22c}', "\u{22d}"),
+	('\u{22e}', "\u{22f}"),
+	('\u{230}', "\u{231}"),
+	('\u{232}', "\u{233}"),
+	('\u{23a}', "\u{2c65}"),
+	('\u{23b}', "\u{23c}"),
+	('\u{23d}', "\u{19a}"),
+	('\u{23e}', "\u{2c66}"),
+	('\u{241}', "\u{242}"),
+	('\u{243}', "\u{180}"),
+	('\u{244}', "\u{289}"),
+	('\u{245}', "\u{28c}"),
+	('\u{246}', "\u{247}"),
+	('\u{248}', "\u{249}"),
+	('\u{24a}', "\u{24b}"),
+	('\u{24c}', "\u{24d}"),
+	('\u{24e}', "\u{24f}"),
+	('\u{345}', "\u{3b9}"),
+	('\u{370}', "\u{371}"),
+	('\u{372}', "\u{373}"),
+	('\u{376}', "\u{377}"),
+	('\u{37f}', "\u{3f3}"),
+	('\u{386}', "\u{3ac}"),
+	('\u{388}', "\u{3ad}"),
+	('\u{389}', "\u{3ae}"),
+	('\u{38a}', "\u{3af}"),
+	('\u{38c}', "\u{3cc}"),
+	('\u{38e}', "\u{3cd}"),
+	('\u{38f}', "\u{3ce}"),
+	('\u{390}', "\u{3b9}\u{308}\u{301}"),
+	('\u{391}', "\u{3b1}"),
+	('\u{392}', "\u{3b2}"),
+	('\u{393}', "\u{3b3}"),
+	('\u{394}', "\u{3b4}"),
+	('\u{395}', "\u{3b5}"),
+	('\u{396}', "\u{3b6}"),
+	('\u{397}', "\u{3b7}"),
+	('\u{398}', "\u{3b8}"),
+	('\u{399}', "\u{3b9}"),
+	('\u{39a}', "\u{3ba}"),
+	('\u{39b}', "\u{3bb}"),
+	('\u{39c}', "\u{3bc}"),
+	('\u{39d}', "\u{3bd}"),
+	('\u{39e}', "\u{3be}"),
+	('\u{39f}', "\u{3bf}"),
+	('\u{3a0}', "\u{3c0}"),
+	('\u{3a1}', "\u{3c1}"),
+	('\u{3a3}', "\u{3c3}"),
+	('\u{3a4}', "\u{3c4}"),
+	('\u{3a5}', "\u{3c5}"),
+	('\u{3a6}', "\u{3c6}"),
+	('\u{3a7}', "\u{3c7}"),
+	('\u{3a8}', "\u{3c8}"),
+	('\u{3a9}', "\u{3c9}"),
+	('\u{3aa}', "\u{3ca}"),
+	('\u{3ab}', "\u{3cb}"),
+	('\u{3b0}', "\u{3c5}\u{308}\u{301}"),
+	('\u{3c2}', "\u{3c3}"),
+	('\u{3cf}', "\u{3d7}"),
+	('\u{3d0}', "\u{3b2}"),
+	('\u{3d1}', "\u{3b8}"),
+	('\u{3d5}', "\u{3c6}"),
+	('\u{3d6}', "\u{3c0}"),
+	('\u{3d8}', "\u{3d9}"),
+	('\u{3da}', "\u{3db}"),
+	('\u{3dc}', "\u{3dd}"),
+	('\u{3de}', "\u{3df}"),
+	('\u{3e0}', "\u{3e1}"),
+	('\u{3e2}', "\u{3e3}"),
+	('\u{3e4}', "\u{3e5}"),
+	('\u{3e6}', "\u{3e7}"),
+	('\u{3e8}', "\u{3e9}"),
+	('\u{3ea}', "\u{3eb}"),
+	('\u{3ec}', "\u{3ed}"),
+	('\u{3ee}', "\u{3ef}"),
+	('\u{3f0}', "\u{3ba}"),
+	('\u{3f1}', "\u{3c1}"),
+	('\u{3f4}', "\u{3b8}"),
+	('\u{3f5}', "\u{3b5}"),
+	('\u{3f7}', "\u{3f8}"),
+	('\u{3f9}', "\u{3f2}"),
+	('\u{3fa}', "\u{3fb}"),
+	('\u{3fd}', "\u{37b}"),
+	('\u{3fe}', "\u{37c}"),
+	('\u{3ff}', "\u{37d}"),
+	('\u{400}', "\u{450}"),
+	('\u{401}', "\u{451}"),
+	('\u{402}', "\u{452}"),
+	('\u{403}', "\u{453}"),
+	('\u{404}', "\u{454}"),
+	('\u{405}', "\u{455}"),
+	('\u{406}', "\u{456}"),
+	('\u{407}', "\u{457}"),
+	('\u{408}', "\u{458}"),
+	('\u{409}', "\u{459}"),
+	('\u{40a}', "\u{45a}"),
+	('\u{40b}', "\u{45b}"),
+	('\u{40c}', "\u{45c}"),
+	('\u{40d}', "\u{45d}"),
+	('\u{40e}', "\u{45e}"),
+	('\u{40f}', "\u{45f}"),
+	('\u{410}', "\u{430}"),
+	('\u{411}', "\u{431}"),
+	('\u{412}', "\u{432}"),
+	('\u{413}', "\u{433}"),
+	('\u{414}', "\u{434}"),
+	('\u{415}', "\u{435}"),
+	('\u{416}', "\u{436}"),
+	('\u{417}', "\u{437}"),
+	('\u{418}', "\u{438}"),
+	('\u{419}', "\u{439}"),
+	('\u{41a}', "\u{43a}"),
+	('\u{41b}', "\u{43b}"),
+	('\u{41c}', "\u{43c}"),
+	('\u{41d}', "\u{43d}"),
+	('\u{41e}', "\u{43e}"),
+	('\u{41f}', "\u{43f}"),
+	('\u{420}', "\u{440}"),
+	('\u{421}', "\u{441}"),
+	('\u{422}', "\u{442}"),
+	('\u{423}', "\u{443}"),
+	('\u{424}', "\u{444}"),
+	('\u{425}', "\u{445}"),
+	('\u{426}', "\u{446}"),
+	('\u{427}', "\u{447}"),
+	('\u{428}', "\u{448}"),
+	('\u{429}', "\u{449}"),
+	('\u{42a}', "\u{44a}"),
+	('\u{42b}', "\u{44b}"),
+	('\u{42c}', "\u{44c}"),
+	('\u{42d}', "\u{44d}"),
+	('\u{42e}', "\u{44e}"),
+	('\u{42f}', "\u{44f}"),
+	('\u{460}', "\u{461}"),
+	('\u{462}', "\u{463}"),
+	('\u{464}', "\u{465}"),
+	('\u{466}', "\u{467}"),
+	('\u{468}', "\u{469}"),
+	('\u{46a}', "\u{46b}"),
+	('\u{46c}', "\u{46d}"),
+	('\u{46e}', "\u{46f}"),
+	('\u{470}', "\u{471}"),
+	('\u{472}', "\u{473}"),
+	('\u{474}', "\u{475}"),
+	('\u{476}', "\u{477}"),
+	('\u{478}', "\u{479}"),
+	('\u{47a}', "\u{47b}"),
+	('\u{47c}', "\u{47d}"),
+	('\u{47e}', "\u{47f}"),
+	('\u{480}', "\u{481}"),
+	('\u{48a}', "\u{48b}"),
+	('\u{48c}', "\u{48d}"),
+	('\u{48e}', "\u{48f}"),
+	('\u{490}', "\u{491}"),
+	('\u{492}', "\u{493}"),
+	('\u{494}', "\u{495}"),
+	('\u{496}', "\u{497}"),
+	('\u{498}', "\u{499}"),
+	('\u{49a}', "\u{49b}"),
+	('\u{49c}', "\u{49d}"),
+	('\u{49e}', "\u{49f}"),
+	('\u{4a0}', "\u{4a1}"),
+	('\u{4a2}', "\u{4a3}"),
+	('\u{4a4}', "\u{4a5}"),
+	('\u{4a6}', "\u{4a7}"),
+	('\u{4a8}', "\u{4a9}"),
+	('\u{4aa}', "\u{4ab}"),
+	('\u{4ac}', "\u{4ad}"),
+	('\u{4ae}', "\u{4af}"),
+	('\u{4b0}', "\u{4b1}"),
+	('\u{4b2}', "\u{4b3}"),
+	('\u{4b4}', "\u{4b5}"),
+	('\u{4b6}', "\u{4b7}"),
+	('\u{4b8}', "\u{4b9}"),
+	('\u{4ba}', "\u{4bb}"),
+	('\u{4bc}', "\u{4bd}"),
+	('\u{4be}', "\u{4bf}"),
+	('\u{4c0}', "\u{4cf}"),
+	('\u{4c1}', "\u{4c2}"),
+	('\u{4c3}', "\u{4c4}"),
+	('\u{4c5}', "\u{4c6}"),
+	('\u{4c7}', "\u{4c8}"),
+	('\u{4c9}', "\u{4ca}"),
+	('\u{4cb}', "\u{4cc}"),
+	('\u{4cd}', "\u{4ce}"),
+	('\u{4d0}', "\u{4d1}"),
+	('\u{4d2}', "\u{4d3}"),
+	('\u{4d4}', "\u{4d5}"),
+	('\u{4d6}', "\u{4d7}"),
+	('\u{4d8}', "\u{4d9}"),
+	('\u{4da}', "\u{4db}"),
+	('\u{4dc}', "\u{4dd}"),
+	('\u{4de}', "\u{4df}"),
+	('\u{4e0}', "\u{4e1}"),
+	('\u{4e2}', "\u{4e3}"),
+	('\u{4e4}', "\u{4e5}"),
+	('\u{4e6}', "\u{4e7}"),
+	('\u{4e8}', "\u{4e9}"),
+	('\u{4ea}', "\u{4eb}"),
+	('\u{4ec}', "\u{4ed}"),
+	('\u{4ee}', "\u{4ef}"),
+	('\u{4f0}', "\u{4f1}"),
+	('\u{4f2}', "\u{4f3}"),
+	('\u{4f4}', "\u{4f5}"),
+	('\u{4f6}', "\u{4f7}"),
+	('\u{4f8}', "\u{4f9}"),
+	('\u{4fa}', "\u{4fb}"),
+	('\u{4fc}', "\u{4fd}"),
+	('\u{4fe}', "\u{4ff}"),
+	('\u{500}', "\u{501}"),
+	('\u{502}', "\u{503}"),
+	('\u{504}', "\u{505}"),
+	('\u{506}', "\u{507}"),
+	('\u{508}', "\u{509}"),
+	('\u{50a}', "\u{50b}"),
+	('\u{50c}', "\u{50d}"),
+	('\u{50e}', "\u{50f}"),
+	('\u{510}', "\u{511}"),
+	('\u{512}', "\u{513}"),
+	('\u{514}', "\u{515}"),
+	('\u{516}', "\u{517}"),
+	('\u{518}', "\u{519}"),
+	('\u{51a}', "\u{51b}"),
+	('\u{51c}', "\u{51d}"),
+	('\u{51e}', "\u{51f}"),
+	('\u{520}', "\u{521}"),
+	('\u{522}', "\u{523}"),
+	('\u{524}', "\u{525}"),
+	('\u{526}', "\u{527}"),
+	('\u{528}', "\u{529}"),
+	('\u{52a}', "\u{52b}"),
+	('\u{52c}', "\u{52d}"),
+	('\u{52e}', "\u{52f}"),
+	('\u{531}', "\u{561}"),
+	('\u{532}', "\u{562}"),
+	('\u{533}', "\u{563}"),
+	('\u{534}', "\u{564}"),
+	('\u{535}', "\u{565}"),
+	('\u{536}', "\u{566}"),
+	('\u{537}', "\u{567}"),
+	('\u{538}', "\u{568}"),
+	('\u{539}', "\u{569}"),
+	('\u{53a}', "\u{56a}"),
+	('\u{53b}', "\u{56b}"),
+	('\u{53c}', "\u{56c}"),
+	('\u{53d}', "\u{56d}"),
+	('\u{53e}', "\u{56e}"),
+	('\u{53f}', "\u{56f}"),
+	('\u{540}', "\u{570}"),
+	('\u{541}', "\u{571}"),
+	('\u{542}', "\u{572}"),
+	('\u{543}', "\u{573}"),
+	('\u{544}', "\u{574}"),
+	('\u{545}', "\u{575}"),
+	('\u{546}', "\u{576}"),
+	('\u{547}', "\u{577}"),
+	('\u{548}', "\u{578}"),
+	('\u{549}', "\u{579}"),
+	('\u{54a}', "\u{57a}"),
+	('\u{54b}', "\u{57b}"),
+	('\u{54c}', "\u{57c}"),
+	('\u{54d}', "\u{57d}"),
+	('\u{54e}', "\u{57e}"),
+	('\u{54f}', "\u{57f}"),
+	('\u{550}', "\u{580}"),
+	('\u{551}', "\u{581}"),
+	('\u{552}', "\u{582}"),
+	('\u{553}', "\u{583}"),
+	('\u{554}', "\u{584}"),
+	('\u{555}', "\u{585}"),
+	('\u{556}', "\u{586}"),
+	('\u{587}', "\u{565}\u{582}"),
+	('\u{10a0}', "\u{2d00}"),
+	('\u{10a1}', "\u{2d01}"),
+	('\u{10a2}', "\u{2d02}"),
+	('\u{10a3}', "\u{2d03}"),
+	('\u{10a4}', "\u{2d04}"),
+	('\u{10a5}', "\u{2d05}"),
+	('\u{10a6}', "\u{2d06}"),
+	('\u{10a7}', "\u{2d07}"),
+	('\u{10a8}', "\u{2d08}"),
+	('\u{10a9}', "\u{2d09}"),
+	('\u{10aa}', "\u{2d0a}"),
+	('\u{10ab}', "\u{2d0b}"),
+	('\u{10ac}', "\u{2d0c}"),
+	('\u{10ad}', "\u{2d0d}"),
+	('\u{10ae}', "\u{2d0e}"),
+	('\u{10af}', "\u{2d0f}"),
+	('\u{10b0}', "\u{2d10}"),
+	('\u{10b1}', "\u{2d11}"),
+	('\u{10b2}', "\u{2d12}"),
+	('\u{10b3}', "\u{2d13}"),
+	('\u{10b4}', "\u{2d14}"),
+	('\u{10b5}', "\u{2d15}"),
+	('\u{10b6}', "\u{2d16}"),
+	('\u{10b7}', "\u{2d17}"),
+	('\u{10b8}', "\u{2d18}"),
+	('\u{10b9}', "\u{2d19}"),
+	('\u{10ba}', "\u{2d1a}"),
+	('\u{10bb}', "\u{2d1b}"),
+	('\u{10bc}', "\u{2d1c}"),
+	('\u{10bd}', "\u{2d1d}"),
+	('\u{10be}', "\u{2d1e}"),
+	('\u{10bf}', "\u{2d1f}"),
+	('\u{10c0}', "\u{2d20}"),
+	('\u{10c1}', "\u{2d21}"),
+	('\u{10c2}', "\u{2d22}"),
+	('\u{10c3}', "\u{2d23}"),
+	('\u{10c4}', "\u{2d24}"),
+	('\u{10c5}', "\u{2d25}"),
+	('\u{10c7}', "\u{2d27}"),
+	('\u{10cd}', "\u{2d2d}"),
+	('\u{13f8}', "\u{13f0}"),
+	('\u{13f9}', "\u{13f1}"),
+	('\u{13fa}', "\u{13f2}"),
+	('\u{13fb}', "\u{13f3}"),
+	('\u{13fc}', "\u{13f4}"),
+	('\u{13fd}', "\u{13f5}"),
+	('\u{1c80}', "\u{432}"),
+	('\u{1c81}', "\u{434}"),
+	('\u{1c82}', "\u{43e}"),
+	('\u{1c83}', "\u{441}"),
+	('\u{1c84}', "\u{442}"),
+	('\u{1c85}', "\u{442}"),
+	('\u{1c86}', "\u{44a}"),
+	('\u{1c87}', "\u{463}"),
+	('\u{1c88}', "\u{a64b}"),
+	('\u{1c90}', "\u{10d0}"),
+	('\u{1c91}', "\u{10d1}"),
+	('\u{1c92}', "\u{10d2}"),
+	('\u{1c93}', "\u{10d3}"),
+	('\u{1c94}', "\u{10d4}"),
+	('\u{1c95}', "\u{10d5}"),
+	('\u{1c96}', "\u{10d6}"),
+	('\u{1c97}', "\u{10d7}"),
+	('\u{1c98}', "\u{10d8}"),
+	('\u{1c99}', "\u{10d9}"),
+	('\u{1c9a}', "\u{10da}"),
+	('\u{1c9b}', "\u{10db}"),
+	('\u{1c9c}', "\u{10dc}"),
+	('\u{1c9d}', "\u{10dd}"),
+	('\u{1c9e}', "\u{10de}"),
+	('\u{1c9f}', "\u{10df}"),
+	('\u{1ca0}', "\u{10e0}"),
+	('\u{1ca1}', "\u{10e1}"),
+	('\u{1ca2}', "\u{10e2}"),
+	('\u{1ca3}', "\u{10e3}"),
+	('\u{1ca4}', "\u{10e4}"),
+	('\u{1ca5}', "\u{10e5}"),
+	('\u{1ca6}', "\u{10e6}"),
+	('\u{1ca7}', "\u{10e7}"),
+	('\u{1ca8}', "\u{10e8}"),
+	('\u{1ca9}', "\u{10e9}"),
+	('\u{1caa}', "\u{10ea}"),
+	('\u{1cab}', "\u{10eb}"),
+	('\u{1cac}', "\u{10ec}"),
+	('\u{1cad}', "\u{10ed}"),
+	('\u{1cae}', "\u{10ee}"),
+	('\u{1caf}', "\u{10ef}"),
+	('\u{1cb0}', "\u{10f0}"),
+	('\u{1cb1}', "\u{10f1}"),
+	('\u{1cb2}', "\u{10f2}"),
+	('\u{1cb3}', "\u{10f3}"),
+	('\u{1cb4}', "\u{10f4}"),
+	('\u{1cb5}', "\u{10f5}"),
+	('\u{1cb6}', "\u{10f6}"),
+	('\u{1cb7}', "\u{10f7}"),
+	('\u{1cb8}', "\u{10f8}"),
+	('\u{1cb9}', "\u{10f9}"),
+	('\u{1cba}', "\u{10fa}"),
+	('\u{1cbd}', "\u{10fd}"),
+	('\u{1cbe}', "\u{10fe}"),
+	('\u{1cbf}', "\u{10ff}"),
+	('\u{1e00}', "\u{1e01}"),
+	('\u{1e02}', "\u{1e03}"),
+	('\u{1e04}', "\u{1e05}"),
+	('\u{1e06}', "\u{1e07}"),
+	('\u{1e08}', "\u{1e09}"),
+	('\u{1e0a}', "\u{1e0b}"),
+	('\u{1e0c}', "\u{1e0d}"),
+	('\u{1e0e}', "\u{1e0f}"),
+	('\u{1e10}', "\u{1e11}"),
+	('\u{1e12}', "\u{1e13}"),
+	('\u{1e14}', "\u{1e15}"),
+	('\u{1e16}', "\u{1e17}"),
+	('\u{1e18}', "\u{1e19}"),
+	('\u{1e1a}', "\u{1e1b}"),
+	('\u{1e1c}', "\u{1e1d}"),
+	('\u{1e1e}', "\u{1e1f}"),
+	('\u{1e20}', "\u{1e21}"),
+	('\u{1e22}', "\u{1e23}"),
+	('\u{1e24}', "\u{1e25}"),
+	('\u{1e26}', "\u{1e27}"),
+	('\u{1e28}', "\u{1e29}"),
+	('\u{1e2a}', "\u{1e2b}"),
+	('\u{1e2c}', "\u{1e2d}"),
+	('\u{1e2e}', "\u{1e2f}"),
+	('\u{1e30}', "\u{1e31}"),
+	('\u{1e32}', "\u{1e33}"),
+	('\u{1e34}', "\u{1e35}"),
+	('\u{1e36}', "\u{1e37}"),
+	('\u{1e38}', "\u{1e39}"),
+	('\u{1e3a}', "\u{1e3b}"),
+	('\u{1e3c}', "\u{1e3d}"),
+	('\u{1e3e}', "\u{1e3f}"),
+	('\u{1e40}', "\u{1e41}"),
+	('\u{1e42}', "\u{1e43}"),
+	('\u{1e44}', "\u{1e45}"),
+	('\u{1e46}', "\u{1e47}"),
+	('\u{1e48}', "\u{1e49}"),
+	('\u{1e4a}', "\u{1e4b}"),
+	('\u{1e4c}', "\u{1e4d}"),
+	('\u{1e4e}', "\u{1e4f}"),
+	('\u{1e50}', "\u{1e51}"),
+	('\u{1e52}', "\u{1e53}"),
+	('\u{1e54}', "\u{1e55}"),
+	('\u{1e56}', "\u{1e57}"),
+	('\u{1e58}', "\u{1e59}"),
+	('\u{1e5a}', "\u{1e5b}"),
+	('\u{1e5c}', "\u{1e5d}"),
+	('\u{1e5e}', "\u{1e5f}"),
+	('\u{1e60}', "\u{1e61}"),
+	('\u{1e62}', "\u{1e63}"),
+	('\u{1e64}', "\u{1e65}"),
+	('\u{1e66}', "\u{1e67}"),
+	('\u{1e68}', "\u{1e69}"),
+	('\u{1e6a}', "\u{1e6b}"),
+	('\u{1e6c}', "\u{1e6d}"),
+	('\u{1e6e}', "\u{1e6f}"),
+	('\u{1e70}', "\u{1e71}"),
+	('\u{1e72}', "\u{1e73}"),
+	('\u{1e74}', "\u{1e75}"),
+	('\u{1e76}', "\u{1e77}"),
+	('\u{1e78}', "\u{1e79}"),
+	('\u{1e7a}', "\u{1e7b}"),
+	('\u{1e7c}', "\u{1e7d}"),
+	('\u{1e7e}', "\u{1e7f}"),
+	('\u{1e80}', "\u{1e81}"),
+	('\u{1e82}', "\u{1e83}"),
+	('\u{1e84}', "\u{1e85}"),
+	('\u{1e86}', "\u{1e87}"),
+	('\u{1e88}', "\u{1e89}"),
+	('\u{1e8a}', "\u{1e8b}"),
+	('\u{1e8c}', "\u{1e8d}"),
+	('\u{1e8e}', "\u{1e8f}"),
+	('\u{1e90}', "\u{1e91}"),
+	('\u{1e92}', "\u{1e93}"),
+	('\u{1e94}', "\u{1e95}"),
+	('\u{1e96}', "\u{68}\u{331}"),
+	('\u{1e97}', "\u{74}\u{308}"),
+	('\u{1e98}', "\u{77}\u{30a}"),
+	('\u{1e99}', "\u{79}\u{30a}"),
+	('\u{1e9a}', "\u{61}\u{2be}"),
+	('\u{1e9b}', "\u{1e61}"),
+	('\u{1e9e}', "\u{73}\u{73}"),
+	('\u{1ea0}', "\u{1ea1}"),
+	('\u{1ea2}', "\u{1ea3}"),
+	('\u{1ea4}', "\u{1ea5}"),
+	('\u{1ea6}', "\u{1ea7}"),
+	('\u{1ea8}', "\u{1ea9}"),
+	('\u{1eaa}', "\u{1eab}"),
+	('\u{1eac}', "\u{1ead}"),
+	('\u{1eae}', "\u{1eaf}"),
+	('\u{1eb0}', "\u{1eb1}"),
+	('\u{1eb2}', "\u{1eb3}"),
+	('\u{1eb4}', "\u{1eb5}"),
+	('\u{1eb6}', "\u{1eb7}"),
+	('\u{1eb8}', "\u{1eb9}"),
+	('\u{1eba}', "\u{1ebb}"),
+	('\u{1ebc}', "\u{1ebd}"),
+	('\u{1ebe}', "\u{1ebf}"),
+	('\u{1ec0}', "\u{1ec1}"),
+	('\u{1ec2}', "\u{1ec3}"),
+	('\u{1ec4}', "\u{1ec5}"),
+	('\u{1ec6}', "\u{1ec7}"),
+	('\u{1ec8}', "\u{1ec9}"),
+	('\u{1eca}', "\u{1ecb}"),
+	('\u{1ecc}', "\u{1ecd}"),
+	('\u{1ece}', "\u{1ecf}"),
+	('\u{1ed0}', "\u{1ed1}"),
+	('\u{1ed2}', "\u{1ed3}"),
+	('\u{1ed4}', "\u{1ed5}"),
+	('\u{1ed6}', "\u{1ed7}"),
+	('\u{1ed8}', "\u{1ed9}"),
+	('\u{1eda}', "\u{1edb}"),
+	('\u{1edc}', "\u{1edd}"),
+	('\u{1ede}', "\u{1edf}"),
+	('\u{1ee0}', "\u{1ee1}"),
+	('\u{1ee2}', "\u{1ee3}"),
+	('\u{1ee4}', "\u{1ee5}"),
+	('\u{1ee6}', "\u{1ee7}"),
+	('\u{1ee8}', "\u{1ee9}"),
+	('\u{1eea}', "\u{1eeb}"),
+	('\u{1eec}', "\u{1eed}"),
+	('\u{1eee}', "\u{1eef}"),
+	('\u{1ef0}', "\u{1ef1}"),
+	('\u{1ef2}', "\u{1ef3}"),
+	('\u{1ef4}', "\u{1ef5}"),
+	('\u{1ef6}', "\u{1ef7}"),
+	('\u{1ef8}', "\u{1ef9}"),
+	('\u{1efa}', "\u{1efb}"),
+	('\u{1efc}', "\u{1efd}"),
+	('\u{1efe}', "\u{1eff}"),
+	('\u{1f08}', "\u{1f00}"),
+	('\u{1f09}', "\u{1f01}"),
+	('\u{1f0a}', "\u{1f02}"),
+	('\u{1f0b}', "\u{1f03}"),
+	('\u{1f0c}', "\u{1f04}"),
+	('\u{1f0d}', "\u{1f05}"),
+	('\u{1f0e}', "\u{1f06}"),
+	('\u{1f0f}', "\u{1f07}"),
+	('\u{1f18}', "\u{1f10}"),
+	('\u{1f19}', "\u{1f11}"),
+	('\u{1f1a}', "\u{1f12}"),
+	('\u{1f1b}', "\u{1f13}"),
+	('\u{1f1c}', "\u{1f14}"),
+	('\u{1f1d}', "\u{1f15}"),
+	('\u{1f28}', "\u{1f20}"),
+	('\u{1f29}', "\u{1f21}"),
+	('\u{1f2a}', "\u{1f22}"),
+	('\u{1f2b}', "\u{1f23}"),
+	('\u{1f2c}', "\u{1f24}"),
+	('\u{1f2d}', "\u{1f25}"),
+	('\u{1f2e}', "\u{1f26}"),
+	('\u{1f2f}', "\u{1f27}"),
+	('\u{1f38}', "\u{1f30}"),
+	('\u{1f39}', "\u{1f31}"),
+	('\u{1f3a}', "\u{1f32}"),
+	('\u{1f3b}', "\u{1f33}"),
+	('\u{1f3c}', "\u{1f34}"),
+	('\u{1f3d}', "\u{1f35}"),
+	('\u{1f3e}', "\u{1f36}"),
+	('\u{1f3f}', "\u{1f37}"),
+	('\u{1f48}', "\u{1f40}"),
+	('\u{1f49}', "\u{1f41}"),
+	('\u{1f4a}', "\u{1f42}"),
+	('\u{1f4b}', "\u{1f43}"),
+	('\u{1f4c}', "\u{1f44}"),
+	('\u{1f4d}', "\u{1f45}"),
+	('\u{1f50}', "\u{3c5}\u{313}"),
+	('\u{1f52}', "\u{3c5}\u{313}\u{300}"),
+	('\u{1f54}', "\u{3c5}\u{313}\u{301}"),
+	('\u{1f56}', "\u{3c5}\u{313}\u{342}"),
+	('\u{1f59}', "\u{1f51}"),
+	('\u{1f5b}', "\u{1f53}"),
+	('\u{1f5d}', "\u{1f55}"),
+	('\u{1f5f}', "\u{1f57}"),
+	('\u{1f68}', "\u{1f60}"),
+	('\u{1f69}', "\u{1f61}"),
+	('\u{1f6a}', "\u{1f62}"),
+	('\u{1f6b}', "\u{1f63}"),
+	('\u{1f6c}', "\u{1f64}"),
+	('\u{1f6d}', "\u{1f65}"),
+	('\u{1f6e}', "\u{1f66}"),
+	('\u{1f6f}', "\u{1f67}"),
+	('\u{1f80}', "\u{1f00}\u{3b9}"),
+	('\u{1f81}', "\u{1f01}\u{3b9}"),
+	('\u{1f82}', "\u{1f02}\u{3b9}"),
+	('\u{1f83}', "\u{1f03}\u{3b9}"),
+	('\u{1f84}', "\u{1f04}\u{3b9}"),
+	('\u{1f85}', "\u{1f05}\u{3b9}"),
+	('\u{1f86}', "\u{1f06}\u{3b9}"),
+	('\u{1f87}', "\u{1f07}\u{3b9}"),
+	('\u{1f88}', "\u{1f00}\u{3b9}"),
+	('\u{1f89}', "\u{1f01}\u{3b9}"),
+	('\u{1f8a}', "\u{1f02}\u{3b9}"),
+	('\u{1f8b}', "\u{1f03}\u{3b9}"),
+	('\u{1f8c}', "\u{1f04}\u{3b9}"),
+	('\u{1f8d}', "\u{1f05}\u{3b9}"),
+	('\u{1f8e}', "\u{1f06}\u{3b9}"),
+	('\u{1f8f}', "\u{1f07}\u{3b9}"),
+	('\u{1f90}', "\u{1f20}\u{3b9}"),
+	('\u{1f91}', "\u{1f21}\u{3b9}"),
+	('\u{1f92}', "\u{1f22}\u{3b9}"),
+	('\u{1f93}', "\u{1f23}\u{3b9}"),
+	('\u{1f94}', "\u{1f24}\u{3b9}"),
+	('\u{1f95}', "\u{1f25}\u{3b9}"),
+	('\u{1f96}', "\u{1f26}\u{3b9}"),
+	('\u{1f97}', "\u{1f27}\u{3b9}"),
+	('\u{1f98}', "\u{1f20}\u{3b9}"),
+	('\u{1f99}', "\u{1f21}\u{3b9}"),
+	('\u{1f9a}', "\u{1f22}\u{3b9}"),
+	('\u{1f9b}', "\u{1f23}\u{3b9}"),
+	('\u{1f9c}', "\u{1f24}\u{3b9}"),
+	('\u{1f9d}', "\u{1f25}\u{3b9}"),
+	('\u{1f9e}', "\u{1f26}\u{3b9}"),
+	('\u{1f9f}', "\u{1f27}\u{3b9}"),
+	('\u{1fa0}', "\u{1f60}\u{3b9}"),
+	('\u{1fa1}', "\u{1f61}\u{3b9}"),
+	('\u{1fa2}', "\u{1f62}\u{3b9}"),
+	('\u{1fa3}', "\u{1f63}\u{3b9}"),
+	('\u{1fa4}', "\u{1f64}\u{3b9}"),
+	('\u{1fa5}', "\u{1f65}\u{3b9}"),
+	('\u{1fa6}', "\u{1f66}\u{3b9}"),
+	('\u{1fa7}', "\u{1f67}\u{3b9}"),
+	('\u{1fa8}', "\u{1f60}\u{3b9}"),
+	('\u{1fa9}', "\u{1f61}\u{3b9}"),
+	('\u{1faa}', "\u{1f62}\u{3b9}"),
+	('\u{1fab}', "\u{1f63}\u{3b9}"),
+	('\u{1fac}', "\u{1f64}\u{3b9}"),
+	('\u{1fad}', "\u{1f65}\u{3b9}"),
+	('\u{1fae}', "\u{1f66}\u{3b9}"),
+	('\u{1faf}', "\u{1f67}\u{3b9}"),
+	('\u{1fb2}', "\u{1f70}\u{3b9}"),
+	('\u{1fb3}', "\u{3b1}\u{3b9}"),
+	('\u{1fb4}', "\u{3ac}\u{3b9}"),
+	('\u{1fb6}', "\u{3b1}\u{342}"),
+	('\u{1fb7}', "\u{3b1}\u{342}\u{3b9}"),
+	('\u{1fb8}', "\u{1fb0}"),
+	('\u{1fb9}', "\u{1fb1}"),
+	('\u{1fba}', "\u{1f70}"),
+	('\u{1fbb}', "\u{1f71}"),
+	('\u{1fbc}', "\u{3b1}\u{3b9}"),
+	('\u{1fbe}', "\u{3b9}"),
+	('\u{1fc2}', "\u{1f74}\u{3b9}"),
+	('\u{1fc3}', "\u{3b7}\u{3b9}"),
+	('\u{1fc4}', "\u{3ae}\u{3b9}"),
+	('\u{1fc6}', "\u{3b7}\u{342}"),
+	('\u{1fc7}', "\u{3b7}\u{342}\u{3b9}"),
+	('\u{1fc8}', "\u{1f72}"),
+	('\u{1fc9}', "\u{1f73}"),
+	('\u{1fca}', "\u{1f74}"),
+	('\u{1fcb}', "\u{1f75}"),
+	('\u{1fcc}', "\u{3b7}\u{3b9}"),
+	('\u{1fd2}', "\u{3b9}\u{308}\u{300}"),
+	('\u{1fd3}', "\u{3b9}\u{308}\u{301}"),
+	('\u{1fd6}', "\u{3b9}\u{342}"),
+	('\u{1fd7}', "\u{3b9}\u{308}\u{342}"),
+	('\u{1fd8}', "\u{1fd0}"),
+	('\u{1fd9}', "\u{1fd1}"),
+	('\u{1fda}', "\u{1f76}"),
+	('\u{1fdb}', "\u{1f77}"),
+	('\u{1fe2}', "\u{3c5}\u{308}\u{300}"),
+	('\u{1fe3}', "\u{3c5}\u{308}\u{301}"),
+	('\u{1fe4}', "\u{3c1}\u{313}"),
+	('\u{1fe6}', "\u{3c5}\u{342}"),
+	('\u{1fe7}', "\u{3c5}\u{308}\u{342}"),
+	('\u{1fe8}', "\u{1fe0}"),
+	('\u{1fe9}', "\u{1fe1}"),
+	('\u{1fea}', "\u{1f7a}"),
+	('\u{1feb}', "\u{1f7b}"),
+	('\u{1fec}', "\u{1fe5}"),
+	('\u{1ff2}', "\u{1f7c}\u{3b9}"),
+	('\u{1ff3}', "\u{3c9}\u{3b9}"),
+	('\u{1ff4}', "\u{3ce}\u{3b9}"),
+	('\u{1ff6}', "\u{3c9}\u{342}"),
+	('\u{1ff7}', "\u{3c9}\u{342}\u{3b9}"),
+	('\u{1ff8}', "\u{1f78}"),
+	('\u{1ff9}', "\u{1f79}"),
+	('\u{1ffa}', "\u{1f7c}"),
+	('\u{1ffb}', "\u{1f7d}"),
+	('\u{1ffc}', "\u{3c9}\u{3b9}"),
+	('\u{2126}', "\u{3c9}"),
+	('\u{212a}', "\u{6b}"),
+	('\u{212b}', "\u{e5}"),
+	('\u{2132}', "\u{214e}"),
+	('\u{2160}', "\u{2170}"),
+	('\u{2161}', "\u{2171}"),
+	('\u{2162}', "\u{2172}"),
+	('\u{2163}', "\u{2173}"),
+	('\u{2164}', "\u{2174}"),
+	('\u{2165}', "\u{2175}"),
+	('\u{2166}', "\u{2176}"),
+	('\u{2167}', "\u{2177}"),
+	('\u{2168}', "\u{2178}"),
+	('\u{2169}', "\u{2179}"),
+	('\u{216a}', "\u{217a}"),
+	('\u{216b}', "\u{217b}"),
+	('\u{216c}', "\u{217c}"),
+	('\u{216d}', "\u{217d}"),
+	('\u{216e}', "\u{217e}"),
+	('\u{216f}', "\u{217f}"),
+	('\u{2183}', "\u{2184}"),
+	('\u{24b6}', "\u{24d0}"),
+	('\u{24b7}', "\u{24d1}"),
+	('\u{24b8}', "\u{24d2}"),
+	('\u{24b9}', "\u{24d3}"),
+	('\u{24ba}', "\u{24d4}"),
+	('\u{24bb}', "\u{24d5}"),
+	('\u{24bc}', "\u{24d6}"),
+	('\u{24bd}', "\u{24d7}"),
+	('\u{24be}', "\u{24d8}"),
+	('\u{24bf}', "\u{24d9}"),
+	('\u{24c0}', "\u{24da}"),
+	('\u{24c1}', "\u{24db}"),
+	('\u{24c2}', "\u{24dc}"),
+	('\u{24c3}', "\u{24dd}"),
+	('\u{24c4}', "\u{24de}"),
+	('\u{24c5}', "\u{24df}"),
+	('\u{24c6}', "\u{24e0}"),
+	('\u{24c7}', "\u{24e1}"),
+	('\u{24c8}', "\u{24e2}"),
+	('\u{24c9}', "\u{24e3}"),
+	('\u{24ca}', "\u{24e4}"),
+	('\u{24cb}', "\u{24e5}"),
+	('\u{24cc}', "\u{24e6}"),
+	('\u{24cd}', "\u{24e7}"),
+	('\u{24ce}', "\u{24e8}"),
+	('\u{24cf}', "\u{24e9}"),
+	('\u{2c00}', "\u{2c30}"),
+	('\u{2c01}', "\u{2c31}"),
+	('\u{2c02}', "\u{2c32}"),
+	('\u{2c03}', "\u{2c33}"),
+	('\u{2c04}', "\u{2c34}"),
+	('\u{2c05}', "\u{2c35}"),
+	('\u{2c06}', "\u{2c36}"),
+	('\u{2c07}', "\u{2c37}"),
+	('\u{2c08}', "\u{2c38}"),
+	('\u{2c09}', "\u{2c39}"),
+	('\u{2c0a}', "\u{2c3a}"),
+	('\u{2c0b}', "\u{2c3b}"),
+	('\u{2c0c}', "\u{2c3c}"),
+	('\u{2c0d}', "\u{2c3d}"),
+	('\u{2c0e}', "\u{2c3e}"),
+	('\u{2c0f}', "\u{2c3f}"),
+	('\u{2c10}', "\u{2c40}"),
+	('\u{2c11}', "\u{2c41}"),
+	('\u{2c12}', "\u{2c42}"),
+	('\u{2c13}', "\u{2c43}"),
+	('\u{2c14}', "\u{2c44}"),
+	('\u{2c15}', "\u{2c45}"),
+	('\u{2c16}', "\u{2c46}"),
+	('\u{2c17}', "\u{2c47}"),
+	('\u{2c18}', "\u{2c48}"),
+	('\u{2c19}', "\u{2c49}"),
+	('\u{2c1a}', "\u{2c4a}"),
+	('\u{2c1b}', "\u{2c4b}"),
+	('\u{2c1c}', "\u{2c4c}"),
+	('\u{2c1d}', "\u{2c4d}"),
+	('\u{2c1e}', "\u{2c4e}"),
+	('\u{2c1f}', "\u{2c4f}"),
+	('\u{2c20}', "\u{2c50}"),
+	('\u{2c21}', "\u{2c51}"),
+	('\u{2c22}', "\u{2c52}"),
+	('\u{2c23}', "\u{2c53}"),
+	('\u{2c24}', "\u{2c54}"),
+	('\u{2c25}', "\u{2c55}"),
+	('\u{2c26}', "\u{2c56}"),
+	('\u{2c27}', "\u{2c57}"),
+	('\u{2c28}', "\u{2c58}"),
+	('\u{2c29}', "\u{2c59}"),
+	('\u{2c2a}', "\u{2c5a}"),
+	('\u{2c2b}', "\u{2c5b}"),
+	('\u{2c2c}', "\u{2c5c}"),
+	('\u{2c2d}', "\u{2c5d}"),
+	('\u{2c2e}', "\u{2c5e}"),
+	('\u{2c2f}', "\u{2c5f}"),
+	('\u{2c60}', "\u{2c61}"),
+	('\u{2c62}', "\u{26b}"),
+	('\u{2c63}', "\u{1d7d}"),
+	('\u{2c64}', "\u{27d}"),
+	('\u{2c67}', "\u{2c68}"),
+	('\u{2c69}', "\u{2c6a}"),
+	('\u{2c6b}', "\u{2c6c}"),
+	('\u{2c6d}', "\u{251}"),
+	('\u{2c6e}', "\u{271}"),
+	('\u{2c6f}', "\u{250}"),
+	('\u{2c70}', "\u{252}"),
+	('\u{2c72}', "\u{2c73}"),
+	('\u{2c75}', "\u{2c76}"),
+	('\u{2c7e}', "\u{23f}"),
+	('\u{2c7f}', "\u{240}"),
+	('\u{2c80}', "\u{2c81}"),
+	('\u{2c82}', "\u{2c83}"),
+	('\u{2c84}', "\u{2c85}"),
+	('\u{2c86}', "\u{2c87}"),
+	('\u{2c88}', "\u{2c89}"),
+	('\u{2c8a}', "\u{2c8b}"),
+	('\u{2c8c}', "\u{2c8d}"),
+	('\u{2c8e}', "\u{2c8f}"),
+	('\u{2c90}', "\u{2c91}"),
+	('\u{2c92}', "\u{2c93}"),
+	('\u{2c94}', "\u{2c95}"),
+	('\u{2c96}', "\u{2c97}"),
+	('\u{2c98}', "\u{2c99}"),
+	('\u{2c9a}', "\u{2c9b}"),
+	('\u{2c9c}', "\u{2c9d}"),
+	('\u{2c9e}', "\u{2c9f}"),
+	('\u{2ca0}', "\u{2ca1}"),
+	('\u{2ca2}', "\u{2ca3}"),
+	('\u{2ca4}', "\u{2ca5}"),
+	('\u{2ca6}', "\u{2ca7}"),
+	('\u{2ca8}', "\u{2ca9}"),
+	('\u{2caa}', "\u{2cab}"),
+	('\u{2cac}', "\u{2cad}"),
+	('\u{2cae}', "\u{2caf}"),
+	('\u{2cb0}', "\u{2cb1}"),
+	('\u{2cb2}', "\u{2cb3}"),
+	('\u{2cb4}', "\u{2cb5}"),
+	('\u{2cb6}', "\u{2cb7}"),
+	('\u{2cb8}', "\u{2cb9}"),
+	('\u{2cba}', "\u{2cbb}"),
+	('\u{2cbc}', "\u{2cbd}"),
+	('\u{2cbe}', "\u{2cbf}"),
+	('\u{2cc0}', "\u{2cc1}"),
+	('\u{2cc2}', "\u{2cc3}"),
+	('\u{2cc4}', "\u{2cc5}"),
+	('\u{2cc6}', "\u{2cc7}"),
+	('\u{2cc8}', "\u{2cc9}"),
+	('\u{2cca}', "\u{2ccb}"),
+	('\u{2ccc}', "\u{2ccd}"),
+	('\u{2cce}', "\u{2ccf}"),
+	('\u{2cd0}', "\u{2cd1}"),
+	('\u{2cd2}', "\u{2cd3}"),
+	('\u{2cd4}', "\u{2cd5}"),
+	('\u{2cd6}', "\u{2cd7}"),
+	('\u{2cd8}', "\u{2cd9}"),
+	('\u{2cda}', "\u{2cdb}"),
+	('\u{2cdc}', "\u{2cdd}"),
+	('\u{2cde}', "\u{2cdf}"),
+	('\u{2ce0}', "\u{2ce1}"),
+	('\u{2ce2}', "\u{2ce3}"),
+	('\u{2ceb}', "\u{2cec}"),
+	('\u{2ced}', "\u{2cee}"),
+	('\u{2cf2}', "\u{2cf3}"),
+	('\u{a640}', "\u{a641}"),
+	('\u{a642}', "\u{a643}"),
+	('\u{a644}', "\u{a645}"),
+	('\u{a646}', "\u{a647}"),
+	('\u{a648}', "\u{a649}"),
+	('\u{a64a}', "\u{a64b}"),
+	('\u{a64c}', "\u{a64d}"),
+	('\u{a64e}', "\u{a64f}"),
+	('\u{a650}', "\u{a651}"),
+	('\u{a652}', "\u{a653}"),
+	('\u{a654}', "\u{a655}"),
+	('\u{a656}', "\u{a657}"),
+	('\u{a658}', "\u{a659}"),
+	('\u{a65a}', "\u{a65b}"),
+	('\u{a65c}', "\u{a65d}"),
+	('\u{a65e}', "\u{a65f}"),
+	('\u{a660}', "\u{a661}"),
+	('\u{a662}', "\u{a663}"),
+	('\u{a664}', "\u{a665}"),
+	('\u{a666}', "\u{a667}"),
+	('\u{a668}', "\u{a669}"),
+	('\u{a66a}', "\u{a66b}"),
+	('\u{a66c}', "\u{a66d}"),
+	('\u{a680}', "\u{a681}"),
+	('\u{a682}', "\u{a683}"),
+	('\u{a684}', "\u{a685}"),
+	('\u{a686}', "\u{a687}"),
+	('\u{a688}', "\u{a689}"),
+	('\u{a68a}', "\u{a68b}"),
+	('\u{a68c}', "\u{a68d}"),
+	('\u{a68e}', "\u{a68f}"),
+	('\u{a690}', "\u{a691}"),
+	('\u{a692}', "\u{a693}"),
+	('\u{a694}', "\u{a695}"),
+	('\u{a696}', "\u{a697}"),
+	('\u{a698}', "\u{a699}"),
+	('\u{a69a}', "\u{a69b}"),
+	('\u{a722}', "\u{a723}"),
+	('\u{a724}', "\u{a725}"),
+	('\u{a726}', "\u{a727}"),
+	('\u{a728}', "\u{a729}"),
+	('\u{a72a}', "\u{a72b}"),
+	('\u{a72c}', "\u{a72d}"),
+	('\u{a72e}', "\u{a72f}"),
+	('\u{a732}', "\u{a733}"),
+	('\u{a734}', "\u{a735}"),
+	('\u{a736}', "\u{a737}"),
+	('\u{a738}', "\u{a739}"),
+	('\u{a73a}', "\u{a73b}"),
+	('\u{a73c}', "\u{a73d}"),
+	('\u{a73e}', "\u{a73f}"),
+	('\u{a740}', "\u{a741}"),
+	('\u{a742}', "\u{a743}"),
+	('\u{a744}', "\u{a745}"),
+	('\u{a746}', "\u{a747}"),
+	('\u{a748}', "\u{a749}"),
+	('\u{a74a}', "\u{a74b}"),
+	('\u{a74c}', "\u{a74d}"),
+	('\u{a74e}', "\u{a74f}"),
+	('\u{a750}', "\u{a751}"),
+	('\u{a752}', "\u{a753}"),
+	('\u{a754}', "\u{a755}"),
+	('\u{a756}', "\u{a757}"),
+	('\u{a758}', "\u{a759}"),
+	('\u{a75a}', "\u{a75b}"),
+	('\u{a75c}', "\u{a75d}"),
+	('\u{a75e}', "\u{a75f}"),
+	('\u{a760}', "\u{a761}"),
+	('\u{a762}', "\u{a763}"),
+	('\u{a764}', "\u{a765}"),
+	('\u{a766}', "\u{a767}"),
+	('\u{a768}', "\u{a769}"),
+	('\u{a76a}', "\u{a76b}"),
+	('\u{a76c}', "\u{a76d}"),
+	('\u{a76e}', "\u{a76f}"),
+	('\u{a779}', "\u{a77a}"),
+	('\u{a77b}', "\u{a77c}"),
+	('\u{a77d}', "\u{1d79}"),
+	('\u{a77e}', "\u{a77f}"),
+	('\u{a780}', "\u{a781}"),
+	('\u{a782}', "\u{a783}"),
+	('\u{a784}', "\u{a785}"),
+	('\u{a786}', "\u{a787}"),
+	('\u{a78b}', "\u{a78c}"),
+	('\u{a78d}', "\u{265}"),
+	('\u{a790}', "\u{a791}"),
+	('\u{a792}', "\u{a793}"),
+	('\u{a796}', "\u{a797}"),
+	('\u{a798}', "\u{a799}"),
+	('\u{a79a}', "\u{a79b}"),
+	('\u{a79c}', "\u{a79d}"),
+	('\u{a79e}', "\u{a79f}"),
+	('\u{a7a0}', "\u{a7a1}"),
+	('\u{a7a2}', "\u{a7a3}"),
+	('\u{a7a4}', "\u{a7a5}"),
+	('\u{a7a6}', "\u{a7a7}"),
+	('\u{a7a8}', "\u{a7a9}"),
+	('\u{a7aa}', "\u{266}"),
+	('\u{a7ab}', "\u{25c}"),
+	('\u{a7ac}', "\u{261}"),
+	('\u{a7ad}', "\u{26c}"),
+	('\u{a7ae}', "\u{26a}"),
+	('\u{a7b0}', "\u{29e}"),
+	('\u{a7b1}', "\u{287}"),
+	('\u{a7b2}', "\u{29d}"),
+	('\u{a7b3}', "\u{ab53}"),
+	('\u{a7b4}', "\u{a7b5}"),
+	('\u{a7b6}', "\u{a7b7}"),
+	('\u{a7b8}', "\u{a7b9}"),
+	('\u{a7ba}', "\u{a7bb}"),
+	('\u{a7bc}', "\u{a7bd}"),
+	('\u{a7be}', "\u{a7bf}"),
+	('\u{a7c0}', "\u{a7c1}"),
+	('\u{a7c2}', "\u{a7c3}"),
+	('\u{a7c4}', "\u{a794}"),
+	('\u{a7c5}', "\u{282}"),
+	('\u{a7c6}', "\u{1d8e}"),
+	('\u{a7c7}', "\u{a7c8}"),
+	('\u{a7c9}', "\u{a7ca}"),
+	('\u{a7d0}', "\u{a7d1}"),
+	('\u{a7d6}', "\u{a7d7}"),
+	('\u{a7d8}', "\u{a7d9}"),
+	('\u{a7f5}', "\u{a7f6}"),
+	('\u{ab70}', "\u{13a0}"),
+	('\u{ab71}', "\u{13a1}"),
+	('\u{ab72}', "\u{13a2}"),
+	('\u{ab73}', "\u{13a3}"),
+	('\u{ab74}', "\u{13a4}"),
+	('\u{ab75}', "\u{13a5}"),
+	('\u{ab76}', "\u{13a6}"),
+	('\u{ab77}', "\u{13a7}"),
+	('\u{ab78}', "\u{13a8}"),
+	('\u{ab79}', "\u{13a9}"),
+	('\u{ab7a}', "\u{13aa}"),
+	('\u{ab7b}', "\u{13ab}"),
+	('\u{ab7c}', "\u{13ac}"),
+	('\u{ab7d}', "\u{13ad}"),
+	('\u{ab7e}', "\u{13ae}"),
+	('\u{ab7f}', "\u{13af}"),
+	('\u{ab80}', "\u{13b0}"),
+	('\u{ab81}', "\u{13b1}"),
+	('\u{ab82}', "\u{13b2}"),
+	('\u{ab83}', "\u{13b3}"),
+	('\u{ab84}', "\u{13b4}"),
+	('\u{ab85}', "\u{13b5}"),
+	('\u{ab86}', "\u{13b6}"),
+	('\u{ab87}', "\u{13b7}"),
+	('\u{ab88}', "\u{13b8}"),
+	('\u{ab89}', "\u{13b9}"),
+	('\u{ab8a}', "\u{13ba}"),
+	('\u{ab8b}', "\u{13bb}"),
+	('\u{ab8c}', "\u{13bc}"),
+	('\u{ab8d}', "\u{13bd}"),
+	('\u{ab8e}', "\u{13be}"),
+	('\u{ab8f}', "\u{13bf}"),
+	('\u{ab90}', "\u{13c0}"),
+	('\u{ab91}', "\u{13c1}"),
+	('\u{ab92}', "\u{13c2}"),
+	('\u{ab93}', "\u{13c3}"),
+	('\u{ab94}', "\u{13c4}"),
+	('\u{ab95}', "\u{13c5}"),
+	('\u{ab96}', "\u{13c6}"),
+	('\u{ab97}', "\u{13c7}"),
+	('\u{ab98}', "\u{13c8}"),
+	('\u{ab99}', "\u{13c9}"),
+	('\u{ab9a}', "\u{13ca}"),
+	('\u{ab9b}', "\u{13cb}"),
+	('\u{ab9c}', "\u{13cc}"),
+	('\u{ab9d}', "\u{13cd}"),
+	('\u{ab9e}', "\u{13ce}"),
+	('\u{ab9f}', "\u{13cf}"),
+	('\u{aba0}', "\u{13d0}"),
+	('\u{aba1}', "\u{13d1}"),
+	('\u{aba2}', "\u{13d2}"),
+	('\u{aba3}', "\u{13d3}"),
+	('\u{aba4}', "\u{13d4}"),
+	('\u{aba5}', "\u{13d5}"),
+	('\u{aba6}', "\u{13d6}"),
+	('\u{aba7}', "\u{13d7}"),
+	('\u{aba8}', "\u{13d8}"),
+	('\u{aba9}', "\u{13d9}"),
+	('\u{abaa}', "\u{13da}"),
+	('\u{abab}', "\u{13db}"),
+	('\u{abac}', "\u{13dc}"),
+	('\u{abad}', "\u{13dd}"),
+	('\u{abae}', "\u{13de}"),
+	('\u{abaf}', "\u{13df}"),
+	('\u{abb0}', "\u{13e0}"),
+	('\u{abb1}', "\u{13e1}"),
+	('\u{abb2}', "\u{13e2}"),
+	('\u{abb3}', "\u{13e3}"),
+	('\u{abb4}', "\u{13e4}"),
+	('\u{abb5}', "\u{13e5}"),
+	('\u{abb6}', "\u{13e6}"),
+	('\u{abb7}', "\u{13e7}"),
+	('\u{abb8}', "\u{13e8}"),
+	('\u{abb9}', "\u{13e9}"),
+	('\u{abba}', "\u{13ea}"),
+	('\u{abbb}', "\u{13eb}"),
+	('\u{abbc}', "\u{13ec}"),
+	('\u{abbd}', "\u{13ed}"),
+	('\u{abbe}', "\u{13ee}"),
+	('\u{abbf}', "\u{13ef}"),
+	('\u{fb00}', "\u{66}\u{66}"),
+	('\u{fb01}', "\u{66}\u{69}"),
+	('\u{fb02}', "\u{66}\u{6c}"),
+	('\u{fb03}', "\u{66}\u{66}\u{69}"),
+	('\u{fb04}', "\u{66}\u{66}\u{6c}"),
+	('\u{fb05}', "\u{73}\u{74}"),
+	('\u{fb06}', "\u{73}\u{74}"),
+	('\u{fb13}', "\u{574}\u{576}"),
+	('\u{fb14}', "\u{574}\u{565}"),
+	('\u{fb15}', "\u{574}\u{56b}"),
+	('\u{fb16}', "\u{57e}\u{576}"),
+	('\u{fb17}', "\u{574}\u{56d}"),
+	('\u{ff21}', "\u{ff41}"),
+	('\u{ff22}', "\u{ff42}"),
+	('\u{ff23}', "\u{ff43}"),
+	('\u{ff24}', "\u{ff44}"),
+	('\u{ff25}', "\u{ff45}"),
+	('\u{ff26}', "\u{ff46}"),
+	('\u{ff27}', "\u{ff47}"),
+	('\u{ff28}', "\u{ff48}"),
+	('\u{ff29}', "\u{ff49}"),
+	('\u{ff2a}', "\u{ff4a}"),
+	('\u{ff2b}', "\u{ff4b}"),
+	('\u{ff2c}', "\u{ff4c}"),
+	('\u{ff2d}', "\u{ff4d}"),
+	('\u{ff2e}', "\u{ff4e}"),
+	('\u{ff2f}', "\u{ff4f}"),
+	('\u{ff30}', "\u{ff50}"),
+	('\u{ff31}', "\u{ff51}"),
+	('\u{ff32}', "\u{ff52}"),
+	('\u{ff33}', "\u{ff53}"),
+	('\u{ff34}', "\u{ff54}"),
+	('\u{ff35}', "\u{ff55}"),
+	('\u{ff36}', "\u{ff56}"),
+	('\u{ff37}', "\u{ff57}"),
+	('\u{ff38}', "\u{ff58}"),
+	('\u{ff39}', "\u{ff59}"),
+	('\u{ff3a}', "\u{ff5a}"),
+	('\u{10400}', "\u{10428}"),
+	('\u{10401}', "\u{10429}"),
+	('\u{10402}', "\u{1042a}"),
+	('\u{10403}', "\u{1042b}"),
+	('\u{10404}', "\u{1042c}"),
+	('\u{10405}', "\u{1042d}"),
+	('\u{10406}', "\u{1042e}"),
+	('\u{10407}', "\u{1042f}"),
+	('\u{10408}', "\u{10430}"),
+	('\u{10409}', "\u{10431}"),
+	('\u{1040a}', "\u{10432}"),
+	('\u{1040b}', "\u{10433}"),
+	('\u{1040c}', "\u{10434}"),
+	('\u{1040d}', "\u{10435}"),
+	('\u{1040e}', "\u{10436}"),
+	('\u{1040f}', "\u{10437}"),
+	('\u{10410}', "\u{10438}"),
+	('\u{10411}', "\u{10439}"),
+	('\u{10412}', "\u{1043a}"),
+	('\u{10413}', "\u{1043b}"),
+	('\u{10414}', "\u{1043c}"),
+	('\u{10415}', "\u{1043d}"),
+	('\u{10416}', "\u{1043e}"),
+	('\u{10417}', "\u{1043f}"),
+	('\u{10418}', "\u{10440}"),
+	('\u{10419}', "\u{10441}"),
+	('\u{1041a}', "\u{10442}"),
+	('\u{1041b}', "\u{10443}"),
+	('\u{1041c}', "\u{10444}"),
+	('\u{1041d}', "\u{10445}"),
+	('\u{1041e}', "\u{10446}"),
+	('\u{1041f}', "\u{10447}"),
+	('\u{10420}', "\u{10448}"),
+	('\u{10421}', "\u{10449}"),
+	('\u{10422}', "\u{1044a}"),
+	('\u{10423}', "\u{1044b}"),
+	('\u{10424}', "\u{1044c}"),
+	('\u{10425}', "\u{1044d}"),
+	('\u{10426}', "\u{1044e}"),
+	('\u{10427}', "\u{1044f}"),
+	('\u{104b0}', "\u{104d8}"),
+	('\u{104b1}', "\u{104d9}"),
+	('\u{104b2}', "\u{104da}"),
+	('\u{104b3}', "\u{104db}"),
+	('\u{104b4}', "\u{104dc}"),
+	('\u{104b5}', "\u{104dd}"),
+	('\u{104b6}', "\u{104de}"),
+	('\u{104b7}', "\u{104df}"),
+	('\u{104b8}', "\u{104e0}"),
+	('\u{104b9}', "\u{104e1}"),
+	('\u{104ba}', "\u{104e2}"),
+	('\u{104bb}', "\u{104e3}"),
+	('\u{104bc}', "\u{104e4}"),
+	('\u{104bd}', "\u{104e5}"),
+	('\u{104be}', "\u{104e6}"),
+	('\u{104bf}', "\u{104e7}"),
+	('\u{104c0}', "\u{104e8}"),
+	('\u{104c1}', "\u{104e9}"),
+	('\u{104c2}', "\u{104ea}"),
+	('\u{104c3}', "\u{104eb}"),
+	('\u{104c4}', "\u{104ec}"),
+	('\u{104c5}', "\u{104ed}"),
+	('\u{104c6}', "\u{104ee}"),
+	('\u{104c7}', "\u{104ef}"),
+	('\u{104c8}', "\u{104f0}"),
+	('\u{104c9}', "\u{104f1}"),
+	('\u{104ca}', "\u{104f2}"),
+	('\u{104cb}', "\u{104f3}"),
+	('\u{104cc}', "\u{104f4}"),
+	('\u{104cd}', "\u{104f5}"),
+	('\u{104ce}', "\u{104f6}"),
+	('\u{104cf}', "\u{104f7}"),
+	('\u{104d0}', "\u{104f8}"),
+	('\u{104d1}', "\u{104f9}"),
+	('\u{104d2}', "\u{104fa}"),
+	('\u{104d3}', "\u{104fb}"),
+	('\u{10570}', "\u{10597}"),
+	('\u{10571}', "\u{10598}"),
+	('\u{10572}', "\u{10599}"),
+	('\u{10573}', "\u{1059a}"),
+	('\u{10574}', "\u{1059b}"),
+	('\u{10575}', "\u{1059c}"),
+	('\u{10576}', "\u{1059d}"),
+	('\u{10577}', "\u{1059e}"),
+	('\u{10578}', "\u{1059f}"),
+	('\u{10579}', "\u{105a0}"),
+	('\u{1057a}', "\u{105a1}"),
+	('\u{1057c}', "\u{105a3}"),
+	('\u{1057d}', "\u{105a4}"),
+	('\u{1057e}', "\u{105a5}"),
+	('\u{1057f}', "\u{105a6}"),
+	('\u{10580}', "\u{105a7}"),
+	('\u{10581}', "\u{105a8}"),
+	('\u{10582}', "\u{105a9}"),
+	('\u{10583}', "\u{105aa}"),
+	('\u{10584}', "\u{105ab}"),
+	('\u{10585}', "\u{105ac}"),
+	('\u{10586}', "\u{105ad}"),
+	('\u{10587}', "\u{105ae}"),
+	('\u{10588}', "\u{105af}"),
+	('\u{10589}', "\u{105b0}"),
+	('\u{1058a}', "\u{105b1}"),
+	('\u{1058c}', "\u{105b3}"),
+	('\u{1058d}', "\u{105b4}"),
+	('\u{1058e}', "\u{105b5}"),
+	('\u{1058f}', "\u{105b6}"),
+	('\u{10590}', "\u{105b7}"),
+	('\u{10591}', "\u{105b8}"),
+	('\u{10592}', "\u{105b9}"),
+	('\u{10594}', "\u{105bb}"),
+	('\u{10595}', "\u{105bc}"),
+	('\u{10c80}', "\u{10cc0}"),
+	('\u{10c81}', "\u{10cc1}"),
+	('\u{10c82}', "\u{10cc2}"),
+	('\u{10c83}', "\u{10cc3}"),
+	('\u{10c84}', "\u{10cc4}"),
+	('\u{10c85}', "\u{10cc5}"),
+	('\u{10c86}', "\u{10cc6}"),
+	('\u{10c87}', "\u{10cc7}"),
+	('\u{10c88}', "\u{10cc8}"),
+	('\u{10c89}', "\u{10cc9}"),
+	('\u{10c8a}', "\u{10cca}"),
+	('\u{10c8b}', "\u{10ccb}"),
+	('\u{10c8c}', "\u{10ccc}"),
+	('\u{10c8d}', "\u{10ccd}"),
+	('\u{10c8e}', "\u{10cce}"),
+	('\u{10c8f}', "\u{10ccf}"),
+	('\u{10c90}', "\u{10cd0}"),
+	('\u{10c91}', "\u{10cd1}"),
+	('\u{10c92}', "\u{10cd2}"),
+	('\u{10c93}', "\u{10cd3}"),
+	('\u{10c94}', "\u{10cd4}"),
+	('\u{10c95}', "\u{10cd5}"),
+	('\u{10c96}', "\u{10cd6}"),
+	('\u{10c97}', "\u{10cd7}"),
+	('\u{10c98}', "\u{10cd8}"),
+	('\u{10c99}', "\u{10cd9}"),
+	('\u{10c9a}', "\u{10cda}"),
+	('\u{10c9b}', "\u{10cdb}"),
+	('\u{10c9c}', "\u{10cdc}"),
+	('\u{10c9d}', "\u{10cdd}"),
+	('\u{10c9e}', "\u{10cde}"),
+	('\u{10c9f}', "\u{10cdf}"),
+	('\u{10ca0}', "\u{10ce0}"),
+	('\u{10ca1}', "\u{10ce1}"),
+	('\u{10ca2}', "\u{10ce2}"),
+	('\u{10ca3}', "\u{10ce3}"),
+	('\u{10ca4}', "\u{10ce4}"),
+	('\u{10ca5}', "\u{10ce5}"),
+	('\u{10ca6}', "\u{10ce6}"),
+	('\u{10ca7}', "\u{10ce7}"),
+	('\u{10ca8}', "\u{10ce8}"),
+	('\u{10ca9}', "\u{10ce9}"),
+	('\u{10caa}', "\u{10cea}"),
+	('\u{10cab}', "\u{10ceb}"),
+	('\u{10cac}', "\u{10cec}"),
+	('\u{10cad}', "\u{10ced}"),
+	('\u{10cae}', "\u{10cee}"),
+	('\u{10caf}', "\u{10cef}"),
+	('\u{10cb0}', "\u{10cf0}"),
+	('\u{10cb1}', "\u{10cf1}"),
+	('\u{10cb2}', "\u{10cf2}"),
+	('\u{118a0}', "\u{118c0}"),
+	('\u{118a1}', "\u{118c1}"),
+	('\u{118a2}', "\u{118c2}"),
+	('\u{118a3}', "\u{118c3}"),
+	('\u{118a4}', "\u{118c4}"),
+	('\u{118a5}', "\u{118c5}"),
+	('\u{118a6}', "\u{118c6}"),
+	('\u{118a7}', "\u{118c7}"),
+	('\u{118a8}', "\u{118c8}"),
+	('\u{118a9}', "\u{118c9}"),
+	('\u{118aa}', "\u{118ca}"),
+	('\u{118ab}', "\u{118cb}"),
+	('\u{118ac}', "\u{118cc}"),
+	('\u{118ad}', "\u{118cd}"),
+	('\u{118ae}', "\u{118ce}"),
+	('\u{118af}', "\u{118cf}"),
+	('\u{118b0}', "\u{118d0}"),
+	('\u{118b1}', "\u{118d1}"),
+	('\u{118b2}', "\u{118d2}"),
+	('\u{118b3}', "\u{118d3}"),
+	('\u{118b4}', "\u{118d4}"),
+	('\u{118b5}', "\u{118d5}"),
+	('\u{118b6}', "\u{118d6}"),
+	('\u{118b7}', "\u{118d7}"),
+	('\u{118b8}', "\u{118d8}"),
+	('\u{118b9}', "\u{118d9}"),
+	('\u{118ba}', "\u{118da}"),
+	('\u{118bb}', "\u{118db}"),
+	('\u{118bc}', "\u{118dc}"),
+	('\u{118bd}', "\u{118dd}"),
+	('\u{118be}', "\u{118de}"),
+	('\u{118bf}', "\u{118df}"),
+	('\u{16e40}', "\u{16e60}"),
+	('\u{16e41}', "\u{16e61}"),
+	('\u{16e42}', "\u{16e62}"),
+	('\u{16e43}', "\u{16e63}"),
+	('\u{16e44}', "\u{16e64}"),
+	('\u{16e45}', "\u{16e65}"),
+	('\u{16e46}', "\u{16e66}"),
+	('\u{16e47}', "\u{16e67}"),
+	('\u{16e48}', "\u{16e68}"),
+	('\u{16e49}', "\u{16e69}"),
+	('\u{16e4a}', "\u{16e6a}"),
+	('\u{16e4b}', "\u{16e6b}"),
+	('\u{16e4c}', "\u{16e6c}"),
+	('\u{16e4d}', "\u{16e6d}"),
+	('\u{16e4e}', "\u{16e6e}"),
+	('\u{16e4f}', "\u{16e6f}"),
+	('\u{16e50}', "\u{16e70}"),
+	('\u{16e51}', "\u{16e71}"),
+	('\u{16e52}', "\u{16e72}"),
+	('\u{16e53}', "\u{16e73}"),
+	('\u{16e54}', "\u{16e74}"),
+	('\u{16e55}', "\u{16e75}"),
+	('\u{16e56}', "\u{16e76}"),
+	('\u{16e57}', "\u{16e77}"),
+	('\u{16e58}', "\u{16e78}"),
+	('\u{16e59}', "\u{16e79}"),
+	('\u{16e5a}', "\u{16e7a}"),
+	('\u{16e5b}', "\u{16e7b}"),
+	('\u{16e5c}', "\u{16e7c}"),
+	('\u{16e5d}', "\u{16e7d}"),
+	('\u{16e5e}', "\u{16e7e}"),
+	('\u{16e5f}', "\u{16e7f}"),
+	('\u{1e900}', "\u{1e922}"),
+	('\u{1e901}', "\u{1e923}"),
+	('\u{1e902}', "\u{1e924}"),
+	('\u{1e903}', "\u{1e925}"),
+	('\u{1e904}', "\u{1e926}"),
+	('\u{1e905}', "\u{1e927}"),
+	('\u{1e906}', "\u{1e928}"),
+	('\u{1e907}', "\u{1e929}"),
+	('\u{1e908}', "\u{1e92a}"),
+	('\u{1e909}', "\u{1e92b}"),
+	('\u{1e90a}', "\u{1e92c}"),
+	('\u{1e90b}', "\u{1e92d}"),
+	('\u{1e90c}', "\u{1e92e}"),
+	('\u{1e90d}', "\u{1e92f}"),
+	('\u{1e90e}', "\u{1e930}"),
+	('\u{1e90f}', "\u{1e931}"),
+	('\u{1e910}', "\u{1e932}"),
+	('\u{1e911}', "\u{1e933}"),
+	('\u{1e912}', "\u{1e934}"),
+	('\u{1e913}', "\u{1e935}"),
+	('\u{1e914}', "\u{1e936}"),
+	('\u{1e915}', "\u{1e937}"),
+	('\u{1e916}', "\u{1e938}"),
+	('\u{1e917}', "\u{1e939}"),
+	('\u{1e918}', "\u{1e93a}"),
+	('\u{1e919}', "\u{1e93b}"),
+	('\u{1e91a}', "\u{1e93c}"),
+	('\u{1e91b}', "\u{1e93d}"),
+	('\u{1e91c}', "\u{1e93e}"),
+	('\u{1e91d}', "\u{1e93f}"),
+	('\u{1e91e}', "\u{1e940}"),
+	('\u{1e91f}', "\u{1e941}"),
+	('\u{1e920}', "\u{1e942}"),
+	('\u{1e921}', "\u{1e943}"),
+];
+
+/**
+Turkic-specific case folding mappings; that is, those with status `T` in `CaseFolding.txt`.  These take precedence over `CASE_FOLDING` when Turkic folding is requested.
+*/
+pub static TURKIC_CASE_FOLDING: &'static [(char, &'static str)] = &[
+	('\u{49}', "\u{131}"),
+	('\u{130}', "\u{69}"),
+];
+
+/**
+Ranges of code points with the general category `Mn` (non-spacing mark) or `Me` (enclosing mark).  Sorted, non-overlapping and inclusive.
+*/
+pub static COMBINING_MARKS: &'static [(char, char)] = &[
+	('\u{300}', '\u{36f}'),
+	('\u{483}', '\u{489}'),
+	('\u{591}', '\u{5bd}'),
+	('\u{5bf}', '\u{5bf}'),
+	('\u{5c1}', '\u{5c2}'),
+	('\u{5c4}', '\u{5c5}'),
+	('\u{5c7}', '\u{5c7}'),
+	('\u{610}', '\u{61a}'),
+	('\u{64b}', '\u{65f}'),
+	('\u{670}', '\u{670}'),
+	('\u{6d6}', '\u{6dc}'),
+	('\u{6df}', '\u{6e4}'),
+	('\u{6e7}', '\u{6e8}'),
+	('\u{6ea}', '\u{6ed}'),
+	('\u{711}', '\u{711}'),
+	('\u{730}', '\u{74a}'),
+	('\u{7a6}', '\u{7b0}'),
+	('\u{7eb}', '\u{7f3}'),
+	('\u{7fd}', '\u{7fd}'),
+	('\u{816}', '\u{819}'),
+	('\u{81b}', '\u{823}'),
+	('\u{825}', '\u{827}'),
+	('\u{829}', '\u{82d}'),
+	('\u{859}', '\u{85b}'),
+	('\u{898}', '\u{89f}'),
+	('\u{8ca}', '\u{8e1}'),
+	('\u{8e3}', '\u{902}'),
+	('\u{93a}', '\u{93a}'),
+	('\u{93c}', '\u{93c}'),
+	('\u{941}', '\u{948}'),
+	('\u{94d}', '\u{94d}'),
+	('\u{951}', '\u{957}'),
+	('\u{962}', '\u{963}'),
+	('\u{981}', '\u{981}'),
+	('\u{9bc}', '\u{9bc}'),
+	('\u{9c1}', '\u{9c4}'),
+	('\u{9cd}', '\u{9cd}'),
+	('\u{9e2}', '\u{9e3}'),
+	('\u{9fe}', '\u{9fe}'),
+	('\u{a01}', '\u{a02}'),
+	('\u{a3c}', '\u{a3c}'),
+	('\u{a41}', '\u{a42}'),
+	('\u{a47}', '\u{a48}'),
+	('\u{a4b}', '\u{a4d}'),
+	('\u{a51}', '\u{a51}'),
+	('\u{a70}', '\u{a71}'),
+	('\u{a75}', '\u{a75}'),
+	('\u{a81}', '\u{a82}'),
+	('\u{abc}', '\u{abc}'),
+	('\u{ac1}', '\u{ac5}'),
+	('\u{ac7}', '\u{ac8}'),
+	('\u{acd}', '\u{acd}'),
+	('\u{ae2}', '\u{ae3}'),
+	('\u{afa}', '\u{aff}'),
+	('\u{b01}', '\u{b01}'),
+	('\u{b3c}', '\u{b3c}'),
+	('\u{b3f}', '\u{b3f}'),
+	('\u{b41}', '\u{b44}'),
+	('\u{b4d}', '\u{b4d}'),
+	('\u{b55}', '\u{b56}'),
+	('\u{b62}', '\u{b63}'),
+	('\u{b82}', '\u{b82}'),
+	('\u{bc0}', '\u{bc0}'),
+	('\u{bcd}', '\u{bcd}'),
+	('\u{c00}', '\u{c00}'),
+	('\u{c04}', '\u{c04}'),
+	('\u{c3c}', '\u{c3c}'),
+	('\u{c3e}', '\u{c40}'),
+	('\u{c46}', '\u{c48}'),
+	('\u{c4a}', '\u{c4d}'),
+	('\u{c55}', '\u{c56}'),
+	('\u{c62}', '\u{c63}'),
+	('\u{c81}', '\u{c81}'),
+	('\u{cbc}', '\u{cbc}'),
+	('\u{cbf}', '\u{cbf}'),
+	('\u{cc6}', '\u{cc6}'),
+	('\u{ccc}', '\u{ccd}'),
+	('\u{ce2}', '\u{ce3}'),
+	('\u{d00}', '\u{d01}'),
+	('\u{d3b}', '\u{d3c}'),
+	('\u{d41}', '\u{d44}'),
+	('\u{d4d}', '\u{d4d}'),
+	('\u{d62}', '\u{d63}'),
+	('\u{d81}', '\u{d81}'),
+	('\u{dca}', '\u{dca}'),
+	('\u{dd2}', '\u{dd4}'),
+	('\u{dd6}', '\u{dd6}'),
+	('\u{e31}', '\u{e31}'),
+	('\u{e34}', '\u{e3a}'),
+	('\u{e47}', '\u{e4e}'),
+	('\u{eb1}', '\u{eb1}'),
+	('\u{eb4}', '\u{ebc}'),
+	('\u{ec8}', '\u{ecd}'),
+	('\u{f18}', '\u{f19}'),
+	('\u{f35}', '\u{f35}'),
+	('\u{f37}', '\u{f37}'),
+	('\u{f39}', '\u{f39}'),
+	('\u{f71}', '\u{f7e}'),
+	('\u{f80}', '\u{f84}'),
+	('\u{f86}', '\u{f87}'),
+	('\u{f8d}', '\u{f97}'),
+	('\u{f99}', '\u{fbc}'),
+	('\u{fc6}', '\u{fc6}'),
+	('\u{102d}', '\u{1030}'),
+	('\u{1032}', '\u{1037}'),
+	('\u{1039}', '\u{103a}'),
+	('\u{103d}', '\u{103e}'),
+	('\u{1058}', '\u{1059}'),
+	('\u{105e}', '\u{1060}'),
+	('\u{1071}', '\u{1074}'),
+	('\u{1082}', '\u{1082}'),
+	('\u{1085}', '\u{1086}'),
+	('\u{108d}', '\u{108d}'),
+	('\u{109d}', '\u{109d}'),
+	('\u{135d}', '\u{135f}'),
+	('\u{1712}', '\u{1714}'),
+	('\u{1732}', '\u{1733}'),
+	('\u{1752}', '\u{1753}'),
+	('\u{1772}', '\u{1773}'),
+	('\u{17b4}', '\u{17b5}'),
+	('\u{17b7}', '\u{17bd}'),
+	('\u{17c6}', '\u{17c6}'),
+	('\u{17c9}', '\u{17d3}'),
+	('\u{17dd}', '\u{17dd}'),
+	('\u{180b}', '\u{180d}'),
+	('\u{180f}', '\u{180f}'),
+	('\u{1885}', '\u{1886}'),
+	('\u{18a9}', '\u{18a9}'),
+	('\u{1920}', '\u{1922}'),
+	('\u{1927}', '\u{1928}'),
+	('\u{1932}', '\u{1932}'),
+	('\u{1939}', '\u{193b}'),
+	('\u{1a17}', '\u{1a18}'),
+	('\u{1a1b}', '\u{1a1b}'),
+	('\u{1a56}', '\u{1a56}'),
+	('\u{1a58}', '\u{1a5e}'),
+	('\u{1a60}', '\u{1a60}'),
+	('\u{1a62}', '\u{1a62}'),
+	('\u{1a65}', '\u{1a6c}'),
+	('\u{1a73}', '\u{1a7c}'),
+	('\u{1a7f}', '\u{1a7f}'),
+	('\u{1ab0}', '\u{1ace}'),
+	('\u{1b00}', '\u{1b03}'),
+	('\u{1b34}', '\u{1b34}'),
+	('\u{1b36}', '\u{1b3a}'),
+	('\u{1b3c}', '\u{1b3c}'),
+	('\u{1b42}', '\u{1b42}'),
+	('\u{1b6b}', '\u{1b73}'),
+	('\u{1b80}', '\u{1b81}'),
+	('\u{1ba2}', '\u{1ba5}'),
+	('\u{1ba8}', '\u{1ba9}'),
+	('\u{1bab}', '\u{1bad}'),
+	('\u{1be6}', '\u{1be6}'),
+	('\u{1be8}', '\u{1be9}'),
+	('\u{1bed}', '\u{1bed}'),
+	('\u{1bef}', '\u{1bf1}'),
+	('\u{1c2c}', '\u{1c33}'),
+	('\u{1c36}', '\u{1c37}'),
+	('\u{1cd0}', '\u{1cd2}'),
+	('\u{1cd4}', '\u{1ce0}'),
+	('\u{1ce2}', '\u{1ce8}'),
+	('\u{1ced}', '\u{1ced}'),
+	('\u{1cf4}', '\u{1cf4}'),
+	('\u{1cf8}', '\u{1cf9}'),
+	('\u{1dc0}', '\u{1dff}'),
+	('\u{20d0}', '\u{20f0}'),
+	('\u{2cef}', '\u{2cf1}'),
+	('\u{2d7f}', '\u{2d7f}'),
+	('\u{2de0}', '\u{2dff}'),
+	('\u{302a}', '\u{302d}'),
+	('\u{3099}', '\u{309a}'),
+	('\u{a66f}', '\u{a672}'),
+	('\u{a674}', '\u{a67d}'),
+	('\u{a69e}', '\u{a69f}'),
+	('\u{a6f0}', '\u{a6f1}'),
+	('\u{a802}', '\u{a802}'),
+	('\u{a806}', '\u{a806}'),
+	('\u{a80b}', '\u{a80b}'),
+	('\u{a825}', '\u{a826}'),
+	('\u{a82c}', '\u{a82c}'),
+	('\u{a8c4}', '\u{a8c5}'),
+	('\u{a8e0}', '\u{a8f1}'),
+	('\u{a8ff}', '\u{a8ff}'),
+	('\u{a926}', '\u{a92d}'),
+	('\u{a947}', '\u{a951}'),
+	('\u{a980}', '\u{a982}'),
+	('\u{a9b3}', '\u{a9b3}'),
+	('\u{a9b6}', '\u{a9b9}'),
+	('\u{a9bc}', '\u{a9bd}'),
+	('\u{a9e5}', '\u{a9e5}'),
+	('\u{aa29}', '\u{aa2e}'),
+	('\u{aa31}', '\u{aa32}'),
+	('\u{aa35}', '\u{aa36}'),
+	('\u{aa43}', '\u{aa43}'),
+	('\u{aa4c}', '\u{aa4c}'),
+	('\u{aa7c}', '\u{aa7c}'),
+	('\u{aab0}', '\u{aab0}'),
+	('\u{aab2}', '\u{aab4}'),
+	('\u{aab7}', '\u{aab8}'),
+	('\u{aabe}', '\u{aabf}'),
+	('\u{aac1}', '\u{aac1}'),
+	('\u{aaec}', '\u{aaed}'),
+	('\u{aaf6}', '\u{aaf6}'),
+	('\u{abe5}', '\u{abe5}'),
+	('\u{abe8}', '\u{abe8}'),
+	('\u{abed}', '\u{abed}'),
+	('\u{fb1e}', '\u{fb1e}'),
+	('\u{fe00}', '\u{fe0f}'),
+	('\u{fe20}', '\u{fe2f}'),
+	('\u{101fd}', '\u{101fd}'),
+	('\u{102e0}', '\u{102e0}'),
+	('\u{10376}', '\u{1037a}'),
+	('\u{10a01}', '\u{10a03}'),
+	('\u{10a05}', '\u{10a06}'),
+	('\u{10a0c}', '\u{10a0f}'),
+	('\u{10a38}', '\u{10a3a}'),
+	('\u{10a3f}', '\u{10a3f}'),
+	('\u{10ae5}', '\u{10ae6}'),
+	('\u{10d24}', '\u{10d27}'),
+	('\u{10eab}', '\u{10eac}'),
+	('\u{10f46}', '\u{10f50}'),
+	('\u{10f82}', '\u{10f85}'),
+	('\u{11001}', '\u{11001}'),
+	('\u{11038}', '\u{11046}'),
+	('\u{11070}', '\u{11070}'),
+	('\u{11073}', '\u{11074}'),
+	('\u{1107f}', '\u{11081}'),
+	('\u{110b3}', '\u{110b6}'),
+	('\u{110b9}', '\u{110ba}'),
+	('\u{110c2}', '\u{110c2}'),
+	('\u{11100}', '\u{11102}'),
+	('\u{11127}', '\u{1112b}'),
+	('\u{1112d}', '\u{11134}'),
+	('\u{11173}', '\u{11173}'),
+	('\u{11180}', '\u{11181}'),
+	('\u{111b6}', '\u{111be}'),
+	('\u{111c9}', '\u{111cc}'),
+	('\u{111cf}', '\u{111cf}'),
+	('\u{1122f}', '\u{11231}'),
+	('\u{11234}', '\u{11234}'),
+	('\u{11236}', '\u{11237}'),
+	('\u{1123e}', '\u{1123e}'),
+	('\u{112df}', '\u{112df}'),
+	('\u{112e3}', '\u{112ea}'),
+	('\u{11300}', '\u{11301}'),
+	('\u{1133b}', '\u{1133c}'),
+	('\u{11340}', '\u{11340}'),
+	('\u{11366}', '\u{1136c}'),
+	('\u{11370}', '\u{11374}'),
+	('\u{11438}', '\u{1143f}'),
+	('\u{11442}', '\u{11444}'),
+	('\u{11446}', '\u{11446}'),
+	('\u{1145e}', '\u{1145e}'),
+	('\u{114b3}', '\u{114b8}'),
+	('\u{114ba}', '\u{114ba}'),
+	('\u{114bf}', '\u{114c0}'),
+	('\u{114c2}', '\u{114c3}'),
+	('\u{115b2}', '\u{115b5}'),
+	('\u{115bc}', '\u{115bd}'),
+	('\u{115bf}', '\u{115c0}'),
+	('\u{115dc}', '\u{115dd}'),
+	('\u{11633}', '\u{1163a}'),
+	('\u{1163d}', '\u{1163d}'),
+	('\u{1163f}', '\u{11640}'),
+	('\u{116ab}', '\u{116ab}'),
+	('\u{116ad}', '\u{116ad}'),
+	('\u{116b0}', '\u{116b5}'),
+	('\u{116b7}', '\u{116b7}'),
+	('\u{1171d}', '\u{1171f}'),
+	('\u{11722}', '\u{11725}'),
+	('\u{11727}', '\u{1172b}'),
+	('\u{1182f}', '\u{11837}'),
+	('\u{11839}', '\u{1183a}'),
+	('\u{1193b}', '\u{1193c}'),
+	('\u{1193e}', '\u{1193e}'),
+	('\u{11943}', '\u{11943}'),
+	('\u{119d4}', '\u{119d7}'),
+	('\u{119da}', '\u{119db}'),
+	('\u{119e0}', '\u{119e0}'),
+	('\u{11a01}', '\u{11a0a}'),
+	('\u{11a33}', '\u{11a38}'),
+	('\u{11a3b}', '\u{11a3e}'),
+	('\u{11a47}', '\u{11a47}'),
+	('\u{11a51}', '\u{11a56}'),
+	('\u{11a59}', '\u{11a5b}'),
+	('\u{11a8a}', '\u{11a96}'),
+	('\u{11a98}', '\u{11a99}'),
+	('\u{11c30}', '\u{11c36}'),
+	('\u{11c38}', '\u{11c3d}'),
+	('\u{11c3f}', '\u{11c3f}'),
+	('\u{11c92}', '\u{11ca7}'),
+	('\u{11caa}', '\u{11cb0}'),
+	('\u{11cb2}', '\u{11cb3}'),
+	('\u{11cb5}', '\u{11cb6}'),
+	('\u{11d31}', '\u{11d36}'),
+	('\u{11d3a}', '\u{11d3a}'),
+	('\u{11d3c}', '\u{11d3d}'),
+	('\u{11d3f}', '\u{11d45}'),
+	('\u{11d47}', '\u{11d47}'),
+	('\u{11d90}', '\u{11d91}'),
+	('\u{11d95}', '\u{11d95}'),
+	('\u{11d97}', '\u{11d97}'),
+	('\u{11ef3}', '\u{11ef4}'),
+	('\u{16af0}', '\u{16af4}'),
+	('\u{16b30}', '\u{16b36}'),
+	('\u{16f4f}', '\u{16f4f}'),
+	('\u{16f8f}', '\u{16f92}'),
+	('\u{16fe4}', '\u{16fe4}'),
+	('\u{1bc9d}', '\u{1bc9e}'),
+	('\u{1cf00}', '\u{1cf2d}'),
+	('\u{1cf30}', '\u{1cf46}'),
+	('\u{1d167}', '\u{1d169}'),
+	('\u{1d17b}', '\u{1d182}'),
+	('\u{1d185}', '\u{1d18b}'),
+	('\u{1d1aa}', '\u{1d1ad}'),
+	('\u{1d242}', '\u{1d244}'),
+	('\u{1da00}', '\u{1da36}'),
+	('\u{1da3b}', '\u{1da6c}'),
+	('\u{1da75}', '\u{1da75}'),
+	('\u{1da84}', '\u{1da84}'),
+	('\u{1da9b}', '\u{1da9f}'),
+	('\u{1daa1}', '\u{1daaf}'),
+	('\u{1e000}', '\u{1e006}'),
+	('\u{1e008}', '\u{1e018}'),
+	('\u{1e01b}', '\u{1e021}'),
+	('\u{1e023}', '\u{1e024}'),
+	('\u{1e026}', '\u{1e02a}'),
+	('\u{1e130}', '\u{1e136}'),
+	('\u{1e2ae}', '\u{1e2ae}'),
+	('\u{1e2ec}', '\u{1e2ef}'),
+	('\u{1e8d0}', '\u{1e8d6}'),
+	('\u{1e944}', '\u{1e94a}'),
+	('\u{e0100}', '\u{e01ef}'),
+];
+
+/**
+Values of the `Grapheme_Cluster_Break` property, other than `Other`.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub enum GraphemeCat {
+	CR,
+	LF,
+	Control,
+	Extend,
+	ZWJ,
+	RegionalIndicator,
+	Prepend,
+	SpacingMark,
+	L,
+	V,
+	T,
+	LV,
+	LVT,
+}
+
+/**
+Ranges of code points and their `Grapheme_Cluster_Break` property.  Code points which do not appear have the value `Other`.  Sorted, non-overlapping and inclusive.
+*/
+pub static GRAPHEME_CLUSTER_BREAK: &'static [(char, char, GraphemeCat)] = &[
+	('\u{0}', '\u{9}', GraphemeCat::Control),
+	('\u{a}', '\u{a}', GraphemeCat::LF),
+	('\u{b}', '\u{c}', GraphemeCat::Control),
+	('\u{d}', '\u{d}', GraphemeCat::CR),
+	('\u{e}', '\u{1f}', GraphemeCat::Control),
+	('\u{7f}', '\u{9f}', GraphemeCat::Control),
+	('\u{ad}', '\u{ad}', GraphemeCat::Control),
+	('\u{300}', '\u{36f}', GraphemeCat::Extend),
+	('\u{483}', '\u{489}', GraphemeCat::Extend),
+	('\u{591}', '\u{5bd}', GraphemeCat::Extend),
+	('\u{5bf}', '\u{5bf}', GraphemeCat::Extend),
+	('\u{5c1}', '\u{5c2}', GraphemeCat::Extend),
+	('\u{5c4}', '\u{5c5}', GraphemeCat::Extend),
+	('\u{5c7}', '\u{5c7}', GraphemeCat::Extend),
+	('\u{600}', '\u{605}', GraphemeCat::Prepend),
+	('\u{610}', '\u{61a}', GraphemeCat::Extend),
+	('\u{61c}', '\u{61c}', GraphemeCat::Control),
+	('\u{64b}', '\u{65f}', GraphemeCat::Extend),
+	('\u{670}', '\u{670}', GraphemeCat::Extend),
+	('\u{6d6}', '\u{6dc}', GraphemeCat::Extend),
+	('\u{6dd}', '\u{6dd}', GraphemeCat::Prepend),
+	('\u{6df}', '\u{6e4}', GraphemeCat::Extend),
+	('\u{6e7}', '\u{6e8}', GraphemeCat::Extend),
+	('\u{6ea}', '\u{6ed}', GraphemeCat::Extend),
+	('\u{70f}', '\u{70f}', GraphemeCat::Prepend),
+	('\u{711}', '\u{711}', GraphemeCat::Extend),
+	('\u{730}', '\u{74a}', GraphemeCat::Extend),
+	('\u{7a6}', '\u{7b0}', GraphemeCat::Extend),
+	('\u{7eb}', '\u{7f3}', GraphemeCat::Extend),
+	('\u{7fd}', '\u{7fd}', GraphemeCat::Extend),
+	('\u{816}', '\u{819}', GraphemeCat::Extend),
+	('\u{81b}', '\u{823}', GraphemeCat::Extend),
+	('\u{825}', '\u{827}', GraphemeCat::Extend),
+	('\u{829}', '\u{82d}', GraphemeCat::Extend),
+	('\u{859}', '\u{85b}', GraphemeCat::Extend),
+	('\u{890}', '\u{891}', GraphemeCat::Prepend),
+	('\u{898}', '\u{89f}', GraphemeCat::Extend),
+	('\u{8ca}', '\u{8e1}', GraphemeCat::Extend),
+	('\u{8e2}', '\u{8e2}', GraphemeCat::Prepend),
+	('\u{8e3}', '\u{902}', GraphemeCat::Extend),
+	('\u{903}', '\u{903}', GraphemeCat::SpacingMark),
+	('\u{93a}', '\u{93a}', GraphemeCat::Extend),
+	('\u{93b}', '\u{93b}', GraphemeCat::SpacingMark),
+	('\u{93c}', '\u{93c}', GraphemeCat::Extend),
+	('\u{93e}', '\u{940}', GraphemeCat::SpacingMark),
+	('\u{941}', '\u{948}', GraphemeCat::Extend),
+	('\u{949}', '\u{94c}', GraphemeCat::SpacingMark),
+	('\u{94d}', '\u{94d}', GraphemeCat::Extend),
+	('\u{94e}', '\u{94f}', GraphemeCat::SpacingMark),
+	('\u{951}', '\u{957}', GraphemeCat::Extend),
+	('\u{962}', '\u{963}', GraphemeCat::Extend),
+	('\u{981}', '\u{981}', GraphemeCat::Extend),
+	('\u{982}', '\u{983}', GraphemeCat::SpacingMark),
+	('\u{9bc}', '\u{9bc}', GraphemeCat::Extend),
+	('\u{9be}', '\u{9be}', GraphemeCat::Extend),
+	('\u{9bf}', '\u{9c0}', GraphemeCat::SpacingMark),
+	('\u{9c1}', '\u{9c4}', GraphemeCat::Extend),
+	('\u{9c7}', '\u{9c8}', GraphemeCat::SpacingMark),
+	('\u{9cb}', '\u{9cc}', GraphemeCat::SpacingMark),
+	('\u{9cd}', '\u{9cd}', GraphemeCat::Extend),
+	('\u{9d7}', '\u{9d7}', GraphemeCat::Extend),
+	('\u{9e2}', '\u{9e3}', GraphemeCat::Extend),
+	('\u{9fe}', '\u{9fe}', GraphemeCat::Extend),
+	('\u{a01}', '\u{a02}', GraphemeCat::Extend),
+	('\u{a03}', '\u{a03}', GraphemeCat::SpacingMark),
+	('\u{a3c}', '\u{a3c}', GraphemeCat::Extend),
+	('\u{a3e}', '\u{a40}', GraphemeCat::SpacingMark),
+	('\u{a41}', '\u{a42}', GraphemeCat::Extend),
+	('\u{a47}', '\u{a48}', GraphemeCat::Extend),
+	('\u{a4b}', '\u{a4d}', GraphemeCat::Extend),
+	('\u{a51}', '\u{a51}', GraphemeCat::Extend),
+	('\u{a70}', '\u{a71}', GraphemeCat::Extend),
+	('\u{a75}', '\u{a75}', GraphemeCat::Extend),
+	('\u{a81}', '\u{a82}', GraphemeCat::Extend),
+	('\u{a83}', '\u{a83}', GraphemeCat::SpacingMark),
+	('\u{abc}', '\u{abc}', GraphemeCat::Extend),
+	('\u{abe}', '\u{ac0}', GraphemeCat::SpacingMark),
+	('\u{ac1}', '\u{ac5}', GraphemeCat::Extend),
+	('\u{ac7}', '\u{ac8}', GraphemeCat::Extend),
+	('\u{ac9}', '\u{ac9}', GraphemeCat::SpacingMark),
+	('\u{acb}', '\u{acc}', GraphemeCat::SpacingMark),
+	('\u{acd}', '\u{acd}', GraphemeCat::Extend),
+	('\u{ae2}', '\u{ae3}', GraphemeCat::Extend),
+	('\u{afa}', '\u{aff}', GraphemeCat::Extend),
+	('\u{b01}', '\u{b01}', GraphemeCat::Extend),
+	('\u{b02}', '\u{b03}', GraphemeCat::SpacingMark),
+	('\u{b3c}', '\u{b3c}', GraphemeCat::Extend),
+	('\u{b3e}', '\u{b3f}', GraphemeCat::Extend),
+	('\u{b40}', '\u{b40}', GraphemeCat::SpacingMark),
+	('\u{b41}', '\u{b44}', GraphemeCat::Extend),
+	('\u{b47}', '\u{b48}', GraphemeCat::SpacingMark),
+	('\u{b4b}', '\u{b4c}', GraphemeCat::SpacingMark),
+	('\u{b4d}', '\u{b4d}', GraphemeCat::Extend),
+	('\u{b55}', '\u{b57}', GraphemeCat::Extend),
+	('\u{b62}', '\u{b63}', GraphemeCat::Extend),
+	('\u{b82}', '\u{b82}', GraphemeCat::Extend),
+	('\u{bbe}', '\u{bbe}', GraphemeCat::Extend),
+	('\u{bbf}', '\u{bbf}', GraphemeCat::SpacingMark),
+	('\u{bc0}', '\u{bc0}', GraphemeCat::Extend),
+	('\u{bc1}', '\u{bc2}', GraphemeCat::SpacingMark),
+	('\u{bc6}', '\u{bc8}', GraphemeCat::SpacingMark),
+	('\u{bca}', '\u{bcc}', GraphemeCat::SpacingMark),
+	('\u{bcd}', '\u{bcd}', GraphemeCat::Extend),
+	('\u{bd7}', '\u{bd7}', GraphemeCat::Extend),
+	('\u{c00}', '\u{c00}', GraphemeCat::Extend),
+	('\u{c01}', '\u{c03}', GraphemeCat::SpacingMark),
+	('\u{c04}', '\u{c04}', GraphemeCat::Extend),
+	('\u{c3c}', '\u{c3c}', GraphemeCat::Extend),
+	('\u{c3e}', '\u{c40}', GraphemeCat::Extend),
+	('\u{c41}', '\u{c44}', GraphemeCat::SpacingMark),
+	('\u{c46}', '\u{c48}', GraphemeCat::Extend),
+	('\u{c4a}', '\u{c4d}', GraphemeCat::Extend),
+	('\u{c55}', '\u{c56}', GraphemeCat::Extend),
+	('\u{c62}', '\u{c63}', GraphemeCat::Extend),
+	('\u{c81}', '\u{c81}', GraphemeCat::Extend),
+	('\u{c82}', '\u{c83}', GraphemeCat::SpacingMark),
+	('\u{cbc}', '\u{cbc}', GraphemeCat::Extend),
+	('\u{cbe}', '\u{cbe}', GraphemeCat::SpacingMark),
+	('\u{cbf}', '\u{cbf}', GraphemeCat::Extend),
+	('\u{cc0}', '\u{cc1}', GraphemeCat::SpacingMark),
+	('\u{cc2}', '\u{cc2}', GraphemeCat::Extend),
+	('\u{cc3}', '\u{cc4}', GraphemeCat::SpacingMark),
+	('\u{cc6}', '\u{cc6}', GraphemeCat::Extend),
+	('\u{cc7}', '\u{cc8}', GraphemeCat::SpacingMark),
+	('\u{cca}', '\u{ccb}', GraphemeCat::SpacingMark),
+	('\u{ccc}', '\u{ccd}', GraphemeCat::Extend),
+	('\u{cd5}', '\u{cd6}', GraphemeCat::Extend),
+	('\u{ce2}', '\u{ce3}', GraphemeCat::Extend),
+	('\u{d00}', '\u{d01}', GraphemeCat::Extend),
+	('\u{d02}', '\u{d03}', GraphemeCat::SpacingMark),
+	('\u{d3b}', '\u{d3c}', GraphemeCat::Extend),
+	('\u{d3e}', '\u{d3e}', GraphemeCat::Extend),
+	('\u{d3f}', '\u{d40}', GraphemeCat::SpacingMark),
+	('\u{d41}', '\u{d44}', GraphemeCat::Extend),
+	('\u{d46}', '\u{d48}', GraphemeCat::SpacingMark),
+	('\u{d4a}', '\u{d4c}', GraphemeCat::SpacingMark),
+	('\u{d4d}', '\u{d4d}', GraphemeCat::Extend),
+	('\u{d4e}', '\u{d4e}', GraphemeCat::Prepend),
+	('\u{d57}', '\u{d57}', GraphemeCat::Extend),
+	('\u{d62}', '\u{d63}', GraphemeCat::Extend),
+	('\u{d81}', '\u{d81}', GraphemeCat::Extend),
+	('\u{d82}', '\u{d83}', GraphemeCat::SpacingMark),
+	('\u{dca}', '\u{dca}', GraphemeCat::Extend),
+	('\u{dcf}', '\u{dcf}', GraphemeCat::Extend),
+	('\u{dd0}', '\u{dd1}', GraphemeCat::SpacingMark),
+	('\u{dd2}', '\u{dd4}', GraphemeCat::Extend),
+	('\u{dd6}', '\u{dd6}', GraphemeCat::Extend),
+	('\u{dd8}', '\u{dde}', GraphemeCat::SpacingMark),
+	('\u{ddf}', '\u{ddf}', GraphemeCat::Extend),
+	('\u{df2}', '\u{df3}', GraphemeCat::SpacingMark),
+	('\u{e31}', '\u{e31}', GraphemeCat::Extend),
+	('\u{e33}', '\u{e33}', GraphemeCat::SpacingMark),
+	('\u{e34}', '\u{e3a}', GraphemeCat::Extend),
+	('\u{e47}', '\u{e4e}', GraphemeCat::Extend),
+	('\u{eb1}', '\u{eb1}', GraphemeCat::Extend),
+	('\u{eb3}', '\u{eb3}', GraphemeCat::SpacingMark),
+	('\u{eb4}', '\u{ebc}', GraphemeCat::Extend),
+	('\u{ec8}', '\u{ecd}', GraphemeCat::Extend),
+	('\u{f18}', '\u{f19}', GraphemeCat::Extend),
+	('\u{f35}', '\u{f35}', GraphemeCat::Extend),
+	('\u{f37}', '\u{f37}', GraphemeCat::Extend),
+	('\u{f39}', '\u{f39}', GraphemeCat::Extend),
+	('\u{f3e}', '\u{f3f}', GraphemeCat::SpacingMark),
+	('\u{f71}', '\u{f7e}', GraphemeCat::Extend),
+	('\u{f7f}', '\u{f7f}', GraphemeCat::SpacingMark),
+	('\u{f80}', '\u{f84}', GraphemeCat::Extend),
+	('\u{f86}', '\u{f87}', GraphemeCat::Extend),
+	('\u{f8d}', '\u{f97}', GraphemeCat::Extend),
+	('\u{f99}', '\u{fbc}', GraphemeCat::Extend),
+	('\u{fc6}', '\u{fc6}', GraphemeCat::Extend),
+	('\u{102d}', '\u{1030}', GraphemeCat::Extend),
+	('\u{1031}', '\u{1031}', GraphemeCat::SpacingMark),
+	('\u{1032}', '\u{1037}', GraphemeCat::Extend),
+	('\u{1039}', '\u{103a}', GraphemeCat::Extend),
+	('\u{103b}', '\u{103c}', GraphemeCat::SpacingMark),
+	('\u{103d}', '\u{103e}', GraphemeCat::Extend),
+	('\u{1056}', '\u{1057}', GraphemeCat::SpacingMark),
+	('\u{1058}', '\u{1059}', GraphemeCat::Extend),
+	('\u{105e}', '\u{1060}', GraphemeCat::Extend),
+	('\u{1071}', '\u{1074}', GraphemeCat::Extend),
+	('\u{1082}', '\u{1082}', GraphemeCat::Extend),
+	('\u{1084}', '\u{1084}', GraphemeCat::SpacingMark),
+	('\u{1085}', '\u{1086}', GraphemeCat::Extend),
+	('\u{108d}', '\u{108d}', GraphemeCat::Extend),
+	('\u{109d}', '\u{109d}', GraphemeCat::Extend),
+	('\u{1100}', '\u{115f}', GraphemeCat::L),
+	('\u{1160}', '\u{11a7}', GraphemeCat::V),
+	('\u{11a8}', '\u{11ff}', GraphemeCat::T),
+	('\u{135d}', '\u{135f}', GraphemeCat::Extend),
+	('\u{1712}', '\u{1714}', GraphemeCat::Extend),
+	('\u{1715}', '\u{1715}', GraphemeCat::SpacingMark),
+	('\u{1732}', '\u{1733}', GraphemeCat::Extend),
+	('\u{1734}', '\u{1734}', GraphemeCat::SpacingMark),
+	('\u{1752}', '\u{1753}', GraphemeCat::Extend),
+	('\u{1772}', '\u{1773}', GraphemeCat::Extend),
+	('\u{17b4}', '\u{17b5}', GraphemeCat::Extend),
+	('\u{17b6}', '\u{17b6}', GraphemeCat::SpacingMark),
+	('\u{17b7}', '\u{17bd}', GraphemeCat::Extend),
+	('\u{17be}', '\u{17c5}', GraphemeCat::SpacingMark),
+	('\u{17c6}', '\u{17c6}', GraphemeCat::Extend),
+	('\u{17c7}', '\u{17c8}', GraphemeCat::SpacingMark),
+	('\u{17c9}', '\u{17d3}', GraphemeCat::Extend),
+	('\u{17dd}', '\u{17dd}', GraphemeCat::Extend),
+	('\u{180b}', '\u{180d}', GraphemeCat::Extend),
+	('\u{180e}', '\u{180e}', GraphemeCat::Control),
+	('\u{180f}', '\u{180f}', GraphemeCat::Extend),
+	('\u{1885}', '\u{1886}', GraphemeCat::Extend),
+	('\u{18a9}', '\u{18a9}', GraphemeCat::Extend),
+	('\u{1920}', '\u{1922}', GraphemeCat::Extend),
+	('\u{1923}', '\u{1926}', GraphemeCat::SpacingMark),
+	('\u{1927}', '\u{1928}', GraphemeCat::Extend),
+	('\u{1929}', '\u{192b}', GraphemeCat::SpacingMark),
+	('\u{1930}', '\u{1931}', GraphemeCat::SpacingMark),
+	('\u{1932}', '\u{1932}', GraphemeCat::Extend),
+	('\u{1933}', '\u{1938}', GraphemeCat::SpacingMark),
+	('\u{1939}', '\u{193b}', GraphemeCat::Extend),
+	('\u{1a17}', '\u{1a18}', GraphemeCat::Extend),
+	('\u{1a19}', '\u{1a1a}', GraphemeCat::SpacingMark),
+	('\u{1a1b}', '\u{1a1b}', GraphemeCat::Extend),
+	('\u{1a55}', '\u{1a55}', GraphemeCat::SpacingMark),
+	('\u{1a56}', '\u{1a56}', GraphemeCat::Extend),
+	('\u{1a57}', '\u{1a57}', GraphemeCat::SpacingMark),
+	('\u{1a58}', '\u{1a5e}', GraphemeCat::Extend),
+	('\u{1a60}', '\u{1a60}', GraphemeCat::Extend),
+	('\u{1a62}', '\u{1a62}', GraphemeCat::Extend),
+	('\u{1a65}', '\u{1a6c}', GraphemeCat::Extend),
+	('\u{1a6d}', '\u{1a72}', GraphemeCat::SpacingMark),
+	('\u{1a73}', '\u{1a7c}', GraphemeCat::Extend),
+	('\u{1a7f}', '\u{1a7f}', GraphemeCat::Extend),
+	('\u{1ab0}', '\u{1ace}', GraphemeCat::Extend),
+	('\u{1b00}', '\u{1b03}', GraphemeCat::Extend),
+	('\u{1b04}', '\u{1b04}', GraphemeCat::SpacingMark),
+	('\u{1b34}', '\u{1b3a}', GraphemeCat::Extend),
+	('\u{1b3b}', '\u{1b3b}', GraphemeCat::SpacingMark),
+	('\u{1b3c}', '\u{1b3c}', GraphemeCat::Extend),
+	('\u{1b3d}', '\u{1b41}', GraphemeCat::SpacingMark),
+	('\u{1b42}', '\u{1b42}', GraphemeCat::Extend),
+	('\u{1b43}', '\u{1b44}', GraphemeCat::SpacingMark),
+	('\u{1b6b}', '\u{1b73}', GraphemeCat::Extend),
+	('\u{1b80}', '\u{1b81}', GraphemeCat::Extend),
+	('\u{1b82}', '\u{1b82}', GraphemeCat::SpacingMark),
+	('\u{1ba1}', '\u{1ba1}', GraphemeCat::SpacingMark),
+	('\u{1ba2}', '\u{1ba5}', GraphemeCat::Extend),
+	('\u{1ba6}', '\u{1ba7}', GraphemeCat::SpacingMark),
+	('\u{1ba8}', '\u{1ba9}', GraphemeCat::Extend),
+	('\u{1baa}', '\u{1baa}', GraphemeCat::SpacingMark),
+	('\u{1bab}', '\u{1bad}', GraphemeCat::Extend),
+	('\u{1be6}', '\u{1be6}', GraphemeCat::Extend),
+	('\u{1be7}', '\u{1be7}', GraphemeCat::SpacingMark),
+	('\u{1be8}', '\u{1be9}', GraphemeCat::Extend),
+	('\u{1bea}', '\u{1bec}', GraphemeCat::SpacingMark),
+	('\u{1bed}', '\u{1bed}', GraphemeCat::Extend),
+	('\u{1bee}', '\u{1bee}', GraphemeCat::SpacingMark),
+	('\u{1bef}', '\u{1bf1}', GraphemeCat::Extend),
+	('\u{1bf2}', '\u{1bf3}', GraphemeCat::SpacingMark),
+	('\u{1c24}', '\u{1c2b}', GraphemeCat::SpacingMark),
+	('\u{1c2c}', '\u{1c33}', GraphemeCat::Extend),
+	('\u{1c34}', '\u{1c35}', GraphemeCat::SpacingMark),
+	('\u{1c36}', '\u{1c37}', GraphemeCat::Extend),
+	('\u{1cd0}', '\u{1cd2}', GraphemeCat::Extend),
+	('\u{1cd4}', '\u{1ce0}', GraphemeCat::Extend),
+	('\u{1ce1}', '\u{1ce1}', GraphemeCat::SpacingMark),
+	('\u{1ce2}', '\u{1ce8}', GraphemeCat::Extend),
+	('\u{1ced}', '\u{1ced}', GraphemeCat::Extend),
+	('\u{1cf4}', '\u{1cf4}', GraphemeCat::Extend),
+	('\u{1cf7}', '\u{1cf7}', GraphemeCat::SpacingMark),
+	('\u{1cf8}', '\u{1cf9}', GraphemeCat::Extend),
+	('\u{1dc0}', '\u{1dff}', GraphemeCat::Extend),
+	('\u{200b}', '\u{200b}', GraphemeCat::Control),
+	('\u{200c}', '\u{200c}', GraphemeCat::Extend),
+	('\u{200d}', '\u{200d}', GraphemeCat::ZWJ),
+	('\u{200e}', '\u{200f}', GraphemeCat::Control),
+	('\u{2028}', '\u{202e}', GraphemeCat::Control),
+	('\u{2060}', '\u{206f}', GraphemeCat::Control),
+	('\u{20d0}', '\u{20f0}', GraphemeCat::Extend),
+	('\u{2cef}', '\u{2cf1}', GraphemeCat::Extend),
+	('\u{2d7f}', '\u{2d7f}', GraphemeCat::Extend),
+	('\u{2de0}', '\u{2dff}', GraphemeCat::Extend),
+	('\u{302a}', '\u{302f}', GraphemeCat::Extend),
+	('\u{3099}', '\u{309a}', GraphemeCat::Extend),
+	('\u{a66f}', '\u{a672}', GraphemeCat::Extend),
+	('\u{a674}', '\u{a67d}', GraphemeCat::Extend),
+	('\u{a69e}', '\u{a69f}', GraphemeCat::Extend),
+	('\u{a6f0}', '\u{a6f1}', GraphemeCat::Extend),
+	('\u{a802}', '\u{a802}', GraphemeCat::Extend),
+	('\u{a806}', '\u{a806}', GraphemeCat::Extend),
+	('\u{a80b}', '\u{a80b}', GraphemeCat::Extend),
+	('\u{a823}', '\u{a824}', GraphemeCat::SpacingMark),
+	('\u{a825}', '\u{a826}', GraphemeCat::Extend),
+	('\u{a827}', '\u{a827}', GraphemeCat::SpacingMark),
+	('\u{a82c}', '\u{a82c}', GraphemeCat::Extend),
+	('\u{a880}', '\u{a881}', GraphemeCat::SpacingMark),
+	('\u{a8b4}', '\u{a8c3}', GraphemeCat::SpacingMark),
+	('\u{a8c4}', '\u{a8c5}', GraphemeCat::Extend),
+	('\u{a8e0}', '\u{a8f1}', GraphemeCat::Extend),
+	('\u{a8ff}', '\u{a8ff}', GraphemeCat::Extend),
+	('\u{a926}', '\u{a92d}', GraphemeCat::Extend),
+	('\u{a947}', '\u{a951}', GraphemeCat::Extend),
+	('\u{a952}', '\u{a953}', GraphemeCat::SpacingMark),
+	('\u{a960}', '\u{a97c}', GraphemeCat::L),
+	('\u{a980}', '\u{a982}', GraphemeCat::Extend),
+	('\u{a983}', '\u{a983}', GraphemeCat::SpacingMark),
+	('\u{a9b3}', '\u{a9b3}', GraphemeCat::Extend),
+	('\u{a9b4}', '\u{a9b5}', GraphemeCat::SpacingMark),
+	('\u{a9b6}', '\u{a9b9}', GraphemeCat::Extend),
+	('\u{a9ba}', '\u{a9bb}', GraphemeCat::SpacingMark),
+	('\u{a9bc}', '\u{a9bd}', GraphemeCat::Extend),
+	('\u{a9be}', '\u{a9c0}', GraphemeCat::SpacingMark),
+	('\u{a9e5}', '\u{a9e5}', GraphemeCat::Extend),
+	('\u{aa29}', '\u{aa2e}', GraphemeCat::Extend),
+	('\u{aa2f}', '\u{aa30}', GraphemeCat::SpacingMark),
+	('\u{aa31}', '\u{aa32}', GraphemeCat::Extend),
+	('\u{aa33}', '\u{aa34}', GraphemeCat::SpacingMark),
+	('\u{aa35}', '\u{aa36}', GraphemeCat::Extend),
+	('\u{aa43}', '\u{aa43}', GraphemeCat::Extend),
+	('\u{aa4c}', '\u{aa4c}', GraphemeCat::Extend),
+	('\u{aa4d}', '\u{aa4d}', GraphemeCat::SpacingMark),
+	('\u{aa7c}', '\u{aa7c}', GraphemeCat::Extend),
+	('\u{aab0}', '\u{aab0}', GraphemeCat::Extend),
+	('\u{aab2}', '\u{aab4}', GraphemeCat::Extend),
+	('\u{aab7}', '\u{aab8}', GraphemeCat::Extend),
+	('\u{aabe}', '\u{aabf}', GraphemeCat::Extend),
+	('\u{aac1}', '\u{aac1}', GraphemeCat::Extend),
+	('\u{aaeb}', '\u{aaeb}', GraphemeCat::SpacingMark),
+	('\u{aaec}', '\u{aaed}', GraphemeCat::Extend),
+	('\u{aaee}', '\u{aaef}', GraphemeCat::SpacingMark),
+	('\u{aaf5}', '\u{aaf5}', GraphemeCat::SpacingMark),
+	('\u{aaf6}', '\u{aaf6}', GraphemeCat::Extend),
+	('\u{abe3}', '\u{abe4}', GraphemeCat::SpacingMark),
+	('\u{abe5}', '\u{abe5}', GraphemeCat::Extend),
+	('\u{abe6}', '\u{abe7}', GraphemeCat::SpacingMark),
+	('\u{abe8}', '\u{abe8}', GraphemeCat::Extend),
+	('\u{abe9}', '\u{abea}', GraphemeCat::SpacingMark),
+	('\u{abec}', '\u{abec}', GraphemeCat::SpacingMark),
+	('\u{abed}', '\u{abed}', GraphemeCat::Extend),
+	('\u{ac00}', '\u{ac00}', GraphemeCat::LV),
+	('\u{ac01}', '\u{ac1b}', GraphemeCat::LVT),
+	('\u{ac1c}', '\u{ac1c}', GraphemeCat::LV),
+	('\u{ac1d}', '\u{ac37}', GraphemeCat::LVT),
+	('\u{ac38}', '\u{ac38}', GraphemeCat::LV),
+	('\u{ac39}', '\u{ac53}', GraphemeCat::LVT),
+	('\u{ac54}', '\u{ac54}', GraphemeCat::LV),
+	('\u{ac55}', '\u{ac6f}', GraphemeCat::LVT),
+	('\u{ac70}', '\u{ac70}', GraphemeCat::LV),
+	('\u{ac71}', '\u{ac8b}', GraphemeCat::LVT),
+	('\u{ac8c}', '\u{ac8c}', GraphemeCat::LV),
+	('\u{ac8d}', '\u{aca7}', GraphemeCat::LVT),
+	('\u{aca8}', '\u{aca8}', GraphemeCat::LV),
+	('\u{aca9}', '\u{acc3}', GraphemeCat::LVT),
+	('\u{acc4}', '\u{acc4}', GraphemeCat::LV),
+	('\u{acc5}', '\u{acdf}', GraphemeCat::LVT),
+	('\u{ace0}', '\u{ace0}', GraphemeCat::LV),
+	('\u{ace1}', '\u{acfb}', GraphemeCat::LVT),
+	('\u{acfc}', '\u{acfc}', GraphemeCat::LV),
+	('\u{acfd}', '\u{ad17}', GraphemeCat::LVT),
+	('\u{ad18}', '\u{ad18}', GraphemeCat::LV),
+	('\u{ad19}', '\u{ad33}', GraphemeCat::LVT),
+	('\u{ad34}', '\u{ad34}', GraphemeCat::LV),
+	('\u{ad35}', '\u{ad4f}', GraphemeCat::LVT),
+	('\u{ad50}', '\u{ad50}', GraphemeCat::LV),
+	('\u{ad51}', '\u{ad6b}', GraphemeCat::LVT),
+	('\u{ad6c}', '\u{ad6c}', GraphemeCat::LV),
+	('\u{ad6d}', '\u{ad87}', GraphemeCat::LVT),
+	('\u{ad88}', '\u{ad88}', GraphemeCat::LV),
+	('\u{ad89}', '\u{ada3}', GraphemeCat::LVT),
+	('\u{ada4}', '\u{ada4}', GraphemeCat::LV),
+	('\u{ada5}', '\u{adbf}', GraphemeCat::LVT),
+	('\u{adc0}', '\u{adc0}', GraphemeCat::LV),
+	('\u{adc1}', '\u{addb}', GraphemeCat::LVT),
+	('\u{addc}', '\u{addc}', GraphemeCat::LV),
+	('\u{addd}', '\u{adf7}', GraphemeCat::LVT),
+	('\u{adf8}', '\u{adf8}', GraphemeCat::LV),
+	('\u{adf9}', '\u{ae13}', GraphemeCat::LVT),
+	('\u{ae14}', '\u{ae14}', GraphemeCat::LV),
+	('\u{ae15}', '\u{ae2f}', GraphemeCat::LVT),
+	('\u{ae30}', '\u{ae30}', GraphemeCat::LV),
+	('\u{ae31}', '\u{ae4b}', GraphemeCat::LVT),
+	('\u{ae4c}', '\u{ae4c}', GraphemeCat::LV),
+	('\u{ae4d}', '\u{ae67}', GraphemeCat::LVT),
+	('\u{ae68}', '\u{ae68}', GraphemeCat::LV),
+	('\u{ae69}', '\u{ae83}', GraphemeCat::LVT),
+	('\u{ae84}', '\u{ae84}', GraphemeCat::LV),
+	('\u{ae85}', '\u{ae9f}', GraphemeCat::LVT),
+	('\u{aea0}', '\u{aea0}', GraphemeCat::LV),
+	('\u{aea1}', '\u{aebb}', GraphemeCat::LVT),
+	('\u{aebc}', '\u{aebc}', GraphemeCat::LV),
+	('\u{aebd}', '\u{aed7}', GraphemeCat::LVT),
+	('\u{aed8}', '\u{aed8}', GraphemeCat::LV),
+	('\u{aed9}', '\u{aef3}', GraphemeCat::LVT),
+	('\u{aef4}', '\u{aef4}', GraphemeCat::LV),
+	('\u{aef5}', '\u{af0f}', GraphemeCat::LVT),
+	('\u{af10}', '\u{af10}', GraphemeCat::LV),
+	('\u{af11}', '\u{af2b}', GraphemeCat::LVT),
+	('\u{af2c}', '\u{af2c}', GraphemeCat::LV),
+	('\u{af2d}', '\u{af47}', GraphemeCat::LVT),
+	('\u{af48}', '\u{af48}', GraphemeCat::LV),
+	('\u{af49}', '\u{af63}', GraphemeCat::LVT),
+	('\u{af64}', '\u{af64}', GraphemeCat::LV),
+	('\u{af65}', '\u{af7f}', GraphemeCat::LVT),
+	('\u{af80}', '\u{af80}', GraphemeCat::LV),
+	('\u{af81}', '\u{af9b}', GraphemeCat::LVT),
+	('\u{af9c}', '\u{af9c}', GraphemeCat::LV),
+	('\u{af9d}', '\u{afb7}', GraphemeCat::LVT),
+	('\u{afb8}', '\u{afb8}', GraphemeCat::LV),
+	('\u{afb9}', '\u{afd3}', GraphemeCat::LVT),
+	('\u{afd4}', '\u{afd4}', GraphemeCat::LV),
+	('\u{afd5}', '\u{afef}', GraphemeCat::LVT),
+	('\u{aff0}', '\u{aff0}', GraphemeCat::LV),
+	('\u{aff1}', '\u{b00b}', GraphemeCat::LVT),
+	('\u{b00c}', '\u{b00c}', GraphemeCat::LV),
+	('\u{b00d}', '\u{b027}', GraphemeCat::LVT),
+	('\u{b028}', '\u{b028}', GraphemeCat::LV),
+	('\u{b029}', '\u{b043}', GraphemeCat::LVT),
+	('\u{b044}', '\u{b044}', GraphemeCat::LV),
+	('\u{b045}', '\u{b05f}', GraphemeCat::LVT),
+	('\u{b060}', '\u{b060}', GraphemeCat::LV),
+	('\u{b061}', '\u{b07b}', GraphemeCat::LVT),
+	('\u{b07c}', '\u{b07c}', GraphemeCat::LV),
+	('\u{b07d}', '\u{b097}', GraphemeCat::LVT),
+	('\u{b098}', '\u{b098}', GraphemeCat::LV),
+	('\u{b099}', '\u{b0b3}', GraphemeCat::LVT),
+	('\u{b0b4}', '\u{b0b4}', GraphemeCat::LV),
+	('\u{b0b5}', '\u{b0cf}', GraphemeCat::LVT),
+	('\u{b0d0}', '\u{b0d0}', GraphemeCat::LV),
+	('\u{b0d1}', '\u{b0eb}', GraphemeCat::LVT),
+	('\u{b0ec}', '\u{b0ec}', GraphemeCat::LV),
+	('\u{b0ed}', '\u{b107}', GraphemeCat::LVT),
+	('\u{b108}', '\u{b108}', GraphemeCat::LV),
+	('\u{b109}', '\u{b123}', GraphemeCat::LVT),
+	('\u{b124}', '\u{b124}', GraphemeCat::LV),
+	('\u{b125}', '\u{b13f}', GraphemeCat::LVT),
+	('\u{b140}', '\u{b140}', GraphemeCat::LV),
+	('\u{b141}', '\u{b15b}', GraphemeCat::LVT),
+	('\u{b15c}', '\u{b15c}', GraphemeCat::LV),
+	('\u{b15d}', '\u{b177}', GraphemeCat::LVT),
+	('\u{b178}', '\u{b178}', GraphemeCat::LV),
+	('\u{b179}', '\u{b193}', GraphemeCat::LVT),
+	('\u{b194}', '\u{b194}', GraphemeCat::LV),
+	('\u{b195}', '\u{b1af}', GraphemeCat::LVT),
+	('\u{b1b0}', '\u{b1b0}', GraphemeCat::LV),
+	('\u{b1b1}', '\u{b1cb}', GraphemeCat::LVT),
+	('\u{b1cc}', '\u{b1cc}', GraphemeCat::LV),
+	('\u{b1cd}', '\u{b1e7}', GraphemeCat::LVT),
+	('\u{b1e8}', '\u{b1e8}', GraphemeCat::LV),
+	('\u{b1e9}', '\u{b203}', GraphemeCat::LVT),
+	('\u{b204}', '\u{b204}', GraphemeCat::LV),
+	('\u{b205}', '\u{b21f}', GraphemeCat::LVT),
+	('\u{b220}', '\u{b220}', GraphemeCat::LV),
+	('\u{b221}', '\u{b23b}', GraphemeCat::LVT),
+	('\u{b23c}', '\u{b23c}', GraphemeCat::LV),
+	('\u{b23d}', '\u{b257}', GraphemeCat::LVT),
+	('\u{b258}', '\u{b258}', GraphemeCat::LV),
+	('\u{b259}', '\u{b273}', GraphemeCat::LVT),
+	('\u{b274}', '\u{b274}', GraphemeCat::LV),
+	('\u{b275}', '\u{b28f}', GraphemeCat::LVT),
+	('\u{b290}', '\u{b290}', GraphemeCat::LV),
+	('\u{b291}', '\u{b2ab}', GraphemeCat::LVT),
+	('\u{b2ac}', '\u{b2ac}', GraphemeCat::LV),
+	('\u{b2ad}', '\u{b2c7}', GraphemeCat::LVT),
+	('\u{b2c8}', '\u{b2c8}', GraphemeCat::LV),
+	('\u{b2c9}', '\u{b2e3}', GraphemeCat::LVT),
+	('\u{b2e4}', '\u{b2e4}', GraphemeCat::LV),
+	('\u{b2e5}', '\u{b2ff}', GraphemeCat::LVT),
+	('\u{b300}', '\u{b300}', GraphemeCat::LV),
+	('\u{b301}', '\u{b31b}', GraphemeCat::LVT),
+	('\u{b31c}', '\u{b31c}', GraphemeCat::LV),
+	('\u{b31d}', '\u{b337}', GraphemeCat::LVT),
+	('\u{b338}', '\u{b338}', GraphemeCat::LV),
+	('\u{b339}', '\u{b353}', GraphemeCat::LVT),
+	('\u{b354}', '\u{b354}', GraphemeCat::LV),
+	('\u{b355}', '\u{b36f}', GraphemeCat::LVT),
+	('\u{b370}', '\u{b370}', GraphemeCat::LV),
+	('\u{b371}', '\u{b38b}', GraphemeCat::LVT),
+	('\u{b38c}', '\u{b38c}', GraphemeCat::LV),
+	('\u{b38d}', '\u{b3a7}', GraphemeCat::LVT),
+	('\u{b3a8}', '\u{b3a8}', GraphemeCat::LV),
+	('\u{b3a9}', '\u{b3c3}', GraphemeCat::LVT),
+	('\u{b3c4}', '\u{b3c4}', GraphemeCat::LV),
+	('\u{b3c5}', '\u{b3df}', GraphemeCat::LVT),
+	('\u{b3e0}', '\u{b3e0}', GraphemeCat::LV),
+	('\u{b3e1}', '\u{b3fb}', GraphemeCat::LVT),
+	('\u{b3fc}', '\u{b3fc}', GraphemeCat::LV),
+	('\u{b3fd}', '\u{b417}', GraphemeCat::LVT),
+	('\u{b418}', '\u{b418}', GraphemeCat::LV),
+	('\u{b419}', '\u{b433}', GraphemeCat::LVT),
+	('\u{b434}', '\u{b434}', GraphemeCat::LV),
+	('\u{b435}', '\u{b44f}', GraphemeCat::LVT),
+	('\u{b450}', '\u{b450}', GraphemeCat::LV),
+	('\u{b451}', '\u{b46b}', GraphemeCat::LVT),
+	('\u{b46c}', '\u{b46c}', GraphemeCat::LV),
+	('\u{b46d}', '\u{b487}', GraphemeCat::LVT),
+	('\u{b488}', '\u{b488}', GraphemeCat::LV),
+	('\u{b489}', '\u{b4a3}', GraphemeCat::LVT),
+	('\u{b4a4}', '\u{b4a4}', GraphemeCat::LV),
+	('\u{b4a5}', '\u{b4bf}', GraphemeCat::LVT),
+	('\u{b4c0}', '\u{b4c0}', GraphemeCat::LV),
+	('\u{b4c1}', '\u{b4db}', GraphemeCat::LVT),
+	('\u{b4dc}', '\u{b4dc}', GraphemeCat::LV),
+	('\u{b4dd}', '\u{b4f7}', GraphemeCat::LVT),
+	('\u{b4f8}', '\u{b4f8}', GraphemeCat::LV),
+	('\u{b4f9}', '\u{b513}', GraphemeCat::LVT),
+	('\u{b514}', '\u{b514}', GraphemeCat::LV),
+	('\u{b515}', '\u{b52f}', GraphemeCat::LVT),
+	('\u{b530}', '\u{b530}', GraphemeCat::LV),
+	('\u{b531}', '\u{b54b}', GraphemeCat::LVT),
+	('\u{b54c}', '\u{b54c}', GraphemeCat::LV),
+	('\u{b54d}', '\u{b567}', GraphemeCat::LVT),
+	('\u{b568}', '\u{b568}', GraphemeCat::LV),
+	('\u{b569}', '\u{b583}', GraphemeCat::LVT),
+	('\u{b584}', '\u{b584}', GraphemeCat::LV),
+	('\u{b585}', '\u{b59f}', GraphemeCat::LVT),
+	('\u{b5a0}', '\u{b5a0}', GraphemeCat::LV),
+	('\u{b5a1}', '\u{b5bb}', GraphemeCat::LVT),
+	('\u{b5bc}', '\u{b5bc}', GraphemeCat::LV),
+	('\u{b5bd}', '\u{b5d7}', GraphemeCat::LVT),
+	('\u{b5d8}', '\u{b5d8}', GraphemeCat::LV),
+	('\u{b5d9}', '\u{b5f3}', GraphemeCat::LVT),
+	('\u{b5f4}', '\u{b5f4}', GraphemeCat::LV),
+	('\u{b5f5}', '\u{b60f}', GraphemeCat::LVT),
+	('\u{b610}', '\u{b610}', GraphemeCat::LV),
+	('\u{b611}', '\u{b62b}', GraphemeCat::LVT),
+	('\u{b62c}', '\u{b62c}', GraphemeCat::LV),
+	('\u{b62d}', '\u{b647}', GraphemeCat::LVT),
+	('\u{b648}', '\u{b648}', GraphemeCat::LV),
+	('\u{b649}', '\u{b663}', GraphemeCat::LVT),
+	('\u{b664}', '\u{b664}', GraphemeCat::LV),
+	('\u{b665}', '\u{b67f}', GraphemeCat::LVT),
+	('\u{b680}', '\u{b680}', GraphemeCat::LV),
+	('\u{b681}', '\u{b69b}', GraphemeCat::LVT),
+	('\u{b69c}', '\u{b69c}', GraphemeCat::LV),
+	('\u{b69d}', '\u{b6b7}', GraphemeCat::LVT),
+	('\u{b6b8}', '\u{b6b8}', GraphemeCat::LV),
+	('\u{b6b9}', '\u{b6d3}', GraphemeCat::LVT),
+	('\u{b6d4}', '\u{b6d4}', GraphemeCat::LV),
+	('\u{b6d5}', '\u{b6ef}', GraphemeCat::LVT),
+	('\u{b6f0}', '\u{b6f0}', GraphemeCat::LV),
+	('\u{b6f1}', '\u{b70b}', GraphemeCat::LVT),
+	('\u{b70c}', '\u{b70c}', GraphemeCat::LV),
+	('\u{b70d}', '\u{b727}', GraphemeCat::LVT),
+	('\u{b728}', '\u{b728}', GraphemeCat::LV),
+	('\u{b729}', '\u{b743}', GraphemeCat::LVT),
+	('\u{b744}', '\u{b744}', GraphemeCat::LV),
+	('\u{b745}', '\u{b75f}', GraphemeCat::LVT),
+	('\u{b760}', '\u{b760}', GraphemeCat::LV),
+	('\u{b761}', '\u{b77b}', GraphemeCat::LVT),
+	('\u{b77c}', '\u{b77c}', GraphemeCat::LV),
+	('\u{b77d}', '\u{b797}', GraphemeCat::LVT),
+	('\u{b798}', '\u{b798}', GraphemeCat::LV),
+	('\u{b799}', '\u{b7b3}', GraphemeCat::LVT),
+	('\u{b7b4}', '\u{b7b4}', GraphemeCat::LV),
+	('\u{b7b5}', '\u{b7cf}', GraphemeCat::LVT),
+	('\u{b7d0}', '\u{b7d0}', GraphemeCat::LV),
+	('\u{b7d1}', '\u{b7eb}', GraphemeCat::LVT),
+	('\u{b7ec}', '\u{b7ec}', GraphemeCat::LV),
+	('\u{b7ed}', '\u{b807}', GraphemeCat::LVT),
+	('\u{b808}', '\u{b808}', GraphemeCat::LV),
+	('\u{b809}', '\u{b823}', GraphemeCat::LVT),
+	('\u{b824}', '\u{b824}', GraphemeCat::LV),
+	('\u{b825}', '\u{b83f}', GraphemeCat::LVT),
+	('\u{b840}', '\u{b840}', GraphemeCat::LV),
+	('\u{b841}', '\u{b85b}', GraphemeCat::LVT),
+	('\u{b85c}', '\u{b85c}', GraphemeCat::LV),
+	('\u{b85d}', '\u{b877}', GraphemeCat::LVT),
+	('\u{b878}', '\u{b878}', GraphemeCat::LV),
+	('\u{b879}', '\u{b893}', GraphemeCat::LVT),
+	('\u{b894}', '\u{b894}', GraphemeCat::LV),
+	('\u{b895}', '\u{b8af}', GraphemeCat::LVT),
+	('\u{b8b0}', '\u{b8b0}', GraphemeCat::LV),
+	('\u{b8b1}', '\u{b8cb}', GraphemeCat::LVT),
+	('\u{b8cc}', '\u{b8cc}', GraphemeCat::LV),
+	('\u{b8cd}', '\u{b8e7}', GraphemeCat::LVT),
+	('\u{b8e8}', '\u{b8e8}', GraphemeCat::LV),
+	('\u{b8e9}', '\u{b903}', GraphemeCat::LVT),
+	('\u{b904}', '\u{b904}', GraphemeCat::LV),
+	('\u{b905}', '\u{b91f}', GraphemeCat::LVT),
+	('\u{b920}', '\u{b920}', GraphemeCat::LV),
+	('\u{b921}', '\u{b93b}', GraphemeCat::LVT),
+	('\u{b93c}', '\u{b93c}', GraphemeCat::LV),
+	('\u{b93d}', '\u{b957}', GraphemeCat::LVT),
+	('\u{b958}', '\u{b958}', GraphemeCat::LV),
+	('\u{b959}', '\u{b973}', GraphemeCat::LVT),
+	('\u{b974}', '\u{b974}', GraphemeCat::LV),
+	('\u{b975}', '\u{b98f}', GraphemeCat::LVT),
+	('\u{b990}', '\u{b990}', GraphemeCat::LV),
+	('\u{b991}', '\u{b9ab}', GraphemeCat::LVT),
+	('\u{b9ac}', '\u{b9ac}', GraphemeCat::LV),
+	('\u{b9ad}', '\u{b9c7}', GraphemeCat::LVT),
+	('\u{b9c8}', '\u{b9c8}', GraphemeCat::LV),
+	('\u{b9c9}', '\u{b9e3}', GraphemeCat::LVT),
+	('\u{b9e4}', '\u{b9e4}', GraphemeCat::LV),
+	('\u{b9e5}', '\u{b9ff}', GraphemeCat::LVT),
+	('\u{ba00}', '\u{ba00}', GraphemeCat::LV),
+	('\u{ba01}', '\u{ba1b}', GraphemeCat::LVT),
+	('\u{ba1c}', '\u{ba1c}', GraphemeCat::LV),
+	('\u{ba1d}', '\u{ba37}', GraphemeCat::LVT),
+	('\u{ba38}', '\u{ba38}', GraphemeCat::LV),
+	('\u{ba39}', '\u{ba53}', GraphemeCat::LVT),
+	('\u{ba54}', '\u{ba54}', GraphemeCat::LV),
+	('\u{ba55}', '\u{ba6f}', GraphemeCat::LVT),
+	('\u{ba70}', '\u{ba70}', GraphemeCat::LV),
+	('\u{ba71}', '\u{ba8b}', GraphemeCat::LVT),
+	('\u{ba8c}', '\u{ba8c}', GraphemeCat::LV),
+	('\u{ba8d}', '\u{baa7}', GraphemeCat::LVT),
+	('\u{baa8}', '\u{baa8}', GraphemeCat::LV),
+	('\u{baa9}', '\u{bac3}', GraphemeCat::LVT),
+	('\u{bac4}', '\u{bac4}', GraphemeCat::LV),
+	('\u{bac5}', '\u{badf}', GraphemeCat::LVT),
+	('\u{bae0}', '\u{bae0}', GraphemeCat::LV),
+	('\u{bae1}', '\u{bafb}', GraphemeCat::LVT),
+	('\u{bafc}', '\u{bafc}', GraphemeCat::LV),
+	('\u{bafd}', '\u{bb17}', GraphemeCat::LVT),
+	('\u{bb18}', '\u{bb18}', GraphemeCat::LV),
+	('\u{bb19}', '\u{bb33}', GraphemeCat::LVT),
+	('\u{bb34}', '\u{bb34}', GraphemeCat::LV),
+	('\u{bb35}', '\u{bb4f}', GraphemeCat::LVT),
+	('\u{bb50}', '\u{bb50}', GraphemeCat::LV),
+	('\u{bb51}', '\u{bb6b}', GraphemeCat::LVT),
+	('\u{bb6c}', '\u{bb6c}', GraphemeCat::LV),
+	('\u{bb6d}', '\u{bb87}', GraphemeCat::LVT),
+	('\u{bb88}', '\u{bb88}', GraphemeCat::LV),
+	('\u{bb89}', '\u{bba3}', GraphemeCat::LVT),
+	('\u{bba4}', '\u{bba4}', GraphemeCat::LV),
+	('\u{bba5}', '\u{bbbf}', GraphemeCat::LVT),
+	('\u{bbc0}', '\u{bbc0}', GraphemeCat::LV),
+	('\u{bbc1}', '\u{bbdb}', GraphemeCat::LVT),
+	('\u{bbdc}', '\u{bbdc}', GraphemeCat::LV),
+	('\u{bbdd}', '\u{bbf7}', GraphemeCat::LVT),
+	('\u{bbf8}', '\u{bbf8}', GraphemeCat::LV),
+	('\u{bbf9}', '\u{bc13}', GraphemeCat::LVT),
+	('\u{bc14}', '\u{bc14}', GraphemeCat::LV),
+	('\u{bc15}', '\u{bc2f}', GraphemeCat::LVT),
+	('\u{bc30}', '\u{bc30}', GraphemeCat::LV),
+	('\u{bc31}', '\u{bc4b}', GraphemeCat::LVT),
+	('\u{bc4c}', '\u{bc4c}', GraphemeCat::LV),
+	('\u{bc4d}', '\u{bc67}', GraphemeCat::LVT),
+	('\u{bc68}', '\u{bc68}', GraphemeCat::LV),
+	('\u{bc69}', '\u{bc83}', GraphemeCat::LVT),
+	('\u{bc84}', '\u{bc84}', GraphemeCat::LV),
+	('\u{bc85}', '\u{bc9f}', GraphemeCat::LVT),
+	('\u{bca0}', '\u{bca0}', GraphemeCat::LV),
+	('\u{bca1}', '\u{bcbb}', GraphemeCat::LVT),
+	('\u{bcbc}', '\u{bcbc}', GraphemeCat::LV),
+	('\u{bcbd}', '\u{bcd7}', GraphemeCat::LVT),
+	('\u{bcd8}', '\u{bcd8}', GraphemeCat::LV),
+	('\u{bcd9}', '\u{bcf3}', GraphemeCat::LVT),
+	('\u{bcf4}', '\u{bcf4}', GraphemeCat::LV),
+	('\u{bcf5}', '\u{bd0f}', GraphemeCat::LVT),
+	('\u{bd10}', '\u{bd10}', GraphemeCat::LV),
+	('\u{bd11}', '\u{bd2b}', GraphemeCat::LVT),
+	('\u{bd2c}', '\u{bd2c}', GraphemeCat::LV),
+	('\u{bd2d}', '\u{bd47}', GraphemeCat::LVT),
+	('\u{bd48}', '\u{bd48}', GraphemeCat::LV),
+	('\u{bd49}', '\u{bd63}', GraphemeCat::LVT),
+	('\u{bd64}', '\u{bd64}', GraphemeCat::LV),
+	('\u{bd65}', '\u{bd7f}', GraphemeCat::LVT),
+	('\u{bd80}', '\u{bd80}', GraphemeCat::LV),
+	('\u{bd81}', '\u{bd9b}', GraphemeCat::LVT),
+	('\u{bd9c}', '\u{bd9c}', GraphemeCat::LV),
+	('\u{bd9d}', '\u{bdb7}', GraphemeCat::LVT),
+	('\u{bdb8}', '\u{bdb8}', GraphemeCat::LV),
+	('\u{bdb9}', '\u{bdd3}', GraphemeCat::LVT),
+	('\u{bdd4}', '\u{bdd4}', GraphemeCat::LV),
+	('\u{bdd5}', '\u{bdef}', GraphemeCat::LVT),
+	('\u{bdf0}', '\u{bdf0}', GraphemeCat::LV),
+	('\u{bdf1}', '\u{be0b}', GraphemeCat::LVT),
+	('\u{be0c}', '\u{be0c}', GraphemeCat::LV),
+	('\u{be0d}', '\u{be27}', GraphemeCat::LVT),
+	('\u{be28}', '\u{be28}', GraphemeCat::LV),
+	('\u{be29}', '\u{be43}', GraphemeCat::LVT),
+	('\u{be44}', '\u{be44}', GraphemeCat::LV),
+	('\u{be45}', '\u{be5f}', GraphemeCat::LVT),
+	('\u{be60}', '\u{be60}', GraphemeCat::LV),
+	('\u{be61}', '\u{be7b}', GraphemeCat::LVT),
+	('\u{be7c}', '\u{be7c}', GraphemeCat::LV),
+	('\u{be7d}', '\u{be97}', GraphemeCat::LVT),
+	('\u{be98}', '\u{be98}', GraphemeCat::LV),
+	('\u{be99}', '\u{beb3}', GraphemeCat::LVT),
+	('\u{beb4}', '\u{beb4}', GraphemeCat::LV),
+	('\u{beb5}', '\u{becf}', GraphemeCat::LVT),
+	('\u{bed0}', '\u{bed0}', GraphemeCat::LV),
+	('\u{bed1}', '\u{beeb}', GraphemeCat::LVT),
+	('\u{beec}', '\u{beec}', GraphemeCat::LV),
+	('\u{beed}', '\u{bf07}', GraphemeCat::LVT),
+	('\u{bf08}', '\u{bf08}', GraphemeCat::LV),
+	('\u{bf09}', '\u{bf23}', GraphemeCat::LVT),
+	('\u{bf24}', '\u{bf24}', GraphemeCat::LV),
+	('\u{bf25}', '\u{bf3f}', GraphemeCat::LVT),
+	('\u{bf40}', '\u{bf40}', GraphemeCat::LV),
+	('\u{bf41}', '\u{bf5b}', GraphemeCat::LVT),
+	('\u{bf5c}', '\u{bf5c}', GraphemeCat::LV),
+	('\u{bf5d}', '\u{bf77}', GraphemeCat::LVT),
+	('\u{bf78}', '\u{bf78}', GraphemeCat::LV),
+	('\u{bf79}', '\u{bf93}', GraphemeCat::LVT),
+	('\u{bf94}', '\u{bf94}', GraphemeCat::LV),
+	('\u{bf95}', '\u{bfaf}', GraphemeCat::LVT),
+	('\u{bfb0}', '\u{bfb0}', GraphemeCat::LV),
+	('\u{bfb1}', '\u{bfcb}', GraphemeCat::LVT),
+	('\u{bfcc}', '\u{bfcc}', GraphemeCat::LV),
+	('\u{bfcd}', '\u{bfe7}', GraphemeCat::LVT),
+	('\u{bfe8}', '\u{bfe8}', GraphemeCat::LV),
+	('\u{bfe9}', '\u{c003}', GraphemeCat::LVT),
+	('\u{c004}', '\u{c004}', GraphemeCat::LV),
+	('\u{c005}', '\u{c01f}', GraphemeCat::LVT),
+	('\u{c020}', '\u{c020}', GraphemeCat::LV),
+	('\u{c021}', '\u{c03b}', GraphemeCat::LVT),
+	('\u{c03c}', '\u{c03c}', GraphemeCat::LV),
+	('\u{c03d}', '\u{c057}', GraphemeCat::LVT),
+	('\u{c058}', '\u{c058}', GraphemeCat::LV),
+	('\u{c059}', '\u{c073}', GraphemeCat::LVT),
+	('\u{c074}', '\u{c074}', GraphemeCat::LV),
+	('\u{c075}', '\u{c08f}', GraphemeCat::LVT),
+	('\u{c090}', '\u{c090}', GraphemeCat::LV),
+	('\u{c091}', '\u{c0ab}', GraphemeCat::LVT),
+	('\u{c0ac}', '\u{c0ac}', GraphemeCat::LV),
+	('\u{c0ad}', '\u{c0c7}', GraphemeCat::LVT),
+	('\u{c0c8}', '\u{c0c8}', GraphemeCat::LV),
+	('\u{c0c9}', '\u{c0e3}', GraphemeCat::LVT),
+	('\u{c0e4}', '\u{c0e4}', GraphemeCat::LV),
+	('\u{c0e5}', '\u{c0ff}', GraphemeCat::LVT),
+	('\u{c100}', '\u{c100}', GraphemeCat::LV),
+	('\u{c101}', '\u{c11b}', GraphemeCat::LVT),
+	('\u{c11c}', '\u{c11c}', GraphemeCat::LV),
+	('\u{c11d}', '\u{c137}', GraphemeCat::LVT),
+	('\u{c138}', '\u{c138}', GraphemeCat::LV),
+	('\u{c139}', '\u{c153}', GraphemeCat::LVT),
+	('\u{c154}', '\u{c154}', GraphemeCat::LV),
+	('\u{c155}', '\u{c16f}', GraphemeCat::LVT),
+	('\u{c170}', '\u{c170}', GraphemeCat::LV),
+	('\u{c171}', '\u{c18b}', GraphemeCat::LVT),
+	('\u{c18c}', '\u{c18c}', GraphemeCat::LV),
+	('\u{c18d}', '\u{c1a7}', GraphemeCat::LVT),
+	('\u{c1a8}', '\u{c1a8}', GraphemeCat::LV),
+	('\u{c1a9}', '\u{c1c3}', GraphemeCat::LVT),
+	('\u{c1c4}', '\u{c1c4}', GraphemeCat::LV),
+	('\u{c1c5}', '\u{c1df}', GraphemeCat::LVT),
+	('\u{c1e0}', '\u{c1e0}', GraphemeCat::LV),
+	('\u{c1e1}', '\u{c1fb}', GraphemeCat::LVT),
+	('\u{c1fc}', '\u{c1fc}', GraphemeCat::LV),
+	('\u{c1fd}', '\u{c217}', GraphemeCat::LVT),
+	('\u{c218}', '\u{c218}', GraphemeCat::LV),
+	('\u{c219}', '\u{c233}', GraphemeCat::LVT),
+	('\u{c234}', '\u{c234}', GraphemeCat::LV),
+	('\u{c235}', '\u{c24f}', GraphemeCat::LVT),
+	('\u{c250}', '\u{c250}', GraphemeCat::LV),
+	('\u{c251}', '\u{c26b}', GraphemeCat::LVT),
+	('\u{c26c}', '\u{c26c}', GraphemeCat::LV),
+	('\u{c26d}', '\u{c287}', GraphemeCat::LVT),
+	('\u{c288}', '\u{c288}', GraphemeCat::LV),
+	('\u{c289}', '\u{c2a3}', GraphemeCat::LVT),
+	('\u{c2a4}', '\u{c2a4}', GraphemeCat::LV),
+	('\u{c2a5}', '\u{c2bf}', GraphemeCat::LVT),
+	('\u{c2c0}', '\u{c2c0}', GraphemeCat::LV),
+	('\u{c2c1}', '\u{c2db}', GraphemeCat::LVT),
+	('\u{c2dc}', '\u{c2dc}', GraphemeCat::LV),
+	('\u{c2dd}', '\u{c2f7}', GraphemeCat::LVT),
+	('\u{c2f8}', '\u{c2f8}', GraphemeCat::LV),
+	('\u{c2f9}', '\u{c313}', GraphemeCat::LVT),
+	('\u{c314}', '\u{c314}', GraphemeCat::LV),
+	('\u{c315}', '\u{c32f}', GraphemeCat::LVT),
+	('\u{c330}', '\u{c330}', GraphemeCat::LV),
+	('\u{c331}', '\u{c34b}', GraphemeCat::LVT),
+	('\u{c34c}', '\u{c34c}', GraphemeCat::LV),
+	('\u{c34d}', '\u{c367}', GraphemeCat::LVT),
+	('\u{c368}', '\u{c368}', GraphemeCat::LV),
+	('\u{c369}', '\u{c383}', GraphemeCat::LVT),
+	('\u{c384}', '\u{c384}', GraphemeCat::LV),
+	('\u{c385}', '\u{c39f}', GraphemeCat::LVT),
+	('\u{c3a0}', '\u{c3a0}', GraphemeCat::LV),
+	('\u{c3a1}', '\u{c3bb}', GraphemeCat::LVT),
+	('\u{c3bc}', '\u{c3bc}', GraphemeCat::LV),
+	('\u{c3bd}', '\u{c3d7}', GraphemeCat::LVT),
+	('\u{c3d8}', '\u{c3d8}', GraphemeCat::LV),
+	('\u{c3d9}', '\u{c3f3}', GraphemeCat::LVT),
+	('\u{c3f4}', '\u{c3f4}', GraphemeCat::LV),
+	('\u{c3f5}', '\u{c40f}', GraphemeCat::LVT),
+	('\u{c410}', '\u{c410}', GraphemeCat::LV),
+	('\u{c411}', '\u{c42b}', GraphemeCat::LVT),
+	('\u{c42c}', '\u{c42c}', GraphemeCat::LV),
+	('\u{c42d}', '\u{c447}', GraphemeCat::LVT),
+	('\u{c448}', '\u{c448}', GraphemeCat::LV),
+	('\u{c449}', '\u{c463}', GraphemeCat::LVT),
+	('\u{c464}', '\u{c464}', GraphemeCat::LV),
+	('\u{c465}', '\u{c47f}', GraphemeCat::LVT),
+	('\u{c480}', '\u{c480}', GraphemeCat::LV),
+	('\u{c481}', '\u{c49b}', GraphemeCat::LVT),
+	('\u{c49c}', '\u{c49c}', GraphemeCat::LV),
+	('\u{c49d}', '\u{c4b7}', GraphemeCat::LVT),
+	('\u{c4b8}', '\u{c4b8}', GraphemeCat::LV),
+	('\u{c4b9}', '\u{c4d3}', GraphemeCat::LVT),
+	('\u{c4d4}', '\u{c4d4}', GraphemeCat::LV),
+	('\u{c4d5}', '\u{c4ef}', GraphemeCat::LVT),
+	('\u{c4f0}', '\u{c4f0}', GraphemeCat::LV),
+	('\u{c4f1}', '\u{c50b}', GraphemeCat::LVT),
+	('\u{c50c}', '\u{c50c}', GraphemeCat::LV),
+	('\u{c50d}', '\u{c527}', GraphemeCat::LVT),
+	('\u{c528}', '\u{c528}', GraphemeCat::LV),
+	('\u{c529}', '\u{c543}', GraphemeCat::LVT),
+	('\u{c544}', '\u{c544}', GraphemeCat::LV),
+	('\u{c545}', '\u{c55f}', GraphemeCat::LVT),
+	('\u{c560}', '\u{c560}', GraphemeCat::LV),
+	('\u{c561}', '\u{c57b}', GraphemeCat::LVT),
+	('\u{c57c}', '\u{c57c}', GraphemeCat::LV),
+	('\u{c57d}', '\u{c597}', GraphemeCat::LVT),
+	('\u{c598}', '\u{c598}', GraphemeCat::LV),
+	('\u{c599}', '\u{c5b3}', GraphemeCat::LVT),
+	('\u{c5b4}', '\u{c5b4}', GraphemeCat::LV),
+	('\u{c5b5}', '\u{c5cf}', GraphemeCat::LVT),
+	('\u{c5d0}', '\u{c5d0}', GraphemeCat::LV),
+	('\u{c5d1}', '\u{c5eb}', GraphemeCat::LVT),
+	('\u{c5ec}', '\u{c5ec}', GraphemeCat::LV),
+	('\u{c5ed}', '\u{c607}', GraphemeCat::LVT),
+	('\u{c608}', '\u{c608}', GraphemeCat::LV),
+	('\u{c609}', '\u{c623}', GraphemeCat::LVT),
+	('\u{c624}', '\u{c624}', GraphemeCat::LV),
+	('\u{c625}', '\u{c63f}', GraphemeCat::LVT),
+	('\u{c640}', '\u{c640}', GraphemeCat::LV),
+	('\u{c641}', '\u{c65b}', GraphemeCat::LVT),
+	('\u{c65c}', '\u{c65c}', GraphemeCat::LV),
+	('\u{c65d}', '\u{c677}', GraphemeCat::LVT),
+	('\u{c678}', '\u{c678}', GraphemeCat::LV),
+	('\u{c679}', '\u{c693}', GraphemeCat::LVT),
+	('\u{c694}', '\u{c694}', GraphemeCat::LV),
+	('\u{c695}', '\u{c6af}', GraphemeCat::LVT),
+	('\u{c6b0}', '\u{c6b0}', GraphemeCat::LV),
+	('\u{c6b1}', '\u{c6cb}', GraphemeCat::LVT),
+	('\u{c6cc}', '\u{c6cc}', GraphemeCat::LV),
+	('\u{c6cd}', '\u{c6e7}', GraphemeCat::LVT),
+	('\u{c6e8}', '\u{c6e8}', GraphemeCat::LV),
+	('\u{c6e9}', '\u{c703}', GraphemeCat::LVT),
+	('\u{c704}', '\u{c704}', GraphemeCat::LV),
+	('\u{c705}', '\u{c71f}', GraphemeCat::LVT),
+	('\u{c720}', '\u{c720}', GraphemeCat::LV),
+	('\u{c721}', '\u{c73b}', GraphemeCat::LVT),
+	('\u{c73c}', '\u{c73c}', GraphemeCat::LV),
+	('\u{c73d}', '\u{c757}', GraphemeCat::LVT),
+	('\u{c758}', '\u{c758}', GraphemeCat::LV),
+	('\u{c759}', '\u{c773}', GraphemeCat::LVT),
+	('\u{c774}', '\u{c774}', GraphemeCat::LV),
+	('\u{c775}', '\u{c78f}', GraphemeCat::LVT),
+	('\u{c790}', '\u{c790}', GraphemeCat::LV),
+	('\u{c791}', '\u{c7ab}', GraphemeCat::LVT),
+	('\u{c7ac}', '\u{c7ac}', GraphemeCat::LV),
+	('\u{c7ad}', '\u{c7c7}', GraphemeCat::LVT),
+	('\u{c7c8}', '\u{c7c8}', GraphemeCat::LV),
+	('\u{c7c9}', '\u{c7e3}', GraphemeCat::LVT),
+	('\u{c7e4}', '\u{c7e4}', GraphemeCat::LV),
+	('\u{c7e5}', '\u{c7ff}', GraphemeCat::LVT),
+	('\u{c800}', '\u{c800}', GraphemeCat::LV),
+	('\u{c801}', '\u{c81b}', GraphemeCat::LVT),
+	('\u{c81c}', '\u{c81c}', GraphemeCat::LV),
+	('\u{c81d}', '\u{c837}', GraphemeCat::LVT),
+	('\u{c838}', '\u{c838}', GraphemeCat::LV),
+	('\u{c839}', '\u{c853}', GraphemeCat::LVT),
+	('\u{c854}', '\u{c854}', GraphemeCat::LV),
+	('\u{c855}', '\u{c86f}', GraphemeCat::LVT),
+	('\u{c870}', '\u{c870}', GraphemeCat::LV),
+	('\u{c871}', '\u{c88b}', GraphemeCat::LVT),
+	('\u{c88c}', '\u{c88c}', GraphemeCat::LV),
+	('\u{c88d}', '\u{c8a7}', GraphemeCat::LVT),
+	('\u{c8a8}', '\u{c8a8}', GraphemeCat::LV),
+	('\u{c8a9}', '\u{c8c3}', GraphemeCat::LVT),
+	('\u{c8c4}', '\u{c8c4}', GraphemeCat::LV),
+	('\u{c8c5}', '\u{c8df}', GraphemeCat::LVT),
+	('\u{c8e0}', '\u{c8e0}', GraphemeCat::LV),
+	('\u{c8e1}', '\u{c8fb}', GraphemeCat::LVT),
+	('\u{c8fc}', '\u{c8fc}', GraphemeCat::LV),
+	('\u{c8fd}', '\u{c917}', GraphemeCat::LVT),
+	('\u{c918}', '\u{c918}', GraphemeCat::LV),
+	('\u{c919}', '\u{c933}', GraphemeCat::LVT),
+	('\u{c934}', '\u{c934}', GraphemeCat::LV),
+	('\u{c935}', '\u{c94f}', GraphemeCat::LVT),
+	('\u{c950}', '\u{c950}', GraphemeCat::LV),
+	('\u{c951}', '\u{c96b}', GraphemeCat::LVT),
+	('\u{c96c}', '\u{c96c}', GraphemeCat::LV),
+	('\u{c96d}', '\u{c987}', GraphemeCat::LVT),
+	('\u{c988}', '\u{c988}', GraphemeCat::LV),
+	('\u{c989}', '\u{c9a3}', GraphemeCat::LVT),
+	('\u{c9a4}', '\u{c9a4}', GraphemeCat::LV),
+	('\u{c9a5}', '\u{c9bf}', GraphemeCat::LVT),
+	('\u{c9c0}', '\u{c9c0}', GraphemeCat::LV),
+	('\u{c9c1}', '\u{c9db}', GraphemeCat::LVT),
+	('\u{c9dc}', '\u{c9dc}', GraphemeCat::LV),
+	('\u{c9dd}', '\u{c9f7}', GraphemeCat::LVT),
+	('\u{c9f8}', '\u{c9f8}', GraphemeCat::LV),
+	('\u{c9f9}', '\u{ca13}', GraphemeCat::LVT),
+	('\u{ca14}', '\u{ca14}', GraphemeCat::LV),
+	('\u{ca15}', '\u{ca2f}', GraphemeCat::LVT),
+	('\u{ca30}', '\u{ca30}', GraphemeCat::LV),
+	('\u{ca31}', '\u{ca4b}', GraphemeCat::LVT),
+	('\u{ca4c}', '\u{ca4c}', GraphemeCat::LV),
+	('\u{ca4d}', '\u{ca67}', GraphemeCat::LVT),
+	('\u{ca68}', '\u{ca68}', GraphemeCat::LV),
+	('\u{ca69}', '\u{ca83}', GraphemeCat::LVT),
+	('\u{ca84}', '\u{ca84}', GraphemeCat::LV),
+	('\u{ca85}', '\u{ca9f}', GraphemeCat::LVT),
+	('\u{caa0}', '\u{caa0}', GraphemeCat::LV),
+	('\u{caa1}', '\u{cabb}', GraphemeCat::LVT),
+	('\u{cabc}', '\u{cabc}', GraphemeCat::LV),
+	('\u{cabd}', '\u{cad7}', GraphemeCat::LVT),
+	('\u{cad8}', '\u{cad8}', GraphemeCat::LV),
+	('\u{cad9}', '\u{caf3}', GraphemeCat::LVT),
+	('\u{caf4}', '\u{caf4}', GraphemeCat::LV),
+	('\u{caf5}', '\u{cb0f}', GraphemeCat::LVT),
+	('\u{cb10}', '\u{cb10}', GraphemeCat::LV),
+	('\u{cb11}', '\u{cb2b}', GraphemeCat::LVT),
+	('\u{cb2c}', '\u{cb2c}', GraphemeCat::LV),
+	('\u{cb2d}', '\u{cb47}', GraphemeCat::LVT),
+	('\u{cb48}', '\u{cb48}', GraphemeCat::LV),
+	('\u{cb49}', '\u{cb63}', GraphemeCat::LVT),
+	('\u{cb64}', '\u{cb64}', GraphemeCat::LV),
+	('\u{cb65}', '\u{cb7f}', GraphemeCat::LVT),
+	('\u{cb80}', '\u{cb80}', GraphemeCat::LV),
+	('\u{cb81}', '\u{cb9b}', GraphemeCat::LVT),
+	('\u{cb9c}', '\u{cb9c}', GraphemeCat::LV),
+	('\u{cb9d}', '\u{cbb7}', GraphemeCat::LVT),
+	('\u{cbb8}', '\u{cbb8}', GraphemeCat::LV),
+	('\u{cbb9}', '\u{cbd3}', GraphemeCat::LVT),
+	('\u{cbd4}', '\u{cbd4}', GraphemeCat::LV),
+	('\u{cbd5}', '\u{cbef}', GraphemeCat::LVT),
+	('\u{cbf0}', '\u{cbf0}', GraphemeCat::LV),
+	('\u{cbf1}', '\u{cc0b}', GraphemeCat::LVT),
+	('\u{cc0c}', '\u{cc0c}', GraphemeCat::LV),
+	('\u{cc0d}', '\u{cc27}', GraphemeCat::LVT),
+	('\u{cc28}', '\u{cc28}', GraphemeCat::LV),
+	('\u{cc29}', '\u{cc43}', GraphemeCat::LVT),
+	('\u{cc44}', '\u{cc44}', GraphemeCat::LV),
+	('\u{cc45}', '\u{cc5f}', GraphemeCat::LVT),
+	('\u{cc60}', '\u{cc60}', GraphemeCat::LV),
+	('\u{cc61}', '\u{cc7b}', GraphemeCat::LVT),
+	('\u{cc7c}', '\u{cc7c}', GraphemeCat::LV),
+	('\u{cc7d}', '\u{cc97}', GraphemeCat::LVT),
+	('\u{cc98}', '\u{cc98}', GraphemeCat::LV),
+	('\u{cc99}', '\u{ccb3}', GraphemeCat::LVT),
+	('\u{ccb4}', '\u{ccb4}', GraphemeCat::LV),
+	('\u{ccb5}', '\u{cccf}', GraphemeCat::LVT),
+	('\u{ccd0}', '\u{ccd0}', GraphemeCat::LV),
+	('\u{ccd1}', '\u{cceb}', GraphemeCat::LVT),
+	('\u{ccec}', '\u{ccec}', GraphemeCat::LV),
+	('\u{cced}', '\u{cd07}', GraphemeCat::LVT),
+	('\u{cd08}', '\u{cd08}', GraphemeCat::LV),
+	('\u{cd09}', '\u{cd23}', GraphemeCat::LVT),
+	('\u{cd24}', '\u{cd24}', GraphemeCat::LV),
+	('\u{cd25}', '\u{cd3f}', GraphemeCat::LVT),
+	('\u{cd40}', '\u{cd40}', GraphemeCat::LV),
+	('\u{cd41}', '\u{cd5b}', GraphemeCat::LVT),
+	('\u{cd5c}', '\u{cd5c}', GraphemeCat::LV),
+	('\u{cd5d}', '\u{cd77}', GraphemeCat::LVT),
+	('\u{cd78}', '\u{cd78}', GraphemeCat::LV),
+	('\u{cd79}', '\u{cd93}', GraphemeCat::LVT),
+	('\u{cd94}', '\u{cd94}', GraphemeCat::LV),
+	('\u{cd95}', '\u{cdaf}', GraphemeCat::LVT),
+	('\u{cdb0}', '\u{cdb0}', GraphemeCat::LV),
+	('\u{cdb1}', '\u{cdcb}', GraphemeCat::LVT),
+	('\u{cdcc}', '\u{cdcc}', GraphemeCat::LV),
+	('\u{cdcd}', '\u{cde7}', GraphemeCat::LVT),
+	('\u{cde8}', '\u{cde8}', GraphemeCat::LV),
+	('\u{cde9}', '\u{ce03}', GraphemeCat::LVT),
+	('\u{ce04}', '\u{ce04}', GraphemeCat::LV),
+	('\u{ce05}', '\u{ce1f}', GraphemeCat::LVT),
+	('\u{ce20}', '\u{ce20}', GraphemeCat::LV),
+	('\u{ce21}', '\u{ce3b}', GraphemeCat::LVT),
+	('\u{ce3c}', '\u{ce3c}', GraphemeCat::LV),
+	('\u{ce3d}', '\u{ce57}', GraphemeCat::LVT),
+	('\u{ce58}', '\u{ce58}', GraphemeCat::LV),
+	('\u{ce59}', '\u{ce73}', GraphemeCat::LVT),
+	('\u{ce74}', '\u{ce74}', GraphemeCat::LV),
+	('\u{ce75}', '\u{ce8f}', GraphemeCat::LVT),
+	('\u{ce90}', '\u{ce90}', GraphemeCat::LV),
+	('\u{ce91}', '\u{ceab}', GraphemeCat::LVT),
+	('\u{ceac}', '\u{ceac}', GraphemeCat::LV),
+	('\u{cead}', '\u{cec7}', GraphemeCat::LVT),
+	('\u{cec8}', '\u{cec8}', GraphemeCat::LV),
+	('\u{cec9}', '\u{cee3}', GraphemeCat::LVT),
+	('\u{cee4}', '\u{cee4}', GraphemeCat::LV),
+	('\u{cee5}', '\u{ceff}', GraphemeCat::LVT),
+	('\u{cf00}', '\u{cf00}', GraphemeCat::LV),
+	('\u{cf01}', '\u{cf1b}', GraphemeCat::LVT),
+	('\u{cf1c}', '\u{cf1c}', GraphemeCat::LV),
+	('\u{cf1d}', '\u{cf37}', GraphemeCat::LVT),
+	('\u{cf38}', '\u{cf38}', GraphemeCat::LV),
+	('\u{cf39}', '\u{cf53}', GraphemeCat::LVT),
+	('\u{cf54}', '\u{cf54}', GraphemeCat::LV),
+	('\u{cf55}', '\u{cf6f}', GraphemeCat::LVT),
+	('\u{cf70}', '\u{cf70}', GraphemeCat::LV),
+	('\u{cf71}', '\u{cf8b}', GraphemeCat::LVT),
+	('\u{cf8c}', '\u{cf8c}', GraphemeCat::LV),
+	('\u{cf8d}', '\u{cfa7}', GraphemeCat::LVT),
+	('\u{cfa8}', '\u{cfa8}', GraphemeCat::LV),
+	('\u{cfa9}', '\u{cfc3}', GraphemeCat::LVT),
+	('\u{cfc4}', '\u{cfc4}', GraphemeCat::LV),
+	('\u{cfc5}', '\u{cfdf}', GraphemeCat::LVT),
+	('\u{cfe0}', '\u{cfe0}', GraphemeCat::LV),
+	('\u{cfe1}', '\u{cffb}', GraphemeCat::LVT),
+	('\u{cffc}', '\u{cffc}', GraphemeCat::LV),
+	('\u{cffd}', '\u{d017}', GraphemeCat::LVT),
+	('\u{d018}', '\u{d018}', GraphemeCat::LV),
+	('\u{d019}', '\u{d033}', GraphemeCat::LVT),
+	('\u{d034}', '\u{d034}', GraphemeCat::LV),
+	('\u{d035}', '\u{d04f}', GraphemeCat::LVT),
+	('\u{d050}', '\u{d050}', GraphemeCat::LV),
+	('\u{d051}', '\u{d06b}', GraphemeCat::LVT),
+	('\u{d06c}', '\u{d06c}', GraphemeCat::LV),
+	('\u{d06d}', '\u{d087}', GraphemeCat::LVT),
+	('\u{d088}', '\u{d088}', GraphemeCat::LV),
+	('\u{d089}', '\u{d0a3}', GraphemeCat::LVT),
+	('\u{d0a4}', '\u{d0a4}', GraphemeCat::LV),
+	('\u{d0a5}', '\u{d0bf}', GraphemeCat::LVT),
+	('\u{d0c0}', '\u{d0c0}', GraphemeCat::LV),
+	('\u{d0c1}', '\u{d0db}', GraphemeCat::LVT),
+	('\u{d0dc}', '\u{d0dc}', GraphemeCat::LV),
+	('\u{d0dd}', '\u{d0f7}', GraphemeCat::LVT),
+	('\u{d0f8}', '\u{d0f8}', GraphemeCat::LV),
+	('\u{d0f9}', '\u{d113}', GraphemeCat::LVT),
+	('\u{d114}', '\u{d114}', GraphemeCat::LV),
+	('\u{d115}', '\u{d12f}', GraphemeCat::LVT),
+	('\u{d130}', '\u{d130}', GraphemeCat::LV),
+	('\u{d131}', '\u{d14b}', GraphemeCat::LVT),
+	('\u{d14c}', '\u{d14c}', GraphemeCat::LV),
+	('\u{d14d}', '\u{d167}', GraphemeCat::LVT),
+	('\u{d168}', '\u{d168}', GraphemeCat::LV),
+	('\u{d169}', '\u{d183}', GraphemeCat::LVT),
+	('\u{d184}', '\u{d184}', GraphemeCat::LV),
+	('\u{d185}', '\u{d19f}', GraphemeCat::LVT),
+	('\u{d1a0}', '\u{d1a0}', GraphemeCat::LV),
+	('\u{d1a1}', '\u{d1bb}', GraphemeCat::LVT),
+	('\u{d1bc}', '\u{d1bc}', GraphemeCat::LV),
+	('\u{d1bd}', '\u{d1d7}', GraphemeCat::LVT),
+	('\u{d1d8}', '\u{d1d8}', GraphemeCat::LV),
+	('\u{d1d9}', '\u{d1f3}', GraphemeCat::LVT),
+	('\u{d1f4}', '\u{d1f4}', GraphemeCat::LV),
+	('\u{d1f5}', '\u{d20f}', GraphemeCat::LVT),
+	('\u{d210}', '\u{d210}', GraphemeCat::LV),
+	('\u{d211}', '\u{d22b}', GraphemeCat::LVT),
+	('\u{d22c}', '\u{d22c}', GraphemeCat::LV),
+	('\u{d22d}', '\u{d247}', GraphemeCat::LVT),
+	('\u{d248}', '\u{d248}', GraphemeCat::LV),
+	('\u{d249}', '\u{d263}', GraphemeCat::LVT),
+	('\u{d264}', '\u{d264}', GraphemeCat::LV),
+	('\u{d265}', '\u{d27f}', GraphemeCat::LVT),
+	('\u{d280}', '\u{d280}', GraphemeCat::LV),
+	('\u{d281}', '\u{d29b}', GraphemeCat::LVT),
+	('\u{d29c}', '\u{d29c}', GraphemeCat::LV),
+	('\u{d29d}', '\u{d2b7}', GraphemeCat::LVT),
+	('\u{d2b8}', '\u{d2b8}', GraphemeCat::LV),
+	('\u{d2b9}', '\u{d2d3}', GraphemeCat::LVT),
+	('\u{d2d4}', '\u{d2d4}', GraphemeCat::LV),
+	('\u{d2d5}', '\u{d2ef}', GraphemeCat::LVT),
+	('\u{d2f0}', '\u{d2f0}', GraphemeCat::LV),
+	('\u{d2f1}', '\u{d30b}', GraphemeCat::LVT),
+	('\u{d30c}', '\u{d30c}', GraphemeCat::LV),
+	('\u{d30d}', '\u{d327}', GraphemeCat::LVT),
+	('\u{d328}', '\u{d328}', GraphemeCat::LV),
+	('\u{d329}', '\u{d343}', GraphemeCat::LVT),
+	('\u{d344}', '\u{d344}', GraphemeCat::LV),
+	('\u{d345}', '\u{d35f}', GraphemeCat::LVT),
+	('\u{d360}', '\u{d360}', GraphemeCat::LV),
+	('\u{d361}', '\u{d37b}', GraphemeCat::LVT),
+	('\u{d37c}', '\u{d37c}', GraphemeCat::LV),
+	('\u{d37d}', '\u{d397}', GraphemeCat::LVT),
+	('\u{d398}', '\u{d398}', GraphemeCat::LV),
+	('\u{d399}', '\u{d3b3}', GraphemeCat::LVT),
+	('\u{d3b4}', '\u{d3b4}', GraphemeCat::LV),
+	('\u{d3b5}', '\u{d3cf}', GraphemeCat::LVT),
+	('\u{d3d0}', '\u{d3d0}', GraphemeCat::LV),
+	('\u{d3d1}', '\u{d3eb}', GraphemeCat::LVT),
+	('\u{d3ec}', '\u{d3ec}', GraphemeCat::LV),
+	('\u{d3ed}', '\u{d407}', GraphemeCat::LVT),
+	('\u{d408}', '\u{d408}', GraphemeCat::LV),
+	('\u{d409}', '\u{d423}', GraphemeCat::LVT),
+	('\u{d424}', '\u{d424}', GraphemeCat::LV),
+	('\u{d425}', '\u{d43f}', GraphemeCat::LVT),
+	('\u{d440}', '\u{d440}', GraphemeCat::LV),
+	('\u{d441}', '\u{d45b}', GraphemeCat::LVT),
+	('\u{d45c}', '\u{d45c}', GraphemeCat::LV),
+	('\u{d45d}', '\u{d477}', GraphemeCat::LVT),
+	('\u{d478}', '\u{d478}', GraphemeCat::LV),
+	('\u{d479}', '\u{d493}', GraphemeCat::LVT),
+	('\u{d494}', '\u{d494}', GraphemeCat::LV),
+	('\u{d495}', '\u{d4af}', GraphemeCat::LVT),
+	('\u{d4b0}', '\u{d4b0}', GraphemeCat::LV),
+	('\u{d4b1}', '\u{d4cb}', GraphemeCat::LVT),
+	('\u{d4cc}', '\u{d4cc}', GraphemeCat::LV),
+	('\u{d4cd}', '\u{d4e7}', GraphemeCat::LVT),
+	('\u{d4e8}', '\u{d4e8}', GraphemeCat::LV),
+	('\u{d4e9}', '\u{d503}', GraphemeCat::LVT),
+	('\u{d504}', '\u{d504}', GraphemeCat::LV),
+	('\u{d505}', '\u{d51f}', GraphemeCat::LVT),
+	('\u{d520}', '\u{d520}', GraphemeCat::LV),
+	('\u{d521}', '\u{d53b}', GraphemeCat::LVT),
+	('\u{d53c}', '\u{d53c}', GraphemeCat::LV),
+	('\u{d53d}', '\u{d557}', GraphemeCat::LVT),
+	('\u{d558}', '\u{d558}', GraphemeCat::LV),
+	('\u{d559}', '\u{d573}', GraphemeCat::LVT),
+	('\u{d574}', '\u{d574}', GraphemeCat::LV),
+	('\u{d575}', '\u{d58f}', GraphemeCat::LVT),
+	('\u{d590}', '\u{d590}', GraphemeCat::LV),
+	('\u{d591}', '\u{d5ab}', GraphemeCat::LVT),
+	('\u{d5ac}', '\u{d5ac}', GraphemeCat::LV),
+	('\u{d5ad}', '\u{d5c7}', GraphemeCat::LVT),
+	('\u{d5c8}', '\u{d5c8}', GraphemeCat::LV),
+	('\u{d5c9}', '\u{d5e3}', GraphemeCat::LVT),
+	('\u{d5e4}', '\u{d5e4}', GraphemeCat::LV),
+	('\u{d5e5}', '\u{d5ff}', GraphemeCat::LVT),
+	('\u{d600}', '\u{d600}', GraphemeCat::LV),
+	('\u{d601}', '\u{d61b}', GraphemeCat::LVT),
+	('\u{d61c}', '\u{d61c}', GraphemeCat::LV),
+	('\u{d61d}', '\u{d637}', GraphemeCat::LVT),
+	('\u{d638}', '\u{d638}', GraphemeCat::LV),
+	('\u{d639}', '\u{d653}', GraphemeCat::LVT),
+	('\u{d654}', '\u{d654}', GraphemeCat::LV),
+	('\u{d655}', '\u{d66f}', GraphemeCat::LVT),
+	('\u{d670}', '\u{d670}', GraphemeCat::LV),
+	('\u{d671}', '\u{d68b}', GraphemeCat::LVT),
+	('\u{d68c}', '\u{d68c}', GraphemeCat::LV),
+	('\u{d68d}', '\u{d6a7}', GraphemeCat::LVT),
+	('\u{d6a8}', '\u{d6a8}', GraphemeCat::LV),
+	('\u{d6a9}', '\u{d6c3}', GraphemeCat::LVT),
+	('\u{d6c4}', '\u{d6c4}', GraphemeCat::LV),
+	('\u{d6c5}', '\u{d6df}', GraphemeCat::LVT),
+	('\u{d6e0}', '\u{d6e0}', GraphemeCat::LV),
+	('\u{d6e1}', '\u{d6fb}', GraphemeCat::LVT),
+	('\u{d6fc}', '\u{d6fc}', GraphemeCat::LV),
+	('\u{d6fd}', '\u{d717}', GraphemeCat::LVT),
+	('\u{d718}', '\u{d718}', GraphemeCat::LV),
+	('\u{d719}', '\u{d733}', GraphemeCat::LVT),
+	('\u{d734}', '\u{d734}', GraphemeCat::LV),
+	('\u{d735}', '\u{d74f}', GraphemeCat::LVT),
+	('\u{d750}', '\u{d750}', GraphemeCat::LV),
+	('\u{d751}', '\u{d76b}', GraphemeCat::LVT),
+	('\u{d76c}', '\u{d76c}', GraphemeCat::LV),
+	('\u{d76d}', '\u{d787}', GraphemeCat::LVT),
+	('\u{d788}', '\u{d788}', GraphemeCat::LV),
+	('\u{d789}', '\u{d7a3}', GraphemeCat::LVT),
+	('\u{d7b0}', '\u{d7c6}', GraphemeCat::V),
+	('\u{d7cb}', '\u{d7fb}', GraphemeCat::T),
+	('\u{fb1e}', '\u{fb1e}', GraphemeCat::Extend),
+	('\u{fe00}', '\u{fe0f}', GraphemeCat::Extend),
+	('\u{fe20}', '\u{fe2f}', GraphemeCat::Extend),
+	('\u{feff}', '\u{feff}', GraphemeCat::Control),
+	('\u{ff9e}', '\u{ff9f}', GraphemeCat::Extend),
+	('\u{fff0}', '\u{fffb}', GraphemeCat::Control),
+	('\u{101fd}', '\u{101fd}', GraphemeCat::Extend),
+	('\u{102e0}', '\u{102e0}', GraphemeCat::Extend),
+	('\u{10376}', '\u{1037a}', GraphemeCat::Extend),
+	('\u{10a01}', '\u{10a03}', GraphemeCat::Extend),
+	('\u{10a05}', '\u{10a06}', GraphemeCat::Extend),
+	('\u{10a0c}', '\u{10a0f}', GraphemeCat::Extend),
+	('\u{10a38}', '\u{10a3a}', GraphemeCat::Extend),
+	('\u{10a3f}', '\u{10a3f}', GraphemeCat::Extend),
+	('\u{10ae5}', '\u{10ae6}', GraphemeCat::Extend),
+	('\u{10d24}', '\u{10d27}', GraphemeCat::Extend),
+	('\u{10eab}', '\u{10eac}', GraphemeCat::Extend),
+	('\u{10f46}', '\u{10f50}', GraphemeCat::Extend),
+	('\u{10f82}', '\u{10f85}', GraphemeCat::Extend),
+	('\u{11000}', '\u{11000}', GraphemeCat::SpacingMark),
+	('\u{11001}', '\u{11001}', GraphemeCat::Extend),
+	('\u{11002}', '\u{11002}', GraphemeCat::SpacingMark),
+	('\u{11038}', '\u{11046}', GraphemeCat::Extend),
+	('\u{11070}', '\u{11070}', GraphemeCat::Extend),
+	('\u{11073}', '\u{11074}', GraphemeCat::Extend),
+	('\u{1107f}', '\u{11081}', GraphemeCat::Extend),
+	('\u{11082}', '\u{11082}', GraphemeCat::SpacingMark),
+	('\u{110b0}', '\u{110b2}', GraphemeCat::SpacingMark),
+	('\u{110b3}', '\u{110b6}', GraphemeCat::Extend),
+	('\u{110b7}', '\u{110b8}', GraphemeCat::SpacingMark),
+	('\u{110b9}', '\u{110ba}', GraphemeCat::Extend),
+	('\u{110bd}', '\u{110bd}', GraphemeCat::Prepend),
+	('\u{110c2}', '\u{110c2}', GraphemeCat::Extend),
+	('\u{110cd}', '\u{110cd}', GraphemeCat::Prepend),
+	('\u{11100}', '\u{11102}', GraphemeCat::Extend),
+	('\u{11127}', '\u{1112b}', GraphemeCat::Extend),
+	('\u{1112c}', '\u{1112c}', GraphemeCat::SpacingMark),
+	('\u{1112d}', '\u{11134}', GraphemeCat::Extend),
+	('\u{11145}', '\u{11146}', GraphemeCat::SpacingMark),
+	('\u{11173}', '\u{11173}', GraphemeCat::Extend),
+	('\u{11180}', '\u{11181}', GraphemeCat::Extend),
+	('\u{11182}', '\u{11182}', GraphemeCat::SpacingMark),
+	('\u{111b3}', '\u{111b5}', GraphemeCat::SpacingMark),
+	('\u{111b6}', '\u{111be}', GraphemeCat::Extend),
+	('\u{111bf}', '\u{111c0}', GraphemeCat::SpacingMark),
+	('\u{111c2}', '\u{111c3}', GraphemeCat::Prepend),
+	('\u{111c9}', '\u{111cc}', GraphemeCat::Extend),
+	('\u{111ce}', '\u{111ce}', GraphemeCat::SpacingMark),
+	('\u{111cf}', '\u{111cf}', GraphemeCat::Extend),
+	('\u{1122c}', '\u{1122e}', GraphemeCat::SpacingMark),
+	('\u{1122f}', '\u{11231}', GraphemeCat::Extend),
+	('\u{11232}', '\u{11233}', GraphemeCat::SpacingMark),
+	('\u{11234}', '\u{11234}', GraphemeCat::Extend),
+	('\u{11235}', '\u{11235}', GraphemeCat::SpacingMark),
+	('\u{11236}', '\u{11237}', GraphemeCat::Extend),
+	('\u{1123e}', '\u{1123e}', GraphemeCat::Extend),
+	('\u{112df}', '\u{112df}', GraphemeCat::Extend),
+	('\u{112e0}', '\u{112e2}', GraphemeCat::SpacingMark),
+	('\u{112e3}', '\u{112ea}', GraphemeCat::Extend),
+	('\u{11300}', '\u{11301}', GraphemeCat::Extend),
+	('\u{11302}', '\u{11303}', GraphemeCat::SpacingMark),
+	('\u{1133b}', '\u{1133c}', GraphemeCat::Extend),
+	('\u{1133e}', '\u{1133e}', GraphemeCat::Extend),
+	('\u{1133f}', '\u{1133f}', GraphemeCat::SpacingMark),
+	('\u{11340}', '\u{11340}', GraphemeCat::Extend),
+	('\u{11341}', '\u{11344}', GraphemeCat::SpacingMark),
+	('\u{11347}', '\u{11348}', GraphemeCat::SpacingMark),
+	('\u{1134b}', '\u{1134d}', GraphemeCat::SpacingMark),
+	('\u{11357}', '\u{11357}', GraphemeCat::Extend),
+	('\u{11362}', '\u{11363}', GraphemeCat::SpacingMark),
+	('\u{11366}', '\u{1136c}', GraphemeCat::Extend),
+	('\u{11370}', '\u{11374}', GraphemeCat::Extend),
+	('\u{11435}', '\u{11437}', GraphemeCat::SpacingMark),
+	('\u{11438}', '\u{1143f}', GraphemeCat::Extend),
+	('\u{11440}', '\u{11441}', GraphemeCat::SpacingMark),
+	('\u{11442}', '\u{11444}', GraphemeCat::Extend),
+	('\u{11445}', '\u{11445}', GraphemeCat::SpacingMark),
+	('\u{11446}', '\u{11446}', GraphemeCat::Extend),
+	('\u{1145e}', '\u{1145e}', GraphemeCat::Extend),
+	('\u{114b0}', '\u{114b0}', GraphemeCat::Extend),
+	('\u{114b1}', '\u{114b2}', GraphemeCat::SpacingMark),
+	('\u{114b3}', '\u{114b8}', GraphemeCat::Extend),
+	('\u{114b9}', '\u{114b9}', GraphemeCat::SpacingMark),
+	('\u{114ba}', '\u{114ba}', GraphemeCat::Extend),
+	('\u{114bb}', '\u{114bc}', GraphemeCat::SpacingMark),
+	('\u{114bd}', '\u{114bd}', GraphemeCat::Extend),
+	('\u{114be}', '\u{114be}', GraphemeCat::SpacingMark),
+	('\u{114bf}', '\u{114c0}', GraphemeCat::Extend),
+	('\u{114c1}', '\u{114c1}', GraphemeCat::SpacingMark),
+	('\u{114c2}', '\u{114c3}', GraphemeCat::Extend),
+	('\u{115af}', '\u{115af}', GraphemeCat::Extend),
+	('\u{115b0}', '\u{115b1}', GraphemeCat::SpacingMark),
+	('\u{115b2}', '\u{115b5}', GraphemeCat::Extend),
+	('\u{115b8}', '\u{115bb}', GraphemeCat::SpacingMark),
+	('\u{115bc}', '\u{115bd}', GraphemeCat::Extend),
+	('\u{115be}', '\u{115be}', GraphemeCat::SpacingMark),
+	('\u{115bf}', '\u{115c0}', GraphemeCat::Extend),
+	('\u{115dc}', '\u{115dd}', GraphemeCat::Extend),
+	('\u{11630}', '\u{11632}', GraphemeCat::SpacingMark),
+	('\u{11633}', '\u{1163a}', GraphemeCat::Extend),
+	('\u{1163b}', '\u{1163c}', GraphemeCat::SpacingMark),
+	('\u{1163d}', '\u{1163d}', GraphemeCat::Extend),
+	('\u{1163e}', '\u{1163e}', GraphemeCat::SpacingMark),
+	('\u{1163f}', '\u{11640}', GraphemeCat::Extend),
+	('\u{116ab}', '\u{116ab}', GraphemeCat::Extend),
+	('\u{116ac}', '\u{116ac}', GraphemeCat::SpacingMark),
+	('\u{116ad}', '\u{116ad}', GraphemeCat::Extend),
+	('\u{116ae}', '\u{116af}', GraphemeCat::SpacingMark),
+	('\u{116b0}', '\u{116b5}', GraphemeCat::Extend),
+	('\u{116b6}', '\u{116b6}', GraphemeCat::SpacingMark),
+	('\u{116b7}', '\u{116b7}', GraphemeCat::Extend),
+	('\u{1171d}', '\u{1171f}', GraphemeCat::Extend),
+	('\u{11722}', '\u{11725}', GraphemeCat::Extend),
+	('\u{11726}', '\u{11726}', GraphemeCat::SpacingMark),
+	('\u{11727}', '\u{1172b}', GraphemeCat::Extend),
+	('\u{1182c}', '\u{1182e}', GraphemeCat::SpacingMark),
+	('\u{1182f}', '\u{11837}', GraphemeCat::Extend),
+	('\u{11838}', '\u{11838}', GraphemeCat::SpacingMark),
+	('\u{11839}', '\u{1183a}', GraphemeCat::Extend),
+	('\u{11930}', '\u{11930}', GraphemeCat::Extend),
+	('\u{11931}', '\u{11935}', GraphemeCat::SpacingMark),
+	('\u{11937}', '\u{11938}', GraphemeCat::SpacingMark),
+	('\u{1193b}', '\u{1193c}', GraphemeCat::Extend),
+	('\u{1193d}', '\u{1193d}', GraphemeCat::SpacingMark),
+	('\u{1193e}', '\u{1193e}', GraphemeCat::Extend),
+	('\u{1193f}', '\u{1193f}', GraphemeCat::Prepend),
+	('\u{11940}', '\u{11940}', GraphemeCat::SpacingMark),
+	('\u{11941}', '\u{11941}', GraphemeCat::Prepend),
+	('\u{11942}', '\u{11942}', GraphemeCat::SpacingMark),
+	('\u{11943}', '\u{11943}', GraphemeCat::Extend),
+	('\u{119d1}', '\u{119d3}', GraphemeCat::SpacingMark),
+	('\u{119d4}', '\u{119d7}', GraphemeCat::Extend),
+	('\u{119da}', '\u{119db}', GraphemeCat::Extend),
+	('\u{119dc}', '\u{119df}', GraphemeCat::SpacingMark),
+	('\u{119e0}', '\u{119e0}', GraphemeCat::Extend),
+	('\u{119e4}', '\u{119e4}', GraphemeCat::SpacingMark),
+	('\u{11a01}', '\u{11a0a}', GraphemeCat::Extend),
+	('\u{11a33}', '\u{11a38}', GraphemeCat::Extend),
+	('\u{11a39}', '\u{11a39}', GraphemeCat::SpacingMark),
+	('\u{11a3a}', '\u{11a3a}', GraphemeCat::Prepend),
+	('\u{11a3b}', '\u{11a3e}', GraphemeCat::Extend),
+	('\u{11a47}', '\u{11a47}', GraphemeCat::Extend),
+	('\u{11a51}', '\u{11a56}', GraphemeCat::Extend),
+	('\u{11a57}', '\u{11a58}', GraphemeCat::SpacingMark),
+	('\u{11a59}', '\u{11a5b}', GraphemeCat::Extend),
+	('\u{11a84}', '\u{11a89}', GraphemeCat::Prepend),
+	('\u{11a8a}', '\u{11a96}', GraphemeCat::Extend),
+	('\u{11a97}', '\u{11a97}', GraphemeCat::SpacingMark),
+	('\u{11a98}', '\u{11a99}', GraphemeCat::Extend),
+	('\u{11c2f}', '\u{11c2f}', GraphemeCat::SpacingMark),
+	('\u{11c30}', '\u{11c36}', GraphemeCat::Extend),
+	('\u{11c38}', '\u{11c3d}', GraphemeCat::Extend),
+	('\u{11c3e}', '\u{11c3e}', GraphemeCat::SpacingMark),
+	('\u{11c3f}', '\u{11c3f}', GraphemeCat::Extend),
+	('\u{11c92}', '\u{11ca7}', GraphemeCat::Extend),
+	('\u{11ca9}', '\u{11ca9}', GraphemeCat::SpacingMark),
+	('\u{11caa}', '\u{11cb0}', GraphemeCat::Extend),
+	('\u{11cb1}', '\u{11cb1}', GraphemeCat::SpacingMark),
+	('\u{11cb2}', '\u{11cb3}', GraphemeCat::Extend),
+	('\u{11cb4}', '\u{11cb4}', GraphemeCat::SpacingMark),
+	('\u{11cb5}', '\u{11cb6}', GraphemeCat::Extend),
+	('\u{11d31}', '\u{11d36}', GraphemeCat::Extend),
+	('\u{11d3a}', '\u{11d3a}', GraphemeCat::Extend),
+	('\u{11d3c}', '\u{11d3d}', GraphemeCat::Extend),
+	('\u{11d3f}', '\u{11d45}', GraphemeCat::Extend),
+	('\u{11d46}', '\u{11d46}', GraphemeCat::Prepend),
+	('\u{11d47}', '\u{11d47}', GraphemeCat::Extend),
+	('\u{11d8a}', '\u{11d8e}', GraphemeCat::SpacingMark),
+	('\u{11d90}', '\u{11d91}', GraphemeCat::Extend),
+	('\u{11d93}', '\u{11d94}', GraphemeCat::SpacingMark),
+	('\u{11d95}', '\u{11d95}', GraphemeCat::Extend),
+	('\u{11d96}', '\u{11d96}', GraphemeCat::SpacingMark),
+	('\u{11d97}', '\u{11d97}', GraphemeCat::Extend),
+	('\u{11ef3}', '\u{11ef4}', GraphemeCat::Extend),
+	('\u{11ef5}', '\u{11ef6}', GraphemeCat::SpacingMark),
+	('\u{13430}', '\u{13438}', GraphemeCat::Control),
+	('\u{16af0}', '\u{16af4}', GraphemeCat::Extend),
+	('\u{16b30}', '\u{16b36}', GraphemeCat::Extend),
+	('\u{16f4f}', '\u{16f4f}', GraphemeCat::Extend),
+	('\u{16f51}', '\u{16f87}', GraphemeCat::SpacingMark),
+	('\u{16f8f}', '\u{16f92}', GraphemeCat::Extend),
+	('\u{16fe4}', '\u{16fe4}', GraphemeCat::Extend),
+	('\u{16ff0}', '\u{16ff1}', GraphemeCat::SpacingMark),
+	('\u{1bc9d}', '\u{1bc9e}', GraphemeCat::Extend),
+	('\u{1bca0}', '\u{1bca3}', GraphemeCat::Control),
+	('\u{1cf00}', '\u{1cf2d}', GraphemeCat::Extend),
+	('\u{1cf30}', '\u{1cf46}', GraphemeCat::Extend),
+	('\u{1d165}', '\u{1d165}', GraphemeCat::Extend),
+	('\u{1d166}', '\u{1d166}', GraphemeCat::SpacingMark),
+	('\u{1d167}', '\u{1d169}', GraphemeCat::Extend),
+	('\u{1d16d}', '\u{1d16d}', GraphemeCat::SpacingMark),
+	('\u{1d16e}', '\u{1d172}', GraphemeCat::Extend),
+	('\u{1d173}', '\u{1d17a}', GraphemeCat::Control),
+	('\u{1d17b}', '\u{1d182}', GraphemeCat::Extend),
+	('\u{1d185}', '\u{1d18b}', GraphemeCat::Extend),
+	('\u{1d1aa}', '\u{1d1ad}', GraphemeCat::Extend),
+	('\u{1d242}', '\u{1d244}', GraphemeCat::Extend),
+	('\u{1da00}', '\u{1da36}', GraphemeCat::Extend),
+	('\u{1da3b}', '\u{1da6c}', GraphemeCat::Extend),
+	('\u{1da75}', '\u{1da75}', GraphemeCat::Extend),
+	('\u{1da84}', '\u{1da84}', GraphemeCat::Extend),
+	('\u{1da9b}', '\u{1da9f}', GraphemeCat::Extend),
+	('\u{1daa1}', '\u{1daaf}', GraphemeCat::Extend),
+	('\u{1e000}', '\u{1e006}', GraphemeCat::Extend),
+	('\u{1e008}', '\u{1e018}', GraphemeCat::Extend),
+	('\u{1e01b}', '\u{1e021}', GraphemeCat::Extend),
+	('\u{1e023}', '\u{1e024}', GraphemeCat::Extend),
+	('\u{1e026}', '\u{1e02a}', GraphemeCat::Extend),
+	('\u{1e130}', '\u{1e136}', GraphemeCat::Extend),
+	('\u{1e2ae}', '\u{1e2ae}', GraphemeCat::Extend),
+	('\u{1e2ec}', '\u{1e2ef}', GraphemeCat::Extend),
+	('\u{1e8d0}', '\u{1e8d6}', GraphemeCat::Extend),
+	('\u{1e944}', '\u{1e94a}', GraphemeCat::Extend),
+	('\u{1f1e6}', '\u{1f1ff}', GraphemeCat::RegionalIndicator),
+	('\u{1f3fb}', '\u{1f3ff}', GraphemeCat::Extend),
+	('\u{e0000}', '\u{e001f}', GraphemeCat::Control),
+	('\u{e0020}', '\u{e007f}', GraphemeCat::Extend),
+	('\u{e0080}', '\u{e00ff}', GraphemeCat::Control),
+	('\u{e0100}', '\u{e01ef}', GraphemeCat::Extend),
+	('\u{e01f0}', '\u{e0fff}', GraphemeCat::Control),
+];
+
+/**
+Ranges of code points with the `Extended_Pictographic` property.  Sorted, non-overlapping and inclusive.
+*/
+pub static EXTENDED_PICTOGRAPHIC: &'static [(char, char)] = &[
+	('\u{a9}', '\u{a9}'),
+	('\u{ae}', '\u{ae}'),
+	('\u{203c}', '\u{203c}'),
+	('\u{2049}', '\u{2049}'),
+	('\u{2122}', '\u{2122}'),
+	('\u{2139}', '\u{2139}'),
+	('\u{2194}', '\u{2199}'),
+	('\u{21a9}', '\u{21aa}'),
+	('\u{231a}', '\u{231b}'),
+	('\u{2328}', '\u{2328}'),
+	('\u{2388}', '\u{2388}'),
+	('\u{23cf}', '\u{23cf}'),
+	('\u{23e9}', '\u{23f3}'),
+	('\u{23f8}', '\u{23fa}'),
+	('\u{24c2}', '\u{24c2}'),
+	('\u{25aa}', '\u{25ab}'),
+	('\u{25b6}', '\u{25b6}'),
+	('\u{25c0}', '\u{25c0}'),
+	('\u{25fb}', '\u{25fe}'),
+	('\u{2600}', '\u{2605}'),
+	('\u{2607}', '\u{2612}'),
+	('\u{2614}', '\u{2685}'),
+	('\u{2690}', '\u{2705}'),
+	('\u{2708}', '\u{2712}'),
+	('\u{2714}', '\u{2714}'),
+	('\u{2716}', '\u{2716}'),
+	('\u{271d}', '\u{271d}'),
+	('\u{2721}', '\u{2721}'),
+	('\u{2728}', '\u{2728}'),
+	('\u{2733}', '\u{2734}'),
+	('\u{2744}', '\u{2744}'),
+	('\u{2747}', '\u{2747}'),
+	('\u{274c}', '\u{274c}'),
+	('\u{274e}', '\u{274e}'),
+	('\u{2753}', '\u{2755}'),
+	('\u{2757}', '\u{2757}'),
+	('\u{2763}', '\u{2767}'),
+	('\u{2795}', '\u{2797}'),
+	('\u{27a1}', '\u{27a1}'),
+	('\u{27b0}', '\u{27b0}'),
+	('\u{27bf}', '\u{27bf}'),
+	('\u{2934}', '\u{2935}'),
+	('\u{2b05}', '\u{2b07}'),
+	('\u{2b1b}', '\u{2b1c}'),
+	('\u{2b50}', '\u{2b50}'),
+	('\u{2b55}', '\u{2b55}'),
+	('\u{3030}', '\u{3030}'),
+	('\u{303d}', '\u{303d}'),
+	('\u{3297}', '\u{3297}'),
+	('\u{3299}', '\u{3299}'),
+	('\u{1f000}', '\u{1f0ff}'),
+	('\u{1f10d}', '\u{1f10f}'),
+	('\u{1f12f}', '\u{1f12f}'),
+	('\u{1f16c}', '\u{1f171}'),
+	('\u{1f17e}', '\u{1f17f}'),
+	('\u{1f18e}', '\u{1f18e}'),
+	('\u{1f191}', '\u{1f19a}'),
+	('\u{1f1ad}', '\u{1f1e5}'),
+	('\u{1f201}', '\u{1f20f}'),
+	('\u{1f21a}', '\u{1f21a}'),
+	('\u{1f22f}', '\u{1f22f}'),
+	('\u{1f232}', '\u{1f23a}'),
+	('\u{1f23c}', '\u{1f23f}'),
+	('\u{1f249}', '\u{1f3fa}'),
+	('\u{1f400}', '\u{1f53d}'),
+	('\u{1f546}', '\u{1f64f}'),
+	('\u{1f680}', '\u{1f6ff}'),
+	('\u{1f774}', '\u{1f77f}'),
+	('\u{1f7d5}', '\u{1f7ff}'),
+	('\u{1f80c}', '\u{1f80f}'),
+	('\u{1f848}', '\u{1f84f}'),
+	('\u{1f85a}', '\u{1f85f}'),
+	('\u{1f888}', '\u{1f88f}'),
+	('\u{1f8ae}', '\u{1f8ff}'),
+	('\u{1f90c}', '\u{1f93a}'),
+	('\u{1f93c}', '\u{1f945}'),
+	('\u{1f947}', '\u{1faff}'),
+	('\u{1fc00}', '\u{1fffd}'),
+];
+
+/**
+Values of the `Word_Break` property, other than `Other`.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub enum WordCat {
+	CR,
+	LF,
+	Newline,
+	Extend,
+	ZWJ,
+	RegionalIndicator,
+	Format,
+	Katakana,
+	HebrewLetter,
+	ALetter,
+	SingleQuote,
+	DoubleQuote,
+	MidNumLet,
+	MidLetter,
+	MidNum,
+	Numeric,
+	ExtendNumLet,
+	WSegSpace,
+}
+
+/**
+Ranges of code points and their `Word_Break` property.  Code points which do not appear have the value `Other`.  Sorted, non-overlapping and inclusive.
+*/
+pub static WORD_BREAK: &'static [(char, char, WordCat)] = &[
+	('\u{a}', '\u{a}', WordCat::LF),
+	('\u{b}', '\u{c}', WordCat::Newline),
+	('\u{d}', '\u{d}', WordCat::CR),
+	('\u{20}', '\u{20}', WordCat::WSegSpace),
+	('\u{22}', '\u{22}', WordCat::DoubleQuote),
+	('\u{27}', '\u{27}', WordCat::SingleQuote),
+	('\u{2c}', '\u{2c}', WordCat::MidNum),
+	('\u{2e}', '\u{2e}', WordCat::MidNumLet),
+	('\u{30}', '\u{39}', WordCat::Numeric),
+	('\u{3a}', '\u{3a}', WordCat::MidLetter),
+	('\u{3b}', '\u{3b}', WordCat::MidNum),
+	('\u{41}', '\u{5a}', WordCat::ALetter),
+	('\u{5f}', '\u{5f}', WordCat::ExtendNumLet),
+	('\u{61}', '\u{7a}', WordCat::ALetter),
+	('\u{85}', '\u{85}', WordCat::Newline),
+	('\u{aa}', '\u{aa}', WordCat::ALetter),
+	('\u{ad}', '\u{ad}', WordCat::Format),
+	('\u{b5}', '\u{b5}', WordCat::ALetter),
+	('\u{b7}', '\u{b7}', WordCat::MidLetter),
+	('\u{ba}', '\u{ba}', WordCat::ALetter),
+	('\u{c0}', '\u{d6}', WordCat::ALetter),
+	('\u{d8}', '\u{f6}', WordCat::ALetter),
+	('\u{f8}', '\u{2d7}', WordCat::ALetter),
+	('\u{2de}', '\u{2ff}', WordCat::ALetter),
+	('\u{300}', '\u{36f}', WordCat::Extend),
+	('\u{370}', '\u{374}', WordCat::ALetter),
+	('\u{376}', '\u{377}', WordCat::ALetter),
+	('\u{37a}', '\u{37d}', WordCat::ALetter),
+	('\u{37e}', '\u{37e}', WordCat::MidNum),
+	('\u{37f}', '\u{37f}', WordCat::ALetter),
+	('\u{386}', '\u{386}', WordCat::ALetter),
+	('\u{387}', '\u{387}', WordCat::MidLetter),
+	('\u{388}', '\u{38a}', WordCat::ALetter),
+	('\u{38c}', '\u{38c}', WordCat::ALetter),
+	('\u{38e}', '\u{3a1}', WordCat::ALetter),
+	('\u{3a3}', '\u{3f5}', WordCat::ALetter),
+	('\u{3f7}', '\u{481}', WordCat::ALetter),
+	('\u{483}', '\u{489}', WordCat::Extend),
+	('\u{48a}', '\u{52f}', WordCat::ALetter),
+	('\u{531}', '\u{556}', WordCat::ALetter),
+	('\u{559}', '\u{55c}', WordCat::ALetter),
+	('\u{55e}', '\u{55e}', WordCat::ALetter),
+	('\u{55f}', '\u{55f}', WordCat::MidLetter),
+	('\u{560}', '\u{588}', WordCat::ALetter),
+	('\u{589}', '\u{589}', WordCat::MidNum),
+	('\u{58a}', '\u{58a}', WordCat::ALetter),
+	('\u{591}', '\u{5bd}', WordCat::Extend),
+	('\u{5bf}', '\u{5bf}', WordCat::Extend),
+	('\u{5c1}', '\u{5c2}', WordCat::Extend),
+	('\u{5c4}', '\u{5c5}', WordCat::Extend),
+	('\u{5c7}', '\u{5c7}', WordCat::Extend),
+	('\u{5d0}', '\u{5ea}', WordCat::HebrewLetter),
+	('\u{5ef}', '\u{5f2}', WordCat::HebrewLetter),
+	('\u{5f3}', '\u{5f3}', WordCat::ALetter),
+	('\u{5f4}', '\u{5f4}', WordCat::MidLetter),
+	('\u{600}', '\u{605}', WordCat::Format),
+	('\u{60c}', '\u{60d}', WordCat::MidNum),
+	('\u{610}', '\u{61a}', WordCat::Extend),
+	('\u{61c}', '\u{61c}', WordCat::Format),
+	('\u{620}', '\u{64a}', WordCat::ALetter),
+	('\u{64b}', '\u{65f}', WordCat::Extend),
+	('\u{660}', '\u{669}', WordCat::Numeric),
+	('\u{66b}', '\u{66b}', WordCat::Numeric),
+	('\u{66c}', '\u{66c}', WordCat::MidNum),
+	('\u{66e}', '\u{66f}', WordCat::ALetter),
+	('\u{670}', '\u{670}', WordCat::Extend),
+	('\u{671}', '\u{6d3}', WordCat::ALetter),
+	('\u{6d5}', '\u{6d5}', WordCat::ALetter),
+	('\u{6d6}', '\u{6dc}', WordCat::Extend),
+	('\u{6dd}', '\u{6dd}', WordCat::Format),
+	('\u{6df}', '\u{6e4}', WordCat::Extend),
+	('\u{6e5}', '\u{6e6}', WordCat::ALetter),
+	('\u{6e7}', '\u{6e8}', WordCat::Extend),
+	('\u{6ea}', '\u{6ed}', WordCat::Extend),
+	('\u{6ee}', '\u{6ef}', WordCat::ALetter),
+	('\u{6f0}', '\u{6f9}', WordCat::Numeric),
+	('\u{6fa}', '\u{6fc}', WordCat::ALetter),
+	('\u{6ff}', '\u{6ff}', WordCat::ALetter),
+	('\u{70f}', '\u{70f}', WordCat::Format),
+	('\u{710}', '\u{710}', WordCat::ALetter),
+	('\u{711}', '\u{711}', WordCat::Extend),
+	('\u{712}', '\u{72f}', WordCat::ALetter),
+	('\u{730}', '\u{74a}', WordCat::Extend),
+	('\u{74d}', '\u{7a5}', WordCat::ALetter),
+	('\u{7a6}', '\u{7b0}', WordCat::Extend),
+	('\u{7b1}', '\u{7b1}', WordCat::ALetter),
+	('\u{7c0}', '\u{7c9}', WordCat::Numeric),
+	('\u{7ca}', '\u{7ea}', WordCat::ALetter),
+	('\u{7eb}', '\u{7f3}', WordCat::Extend),
+	('\u{7f4}', '\u{7f5}', WordCat::ALetter),
+	('\u{7f8}', '\u{7f8}', WordCat::MidNum),
+	('\u{7fa}', '\u{7fa}', WordCat::ALetter),
+	('\u{7fd}', '\u{7fd}', WordCat::Extend),
+	('\u{800}', '\u{815}', WordCat::ALetter),
+	('\u{816}', '\u{819}', WordCat::Extend),
+	('\u{81a}', '\u{81a}', WordCat::ALetter),
+	('\u{81b}', '\u{823}', WordCat::Extend),
+	('\u{824}', '\u{824}', WordCat::ALetter),
+	('\u{825}', '\u{827}', WordCat::Extend),
+	('\u{828}', '\u{828}', WordCat::ALetter),
+	('\u{829}', '\u{82d}', WordCat::Extend),
+	('\u{840}', '\u{858}', WordCat::ALetter),
+	('\u{859}', '\u{85b}', WordCat::Extend),
+	('\u{860}', '\u{86a}', WordCat::ALetter),
+	('\u{870}', '\u{887}', WordCat::ALetter),
+	('\u{889}', '\u{88e}', WordCat::ALetter),
+	('\u{890}', '\u{891}', WordCat::Format),
+	('\u{898}', '\u{89f}', WordCat::Extend),
+	('\u{8a0}', '\u{8c9}', WordCat::ALetter),
+	('\u{8ca}', '\u{8e1}', WordCat::Extend),
+	('\u{8e2}', '\u{8e2}', WordCat::Format),
+	('\u{8e3}', '\u{903}', WordCat::Extend),
+	('\u{904}', '\u{939}', WordCat::ALetter),
+	('\u{93a}', '\u{93c}', WordCat::Extend),
+	('\u{93d}', '\u{93d}', WordCat::ALetter),
+	('\u{93e}', '\u{94f}', WordCat::Extend),
+	('\u{950}', '\u{950}', WordCat::ALetter),
+	('\u{951}', '\u{957}', WordCat::Extend),
+	('\u{958}', '\u{961}', WordCat::ALetter),
+	('\u{962}', '\u{963}', WordCat::Extend),
+	('\u{966}', '\u{96f}', WordCat::Numeric),
+	('\u{971}', '\u{980}', WordCat::ALetter),
+	('\u{981}', '\u{983}', WordCat::Extend),
+	('\u{985}', '\u{98c}', WordCat::ALetter),
+	('\u{98f}', '\u{990}', WordCat::ALetter),
+	('\u{993}', '\u{9a8}', WordCat::ALetter),
+	('\u{9aa}', '\u{9b0}', WordCat::ALetter),
+	('\u{9b2}', '\u{9b2}', WordCat::ALetter),
+	('\u{9b6}', '\u{9b9}', WordCat::ALetter),
+	('\u{9bc}', '\u{9bc}', WordCat::Extend),
+	('\u{9bd}', '\u{9bd}', WordCat::ALetter),
+	('\u{9be}', '\u{9c4}', WordCat::Extend),
+	('\u{9c7}', '\u{9c8}', WordCat::Extend),
+	('\u{9cb}', '\u{9cd}', WordCat::Extend),
+	('\u{9ce}', '\u{9ce}', WordCat::ALetter),
+	('\u{9d7}', '\u{9d7}', WordCat::Extend),
+	('\u{9dc}', '\u{9dd}', WordCat::ALetter),
+	('\u{9df}', '\u{9e1}', WordCat::ALetter),
+	('\u{9e2}', '\u{9e3}', WordCat::Extend),
+	('\u{9e6}', '\u{9ef}', WordCat::Numeric),
+	('\u{9f0}', '\u{9f1}', WordCat::ALetter),
+	('\u{9fc}', '\u{9fc}', WordCat::ALetter),
+	('\u{9fe}', '\u{9fe}', WordCat::Extend),
+	('\u{a01}', '\u{a03}', WordCat::Extend),
+	('\u{a05}', '\u{a0a}', WordCat::ALetter),
+	('\u{a0f}', '\u{a10}', WordCat::ALetter),
+	('\u{a13}', '\u{a28}', WordCat::ALetter),
+	('\u{a2a}', '\u{a30}', WordCat::ALetter),
+	('\u{a32}', '\u{a33}', WordCat::ALetter),
+	('\u{a35}', '\u{a36}', WordCat::ALetter),
+	('\u{a38}', '\u{a39}', WordCat::ALetter),
+	('\u{a3c}', '\u{a3c}', WordCat::Extend),
+	('\u{a3e}', '\u{a42}', WordCat::Extend),
+	('\u{a47}', '\u{a48}', WordCat::Extend),
+	('\u{a4b}', '\u{a4d}', WordCat::Extend),
+	('\u{a51}', '\u{a51}', WordCat::Extend),
+	('\u{a59}', '\u{a5c}', WordCat::ALetter),
+	('\u{a5e}', '\u{a5e}', WordCat::ALetter),
+	('\u{a66}', '\u{a6f}', WordCat::Numeric),
+	('\u{a70}', '\u{a71}', WordCat::Extend),
+	('\u{a72}', '\u{a74}', WordCat::ALetter),
+	('\u{a75}', '\u{a75}', WordCat::Extend),
+	('\u{a81}', '\u{a83}', WordCat::Extend),
+	('\u{a85}', '\u{a8d}', WordCat::ALetter),
+	('\u{a8f}', '\u{a91}', WordCat::ALetter),
+	('\u{a93}', '\u{aa8}', WordCat::ALetter),
+	('\u{aaa}', '\u{ab0}', WordCat::ALetter),
+	('\u{ab2}', '\u{ab3}', WordCat::ALetter),
+	('\u{ab5}', '\u{ab9}', WordCat::ALetter),
+	('\u{abc}', '\u{abc}', WordCat::Extend),
+	('\u{abd}', '\u{abd}', WordCat::ALetter),
+	('\u{abe}', '\u{ac5}', WordCat::Extend),
+	('\u{ac7}', '\u{ac9}', WordCat::Extend),
+	('\u{acb}', '\u{acd}', WordCat::Extend),
+	('\u{ad0}', '\u{ad0}', WordCat::ALetter),
+	('\u{ae0}', '\u{ae1}', WordCat::ALetter),
+	('\u{ae2}', '\u{ae3}', WordCat::Extend),
+	('\u{ae6}', '\u{aef}', WordCat::Numeric),
+	('\u{af9}', '\u{af9}', WordCat::ALetter),
+	('\u{afa}', '\u{aff}', WordCat::Extend),
+	('\u{b01}', '\u{b03}', WordCat::Extend),
+	('\u{b05}', '\u{b0c}', WordCat::ALetter),
+	('\u{b0f}', '\u{b10}', WordCat::ALetter),
+	('\u{b13}', '\u{b28}', WordCat::ALetter),
+	('\u{b2a}', '\u{b30}', WordCat::ALetter),
+	('\u{b32}', '\u{b33}', WordCat::ALetter),
+	('\u{b35}', '\u{b39}', WordCat::ALetter),
+	('\u{b3c}', '\u{b3c}', WordCat::Extend),
+	('\u{b3d}', '\u{b3d}', WordCat::ALetter),
+	('\u{b3e}', '\u{b44}', WordCat::Extend),
+	('\u{b47}', '\u{b48}', WordCat::Extend),
+	('\u{b4b}', '\u{b4d}', WordCat::Extend),
+	('\u{b55}', '\u{b57}', WordCat::Extend),
+	('\u{b5c}', '\u{b5d}', WordCat::ALetter),
+	('\u{b5f}', '\u{b61}', WordCat::ALetter),
+	('\u{b62}', '\u{b63}', WordCat::Extend),
+	('\u{b66}', '\u{b6f}', WordCat::Numeric),
+	('\u{b71}', '\u{b71}', WordCat::ALetter),
+	('\u{b82}', '\u{b82}', WordCat::Extend),
+	('\u{b83}', '\u{b83}', WordCat::ALetter),
+	('\u{b85}', '\u{b8a}', WordCat::ALetter),
+	('\u{b8e}', '\u{b90}', WordCat::ALetter),
+	('\u{b92}', '\u{b95}', WordCat::ALetter),
+	('\u{b99}', '\u{b9a}', WordCat::ALetter),
+	('\u{b9c}', '\u{b9c}', WordCat::ALetter),
+	('\u{b9e}', '\u{b9f}', WordCat::ALetter),
+	('\u{ba3}', '\u{ba4}', WordCat::ALetter),
+	('\u{ba8}', '\u{baa}', WordCat::ALetter),
+	('\u{bae}', '\u{bb9}', WordCat::ALetter),
+	('\u{bbe}', '\u{bc2}', WordCat::Extend),
+	('\u{bc6}', '\u{bc8}', WordCat::Extend),
+	('\u{bca}', '\u{bcd}', WordCat::Extend),
+	('\u{bd0}', '\u{bd0}', WordCat::ALetter),
+	('\u{bd7}', '\u{bd7}', WordCat::Extend),
+	('\u{be6}', '\u{bef}', WordCat::Numeric),
+	('\u{c00}', '\u{c04}', WordCat::Extend),
+	('\u{c05}', '\u{c0c}', WordCat::ALetter),
+	('\u{c0e}', '\u{c10}', WordCat::ALetter),
+	('\u{c12}', '\u{c28}', WordCat::ALetter),
+	('\u{c2a}', '\u{c39}', WordCat::ALetter),
+	('\u{c3c}', '\u{c3c}', WordCat::Extend),
+	('\u{c3d}', '\u{c3d}', WordCat::ALetter),
+	('\u{c3e}', '\u{c44}', WordCat::Extend),
+	('\u{c46}', '\u{c48}', WordCat::Extend),
+	('\u{c4a}', '\u{c4d}', WordCat::Extend),
+	('\u{c55}', '\u{c56}', WordCat::Extend),
+	('\u{c58}', '\u{c5a}', WordCat::ALetter),
+	('\u{c5d}', '\u{c5d}', WordCat::ALetter),
+	('\u{c60}', '\u{c61}', WordCat::ALetter),
+	('\u{c62}', '\u{c63}', WordCat::Extend),
+	('\u{c66}', '\u{c6f}', WordCat::Numeric),
+	('\u{c80}', '\u{c80}', WordCat::ALetter),
+	('\u{c81}', '\u{c83}', WordCat::Extend),
+	('\u{c85}', '\u{c8c}', WordCat::ALetter),
+	('\u{c8e}', '\u{c90}', WordCat::ALetter),
+	('\u{c92}', '\u{ca8}', WordCat::ALetter),
+	('\u{caa}', '\u{cb3}', WordCat::ALetter),
+	('\u{cb5}', '\u{cb9}', WordCat::ALetter),
+	('\u{cbc}', '\u{cbc}', WordCat::Extend),
+	('\u{cbd}', '\u{cbd}', WordCat::ALetter),
+	('\u{cbe}', '\u{cc4}', WordCat::Extend),
+	('\u{cc6}', '\u{cc8}', WordCat::Extend),
+	('\u{cca}', '\u{ccd}', WordCat::Extend),
+	('\u{cd5}', '\u{cd6}', WordCat::Extend),
+	('\u{cdd}', '\u{cde}', WordCat::ALetter),
+	('\u{ce0}', '\u{ce1}', WordCat::ALetter),
+	('\u{ce2}', '\u{ce3}', WordCat::Extend),
+	('\u{ce6}', '\u{cef}', WordCat::Numeric),
+	('\u{cf1}', '\u{cf2}', WordCat::ALetter),
+	('\u{d00}', '\u{d03}', WordCat::Extend),
+	('\u{d04}', '\u{d0c}', WordCat::ALetter),
+	('\u{d0e}', '\u{d10}', WordCat::ALetter),
+	('\u{d12}', '\u{d3a}', WordCat::ALetter),
+	('\u{d3b}', '\u{d3c}', WordCat::Extend),
+	('\u{d3d}', '\u{d3d}', WordCat::ALetter),
+	('\u{d3e}', '\u{d44}', WordCat::Extend),
+	('\u{d46}', '\u{d48}', WordCat::Extend),
+	('\u{d4a}', '\u{d4d}', WordCat::Extend),
+	('\u{d4e}', '\u{d4e}', WordCat::ALetter),
+	('\u{d54}', '\u{d56}', WordCat::ALetter),
+	('\u{d57}', '\u{d57}', WordCat::Extend),
+	('\u{d5f}', '\u{d61}', WordCat::ALetter),
+	('\u{d62}', '\u{d63}', WordCat::Extend),
+	('\u{d66}', '\u{d6f}', WordCat::Numeric),
+	('\u{d7a}', '\u{d7f}', WordCat::ALetter),
+	('\u{d81}', '\u{d83}', WordCat::Extend),
+	('\u{d85}', '\u{d96}', WordCat::ALetter),
+	('\u{d9a}', '\u{db1}', WordCat::ALetter),
+	('\u{db3}', '\u{dbb}', WordCat::ALetter),
+	('\u{dbd}', '\u{dbd}', WordCat::ALetter),
+	('\u{dc0}', '\u{dc6}', WordCat::ALetter),
+	('\u{dca}', '\u{dca}', WordCat::Extend),
+	('\u{dcf}', '\u{dd4}', WordCat::Extend),
+	('\u{dd6}', '\u{dd6}', WordCat::Extend),
+	('\u{dd8}', '\u{ddf}', WordCat::Extend),
+	('\u{de6}', '\u{def}', WordCat::Numeric),
+	('\u{df2}', '\u{df3}', WordCat::Extend),
+	('\u{e31}', '\u{e31}', WordCat::Extend),
+	('\u{e34}', '\u{e3a}', WordCat::Extend),
+	('\u{e47}', '\u{e4e}', WordCat::Extend),
+	('\u{e50}', '\u{e59}', WordCat::Numeric),
+	('\u{eb1}', '\u{eb1}', WordCat::Extend),
+	('\u{eb4}', '\u{ebc}', WordCat::Extend),
+	('\u{ec8}', '\u{ecd}', WordCat::Extend),
+	('\u{ed0}', '\u{ed9}', WordCat::Numeric),
+	('\u{f00}', '\u{f00}', WordCat::ALetter),
+	('\u{f18}', '\u{f19}', WordCat::Extend),
+	('\u{f20}', '\u{f29}', WordCat::Numeric),
+	('\u{f35}', '\u{f35}', WordCat::Extend),
+	('\u{f37}', '\u{f37}', WordCat::Extend),
+	('\u{f39}', '\u{f39}', WordCat::Extend),
+	('\u{f3e}', '\u{f3f}', WordCat::Extend),
+	('\u{f40}', '\u{f47}', WordCat::ALetter),
+	('\u{f49}', '\u{f6c}', WordCat::ALetter),
+	('\u{f71}', '\u{f84}', WordCat::Extend),
+	('\u{f86}', '\u{f87}', WordCat::Extend),
+	('\u{f88}', '\u{f8c}', WordCat::ALetter),
+	('\u{f8d}', '\u{f97}', WordCat::Extend),
+	('\u{f99}', '\u{fbc}', WordCat::Extend),
+	('\u{fc6}', '\u{fc6}', WordCat::Extend),
+	('\u{102b}', '\u{103e}', WordCat::Extend),
+	('\u{1040}', '\u{1049}', WordCat::Numeric),
+	('\u{1056}', '\u{1059}', WordCat::Extend),
+	('\u{105e}', '\u{1060}', WordCat::Extend),
+	('\u{1062}', '\u{1064}', WordCat::Extend),
+	('\u{1067}', '\u{106d}', WordCat::Extend),
+	('\u{1071}', '\u{1074}', WordCat::Extend),
+	('\u{1082}', '\u{108d}', WordCat::Extend),
+	('\u{108f}', '\u{108f}', WordCat::Extend),
+	('\u{1090}', '\u{1099}', WordCat::Numeric),
+	('\u{109a}', '\u{109d}', WordCat::Extend),
+	('\u{10a0}', '\u{10c5}', WordCat::ALetter),
+	('\u{10c7}', '\u{10c7}', WordCat::ALetter),
+	('\u{10cd}', '\u{10cd}', WordCat::ALetter),
+	('\u{10d0}', '\u{10fa}', WordCat::ALetter),
+	('\u{10fc}', '\u{1248}', WordCat::ALetter),
+	('\u{124a}', '\u{124d}', WordCat::ALetter),
+	('\u{1250}', '\u{1256}', WordCat::ALetter),
+	('\u{1258}', '\u{1258}', WordCat::ALetter),
+	('\u{125a}', '\u{125d}', WordCat::ALetter),
+	('\u{1260}', '\u{1288}', WordCat::ALetter),
+	('\u{128a}', '\u{128d}', WordCat::ALetter),
+	('\u{1290}', '\u{12b0}', WordCat::ALetter),
+	('\u{12b2}', '\u{12b5}', WordCat::ALetter),
+	('\u{12b8}', '\u{12be}', WordCat::ALetter),
+	('\u{12c0}', '\u{12c0}', WordCat::ALetter),
+	('\u{12c2}', '\u{12c5}', WordCat::ALetter),
+	('\u{12c8}', '\u{12d6}', WordCat::ALetter),
+	('\u{12d8}', '\u{1310}', WordCat::ALetter),
+	('\u{1312}', '\u{1315}', WordCat::ALetter),
+	('\u{1318}', '\u{135a}', WordCat::ALetter),
+	('\u{135d}', '\u{135f}', WordCat::Extend),
+	('\u{1380}', '\u{138f}', WordCat::ALetter),
+	('\u{13a0}', '\u{13f5}', WordCat::ALetter),
+	('\u{13f8}', '\u{13fd}', WordCat::ALetter),
+	('\u{1401}', '\u{166c}', WordCat::ALetter),
+	('\u{166f}', '\u{167f}', WordCat::ALetter),
+	('\u{1680}', '\u{1680}', WordCat::WSegSpace),
+	('\u{1681}', '\u{169a}', WordCat::ALetter),
+	('\u{16a0}', '\u{16ea}', WordCat::ALetter),
+	('\u{16ee}', '\u{16f8}', WordCat::ALetter),
+	('\u{1700}', '\u{1711}', WordCat::ALetter),
+	('\u{1712}', '\u{1715}', WordCat::Extend),
+	('\u{171f}', '\u{1731}', WordCat::ALetter),
+	('\u{1732}', '\u{1734}', WordCat::Extend),
+	('\u{1740}', '\u{1751}', WordCat::ALetter),
+	('\u{1752}', '\u{1753}', WordCat::Extend),
+	('\u{1760}', '\u{176c}', WordCat::ALetter),
+	('\u{176e}', '\u{1770}', WordCat::ALetter),
+	('\u{1772}', '\u{1773}', WordCat::Extend),
+	('\u{17b4}', '\u{17d3}', WordCat::Extend),
+	('\u{17dd}', '\u{17dd}', WordCat::Extend),
+	('\u{17e0}', '\u{17e9}', WordCat::Numeric),
+	('\u{180b}', '\u{180d}', WordCat::Extend),
+	('\u{180e}', '\u{180e}', WordCat::Format),
+	('\u{180f}', '\u{180f}', WordCat::Extend),
+	('\u{1810}', '\u{1819}', WordCat::Numeric),
+	('\u{1820}', '\u{1878}', WordCat::ALetter),
+	('\u{1880}', '\u{1884}', WordCat::ALetter),
+	('\u{1885}', '\u{1886}', WordCat::Extend),
+	('\u{1887}', '\u{18a8}', WordCat::ALetter),
+	('\u{18a9}', '\u{18a9}', WordCat::Extend),
+	('\u{18aa}', '\u{18aa}', WordCat::ALetter),
+	('\u{18b0}', '\u{18f5}', WordCat::ALetter),
+	('\u{1900}', '\u{191e}', WordCat::ALetter),
+	('\u{1920}', '\u{192b}', WordCat::Extend),
+	('\u{1930}', '\u{193b}', WordCat::Extend),
+	('\u{1946}', '\u{194f}', WordCat::Numeric),
+	('\u{19d0}', '\u{19d9}', WordCat::Numeric),
+	('\u{1a00}', '\u{1a16}', WordCat::ALetter),
+	('\u{1a17}', '\u{1a1b}', WordCat::Extend),
+	('\u{1a55}', '\u{1a5e}', WordCat::Extend),
+	('\u{1a60}', '\u{1a7c}', WordCat::Extend),
+	('\u{1a7f}', '\u{1a7f}', WordCat::Extend),
+	('\u{1a80}', '\u{1a89}', WordCat::Numeric),
+	('\u{1a90}', '\u{1a99}', WordCat::Numeric),
+	('\u{1ab0}', '\u{1ace}', WordCat::Extend),
+	('\u{1b00}', '\u{1b04}', WordCat::Extend),
+	('\u{1b05}', '\u{1b33}', WordCat::ALetter),
+	('\u{1b34}', '\u{1b44}', WordCat::Extend),
+	('\u{1b45}', '\u{1b4c}', WordCat::ALetter),
+	('\u{1b50}', '\u{1b59}', WordCat::Numeric),
+	('\u{1b6b}', '\u{1b73}', WordCat::Extend),
+	('\u{1b80}', '\u{1b82}', WordCat::Extend),
+	('\u{1b83}', '\u{1ba0}', WordCat::ALetter),
+	('\u{1ba1}', '\u{1bad}', WordCat::Extend),
+	('\u{1bae}', '\u{1baf}', WordCat::ALetter),
+	('\u{1bb0}', '\u{1bb9}', WordCat::Numeric),
+	('\u{1bba}', '\u{1be5}', WordCat::ALetter),
+	('\u{1be6}', '\u{1bf3}', WordCat::Extend),
+	('\u{1c00}', '\u{1c23}', WordCat::ALetter),
+	('\u{1c24}', '\u{1c37}', WordCat::Extend),
+	('\u{1c40}', '\u{1c49}', WordCat::Numeric),
+	('\u{1c4d}', '\u{1c4f}', WordCat::ALetter),
+	('\u{1c50}', '\u{1c59}', WordCat::Numeric),
+	('\u{1c5a}', '\u{1c7d}', WordCat::ALetter),
+	('\u{1c80}', '\u{1c88}', WordCat::ALetter),
+	('\u{1c90}', '\u{1cba}', WordCat::ALetter),
+	('\u{1cbd}', '\u{1cbf}', WordCat::ALetter),
+	('\u{1cd0}', '\u{1cd2}', WordCat::Extend),
+	('\u{1cd4}', '\u{1ce8}', WordCat::Extend),
+	('\u{1ce9}', '\u{1cec}', WordCat::ALetter),
+	('\u{1ced}', '\u{1ced}', WordCat::Extend),
+	('\u{1cee}', '\u{1cf3}', WordCat::ALetter),
+	('\u{1cf4}', '\u{1cf4}', WordCat::Extend),
+	('\u{1cf5}', '\u{1cf6}', WordCat::ALetter),
+	('\u{1cf7}', '\u{1cf9}', WordCat::Extend),
+	('\u{1cfa}', '\u{1cfa}', WordCat::ALetter),
+	('\u{1d00}', '\u{1dbf}', WordCat::ALetter),
+	('\u{1dc0}', '\u{1dff}', WordCat::Extend),
+	('\u{1e00}', '\u{1f15}', WordCat::ALetter),
+	('\u{1f18}', '\u{1f1d}', WordCat::ALetter),
+	('\u{1f20}', '\u{1f45}', WordCat::ALetter),
+	('\u{1f48}', '\u{1f4d}', WordCat::ALetter),
+	('\u{1f50}', '\u{1f57}', WordCat::ALetter),
+	('\u{1f59}', '\u{1f59}', WordCat::ALetter),
+	('\u{1f5b}', '\u{1f5b}', WordCat::ALetter),
+	('\u{1f5d}', '\u{1f5d}', WordCat::ALetter),
+	('\u{1f5f}', '\u{1f7d}', WordCat::ALetter),
+	('\u{1f80}', '\u{1fb4}', WordCat::ALetter),
+	('\u{1fb6}', '\u{1fbc}', WordCat::ALetter),
+	('\u{1fbe}', '\u{1fbe}', WordCat::ALetter),
+	('\u{1fc2}', '\u{1fc4}', WordCat::ALetter),
+	('\u{1fc6}', '\u{1fcc}', WordCat::ALetter),
+	('\u{1fd0}', '\u{1fd3}', WordCat::ALetter),
+	('\u{1fd6}', '\u{1fdb}', WordCat::ALetter),
+	('\u{1fe0}', '\u{1fec}', WordCat::ALetter),
+	('\u{1ff2}', '\u{1ff4}', WordCat::ALetter),
+	('\u{1ff6}', '\u{1ffc}', WordCat::ALetter),
+	('\u{2000}', '\u{2006}', WordCat::WSegSpace),
+	('\u{2008}', '\u{200a}', WordCat::WSegSpace),
+	('\u{200c}', '\u{200c}', WordCat::Extend),
+	('\u{200d}', '\u{200d}', WordCat::ZWJ),
+	('\u{200e}', '\u{200f}', WordCat::Format),
+	('\u{2018}', '\u{2019}', WordCat::MidNumLet),
+	('\u{2024}', '\u{2024}', WordCat::MidNumLet),
+	('\u{2027}', '\u{2027}', WordCat::MidLetter),
+	('\u{2028}', '\u{2029}', WordCat::Newline),
+	('\u{202a}', '\u{202e}', WordCat::Format),
+	('\u{202f}', '\u{202f}', WordCat::ExtendNumLet),
+	('\u{203f}', '\u{2040}', WordCat::ExtendNumLet),
+	('\u{2044}', '\u{2044}', WordCat::MidNum),
+	('\u{2054}', '\u{2054}', WordCat::ExtendNumLet),
+	('\u{205f}', '\u{205f}', WordCat::WSegSpace),
+	('\u{2060}', '\u{2064}', WordCat::Format),
+	('\u{2066}', '\u{206f}', WordCat::Format),
+	('\u{2071}', '\u{2071}', WordCat::ALetter),
+	('\u{207f}', '\u{207f}', WordCat::ALetter),
+	('\u{2090}', '\u{209c}', WordCat::ALetter),
+	('\u{20d0}', '\u{20f0}', WordCat::Extend),
+	('\u{2102}', '\u{2102}', WordCat::ALetter),
+	('\u{2107}', '\u{2107}', WordCat::ALetter),
+	('\u{210a}', '\u{2113}', WordCat::ALetter),
+	('\u{2115}', '\u{2115}', WordCat::ALetter),
+	('\u{2119}', '\u{211d}', WordCat::ALetter),
+	('\u{2124}', '\u{2124}', WordCat::ALetter),
+	('\u{2126}', '\u{2126}', WordCat::ALetter),
+	('\u{2128}', '\u{2128}', WordCat::ALetter),
+	('\u{212a}', '\u{212d}', WordCat::ALetter),
+	('\u{212f}', '\u{2139}', WordCat::ALetter),
+	('\u{213c}', '\u{213f}', WordCat::ALetter),
+	('\u{2145}', '\u{2149}', WordCat::ALetter),
+	('\u{214e}', '\u{214e}', WordCat::ALetter),
+	('\u{2160}', '\u{2188}', WordCat::ALetter),
+	('\u{24b6}', '\u{24e9}', WordCat::ALetter),
+	('\u{2c00}', '\u{2ce4}', WordCat::ALetter),
+	('\u{2ceb}', '\u{2cee}', WordCat::ALetter),
+	('\u{2cef}', '\u{2cf1}', WordCat::Extend),
+	('\u{2cf2}', '\u{2cf3}', WordCat::ALetter),
+	('\u{2d00}', '\u{2d25}', WordCat::ALetter),
+	('\u{2d27}', '\u{2d27}', WordCat::ALetter),
+	('\u{2d2d}', '\u{2d2d}', WordCat::ALetter),
+	('\u{2d30}', '\u{2d67}', WordCat::ALetter),
+	('\u{2d6f}', '\u{2d6f}', WordCat::ALetter),
+	('\u{2d7f}', '\u{2d7f}', WordCat::Extend),
+	('\u{2d80}', '\u{2d96}', WordCat::ALetter),
+	('\u{2da0}', '\u{2da6}', WordCat::ALetter),
+	('\u{2da8}', '\u{2dae}', WordCat::ALetter),
+	('\u{2db0}', '\u{2db6}', WordCat::ALetter),
+	('\u{2db8}', '\u{2dbe}', WordCat::ALetter),
+	('\u{2dc0}', '\u{2dc6}', WordCat::ALetter),
+	('\u{2dc8}', '\u{2dce}', WordCat::ALetter),
+	('\u{2dd0}', '\u{2dd6}', WordCat::ALetter),
+	('\u{2dd8}', '\u{2dde}', WordCat::ALetter),
+	('\u{2de0}', '\u{2dff}', WordCat::Extend),
+	('\u{2e2f}', '\u{2e2f}', WordCat::ALetter),
+	('\u{3000}', '\u{3000}', WordCat::WSegSpace),
+	('\u{3005}', '\u{3005}', WordCat::ALetter),
+	('\u{302a}', '\u{302f}', WordCat::Extend),
+	('\u{3031}', '\u{3035}', WordCat::Katakana),
+	('\u{303b}', '\u{303c}', WordCat::ALetter),
+	('\u{3099}', '\u{309a}', WordCat::Extend),
+	('\u{309b}', '\u{309c}', WordCat::Katakana),
+	('\u{30a0}', '\u{30fa}', WordCat::Katakana),
+	('\u{30fc}', '\u{30ff}', WordCat::Katakana),
+	('\u{3105}', '\u{312f}', WordCat::ALetter),
+	('\u{3131}', '\u{318e}', WordCat::ALetter),
+	('\u{31a0}', '\u{31bf}', WordCat::ALetter),
+	('\u{31f0}', '\u{31ff}', WordCat::Katakana),
+	('\u{32d0}', '\u{32fe}', WordCat::Katakana),
+	('\u{3300}', '\u{3357}', WordCat::Katakana),
+	('\u{a000}', '\u{a48c}', WordCat::ALetter),
+	('\u{a4d0}', '\u{a4fd}', WordCat::ALetter),
+	('\u{a500}', '\u{a60c}', WordCat::ALetter),
+	('\u{a610}', '\u{a61f}', WordCat::ALetter),
+	('\u{a620}', '\u{a629}', WordCat::Numeric),
+	('\u{a62a}', '\u{a62b}', WordCat::ALetter),
+	('\u{a640}', '\u{a66e}', WordCat::ALetter),
+	('\u{a66f}', '\u{a672}', WordCat::Extend),
+	('\u{a674}', '\u{a67d}', WordCat::Extend),
+	('\u{a67f}', '\u{a69d}', WordCat::ALetter),
+	('\u{a69e}', '\u{a69f}', WordCat::Extend),
+	('\u{a6a0}', '\u{a6ef}', WordCat::ALetter),
+	('\u{a6f0}', '\u{a6f1}', WordCat::Extend),
+	('\u{a708}', '\u{a7ca}', WordCat::ALetter),
+	('\u{a7d0}', '\u{a7d1}', WordCat::ALetter),
+	('\u{a7d3}', '\u{a7d3}', WordCat::ALetter),
+	('\u{a7d5}', '\u{a7d9}', WordCat::ALetter),
+	('\u{a7f2}', '\u{a801}', WordCat::ALetter),
+	('\u{a802}', '\u{a802}', WordCat::Extend),
+	('\u{a803}', '\u{a805}', WordCat::ALetter),
+	('\u{a806}', '\u{a806}', WordCat::Extend),
+	('\u{a807}', '\u{a80a}', WordCat::ALetter),
+	('\u{a80b}', '\u{a80b}', WordCat::Extend),
+	('\u{a80c}', '\u{a822}', WordCat::ALetter),
+	('\u{a823}', '\u{a827}', WordCat::Extend),
+	('\u{a82c}', '\u{a82c}', WordCat::Extend),
+	('\u{a840}', '\u{a873}', WordCat::ALetter),
+	('\u{a880}', '\u{a881}', WordCat::Extend),
+	('\u{a882}', '\u{a8b3}', WordCat::ALetter),
+	('\u{a8b4}', '\u{a8c5}', WordCat::Extend),
+	('\u{a8d0}', '\u{a8d9}', WordCat::Numeric),
+	('\u{a8e0}', '\u{a8f1}', WordCat::Extend),
+	('\u{a8f2}', '\u{a8f7}', WordCat::ALetter),
+	('\u{a8fb}', '\u{a8fb}', WordCat::ALetter),
+	('\u{a8fd}', '\u{a8fe}', WordCat::ALetter),
+	('\u{a8ff}', '\u{a8ff}', WordCat::Extend),
+	('\u{a900}', '\u{a909}', WordCat::Numeric),
+	('\u{a90a}', '\u{a925}', WordCat::ALetter),
+	('\u{a926}', '\u{a92d}', WordCat::Extend),
+	('\u{a930}', '\u{a946}', WordCat::ALetter),
+	('\u{a947}', '\u{a953}', WordCat::Extend),
+	('\u{a960}', '\u{a97c}', WordCat::ALetter),
+	('\u{a980}', '\u{a983}', WordCat::Extend),
+	('\u{a984}', '\u{a9b2}', WordCat::ALetter),
+	('\u{a9b3}', '\u{a9c0}', WordCat::Extend),
+	('\u{a9cf}', '\u{a9cf}', WordCat::ALetter),
+	('\u{a9d0}', '\u{a9d9}', WordCat::Numeric),
+	('\u{a9e5}', '\u{a9e5}', WordCat::Extend),
+	('\u{a9f0}', '\u{a9f9}', WordCat::Numeric),
+	('\u{aa00}', '\u{aa28}', WordCat::ALetter),
+	('\u{aa29}', '\u{aa36}', WordCat::Extend),
+	('\u{aa40}', '\u{aa42}', WordCat::ALetter),
+	('\u{aa43}', '\u{aa43}', WordCat::Extend),
+	('\u{aa44}', '\u{aa4b}', WordCat::ALetter),
+	('\u{aa4c}', '\u{aa4d}', WordCat::Extend),
+	('\u{aa50}', '\u{aa59}', WordCat::Numeric),
+	('\u{aa7b}', '\u{aa7d}', WordCat::Extend),
+	('\u{aab0}', '\u{aab0}', WordCat::Extend),
+	('\u{aab2}', '\u{aab4}', WordCat::Extend),
+	('\u{aab7}', '\u{aab8}', WordCat::Extend),
+	('\u{aabe}', '\u{aabf}', WordCat::Extend),
+	('\u{aac1}', '\u{aac1}', WordCat::Extend),
+	('\u{aae0}', '\u{aaea}', WordCat::ALetter),
+	('\u{aaeb}', '\u{aaef}', WordCat::Extend),
+	('\u{aaf2}', '\u{aaf4}', WordCat::ALetter),
+	('\u{aaf5}', '\u{aaf6}', WordCat::Extend),
+	('\u{ab01}', '\u{ab06}', WordCat::ALetter),
+	('\u{ab09}', '\u{ab0e}', WordCat::ALetter),
+	('\u{ab11}', '\u{ab16}', WordCat::ALetter),
+	('\u{ab20}', '\u{ab26}', WordCat::ALetter),
+	('\u{ab28}', '\u{ab2e}', WordCat::ALetter),
+	('\u{ab30}', '\u{ab69}', WordCat::ALetter),
+	('\u{ab70}', '\u{abe2}', WordCat::ALetter),
+	('\u{abe3}', '\u{abea}', WordCat::Extend),
+	('\u{abec}', '\u{abed}', WordCat::Extend),
+	('\u{abf0}', '\u{abf9}', WordCat::Numeric),
+	('\u{ac00}', '\u{d7a3}', WordCat::ALetter),
+	('\u{d7b0}', '\u{d7c6}', WordCat::ALetter),
+	('\u{d7cb}', '\u{d7fb}', WordCat::ALetter),
+	('\u{fb00}', '\u{fb06}', WordCat::ALetter),
+	('\u{fb13}', '\u{fb17}', WordCat::ALetter),
+	('\u{fb1d}', '\u{fb1d}', WordCat::HebrewLetter),
+	('\u{fb1e}', '\u{fb1e}', WordCat::Extend),
+	('\u{fb1f}', '\u{fb28}', WordCat::HebrewLetter),
+	('\u{fb2a}', '\u{fb36}', WordCat::HebrewLetter),
+	('\u{fb38}', '\u{fb3c}', WordCat::HebrewLetter),
+	('\u{fb3e}', '\u{fb3e}', WordCat::HebrewLetter),
+	('\u{fb40}', '\u{fb41}', WordCat::HebrewLetter),
+	('\u{fb43}', '\u{fb44}', WordCat::HebrewLetter),
+	('\u{fb46}', '\u{fb4f}', WordCat::HebrewLetter),
+	('\u{fb50}', '\u{fbb1}', WordCat::ALetter),
+	('\u{fbd3}', '\u{fd3d}', WordCat::ALetter),
+	('\u{fd50}', '\u{fd8f}', WordCat::ALetter),
+	('\u{fd92}', '\u{fdc7}', WordCat::ALetter),
+	('\u{fdf0}', '\u{fdfb}', WordCat::ALetter),
+	('\u{fe00}', '\u{fe0f}', WordCat::Extend),
+	('\u{fe10}', '\u{fe10}', WordCat::MidNum),
+	('\u{fe13}', '\u{fe13}', WordCat::MidLetter),
+	('\u{fe14}', '\u{fe14}', WordCat::MidNum),
+	('\u{fe20}', '\u{fe2f}', WordCat::Extend),
+	('\u{fe33}', '\u{fe34}', WordCat::ExtendNumLet),
+	('\u{fe4d}', '\u{fe4f}', WordCat::ExtendNumLet),
+	('\u{fe50}', '\u{fe50}', WordCat::MidNum),
+	('\u{fe52}', '\u{fe52}', WordCat::MidNumLet),
+	('\u{fe54}', '\u{fe54}', WordCat::MidNum),
+	('\u{fe55}', '\u{fe55}', WordCat::MidLetter),
+	('\u{fe70}', '\u{fe74}', WordCat::ALetter),
+	('\u{fe76}', '\u{fefc}', WordCat::ALetter),
+	('\u{feff}', '\u{feff}', WordCat::Format),
+	('\u{ff07}', '\u{ff07}', WordCat::MidNumLet),
+	('\u{ff0c}', '\u{ff0c}', WordCat::MidNum),
+	('\u{ff0e}', '\u{ff0e}', WordCat::MidNumLet),
+	('\u{ff10}', '\u{ff19}', WordCat::Numeric),
+	('\u{ff1a}', '\u{ff1a}', WordCat::MidLetter),
+	('\u{ff1b}', '\u{ff1b}', WordCat::MidNum),
+	('\u{ff21}', '\u{ff3a}', WordCat::ALetter),
+	('\u{ff3f}', '\u{ff3f}', WordCat::ExtendNumLet),
+	('\u{ff41}', '\u{ff5a}', WordCat::ALetter),
+	('\u{ff66}', '\u{ff9d}', WordCat::Katakana),
+	('\u{ff9e}', '\u{ff9f}', WordCat::Extend),
+	('\u{ffa0}', '\u{ffbe}', WordCat::ALetter),
+	('\u{ffc2}', '\u{ffc7}', WordCat::ALetter),
+	('\u{ffca}', '\u{ffcf}', WordCat::ALetter),
+	('\u{ffd2}', '\u{ffd7}', WordCat::ALetter),
+	('\u{ffda}', '\u{ffdc}', WordCat::ALetter),
+	('\u{fff9}', '\u{fffb}', WordCat::Format),
+	('\u{10000}', '\u{1000b}', WordCat::ALetter),
+	('\u{1000d}', '\u{10026}', WordCat::ALetter),
+	('\u{10028}', '\u{1003a}', WordCat::ALetter),
+	('\u{1003c}', '\u{1003d}', WordCat::ALetter),
+	('\u{1003f}', '\u{1004d}', WordCat::ALetter),
+	('\u{10050}', '\u{1005d}', WordCat::ALetter),
+	('\u{10080}', '\u{100fa}', WordCat::ALetter),
+	('\u{10140}', '\u{10174}', WordCat::ALetter),
+	('\u{101fd}', '\u{101fd}', WordCat::Extend),
+	('\u{10280}', '\u{1029c}', WordCat::ALetter),
+	('\u{102a0}', '\u{102d0}', WordCat::ALetter),
+	('\u{102e0}', '\u{102e0}', WordCat::Extend),
+	('\u{10300}', '\u{1031f}', WordCat::ALetter),
+	('\u{1032d}', '\u{1034a}', WordCat::ALetter),
+	('\u{10350}', '\u{10375}', WordCat::ALetter),
+	('\u{10376}', '\u{1037a}', WordCat::Extend),
+	('\u{10380}', '\u{1039d}', WordCat::ALetter),
+	('\u{103a0}', '\u{103c3}', WordCat::ALetter),
+	('\u{103c8}', '\u{103cf}', WordCat::ALetter),
+	('\u{103d1}', '\u{103d5}', WordCat::ALetter),
+	('\u{10400}', '\u{1049d}', WordCat::ALetter),
+	('\u{104a0}', '\u{104a9}', WordCat::Numeric),
+	('\u{104b0}', '\u{104d3}', WordCat::ALetter),
+	('\u{104d8}', '\u{104fb}', WordCat::ALetter),
+	('\u{10500}', '\u{10527}', WordCat::ALetter),
+	('\u{10530}', '\u{10563}', WordCat::ALetter),
+	('\u{10570}', '\u{1057a}', WordCat::ALetter),
+	('\u{1057c}', '\u{1058a}', WordCat::ALetter),
+	('\u{1058c}', '\u{10592}', WordCat::ALetter),
+	('\u{10594}', '\u{10595}', WordCat::ALetter),
+	('\u{10597}', '\u{105a1}', WordCat::ALetter),
+	('\u{105a3}', '\u{105b1}', WordCat::ALetter),
+	('\u{105b3}', '\u{105b9}', WordCat::ALetter),
+	('\u{105bb}', '\u{105bc}', WordCat::ALetter),
+	('\u{10600}', '\u{10736}', WordCat::ALetter),
+	('\u{10740}', '\u{10755}', WordCat::ALetter),
+	('\u{10760}', '\u{10767}', WordCat::ALetter),
+	('\u{10780}', '\u{10785}', WordCat::ALetter),
+	('\u{10787}', '\u{107b0}', WordCat::ALetter),
+	('\u{107b2}', '\u{107ba}', WordCat::ALetter),
+	('\u{10800}', '\u{10805}', WordCat::ALetter),
+	('\u{10808}', '\u{10808}', WordCat::ALetter),
+	('\u{1080a}', '\u{10835}', WordCat::ALetter),
+	('\u{10837}', '\u{10838}', WordCat::ALetter),
+	('\u{1083c}', '\u{1083c}', WordCat::ALetter),
+	('\u{1083f}', '\u{10855}', WordCat::ALetter),
+	('\u{10860}', '\u{10876}', WordCat::ALetter),
+	('\u{10880}', '\u{1089e}', WordCat::ALetter),
+	('\u{108e0}', '\u{108f2}', WordCat::ALetter),
+	('\u{108f4}', '\u{108f5}', WordCat::ALetter),
+	('\u{10900}', '\u{10915}', WordCat::ALetter),
+	('\u{10920}', '\u{10939}', WordCat::ALetter),
+	('\u{10980}', '\u{109b7}', WordCat::ALetter),
+	('\u{109be}', '\u{109bf}', WordCat::ALetter),
+	('\u{10a00}', '\u{10a00}', WordCat::ALetter),
+	('\u{10a01}', '\u{10a03}', WordCat::Extend),
+	('\u{10a05}', '\u{10a06}', WordCat::Extend),
+	('\u{10a0c}', '\u{10a0f}', WordCat::Extend),
+	('\u{10a10}', '\u{10a13}', WordCat::ALetter),
+	('\u{10a15}', '\u{10a17}', WordCat::ALetter),
+	('\u{10a19}', '\u{10a35}', WordCat::ALetter),
+	('\u{10a38}', '\u{10a3a}', WordCat::Extend),
+	('\u{10a3f}', '\u{10a3f}', WordCat::Extend),
+	('\u{10a60}', '\u{10a7c}', WordCat::ALetter),
+	('\u{10a80}', '\u{10a9c}', WordCat::ALetter),
+	('\u{10ac0}', '\u{10ac7}', WordCat::ALetter),
+	('\u{10ac9}', '\u{10ae4}', WordCat::ALetter),
+	('\u{10ae5}', '\u{10ae6}', WordCat::Extend),
+	('\u{10b00}', '\u{10b35}', WordCat::ALetter),
+	('\u{10b40}', '\u{10b55}', WordCat::ALetter),
+	('\u{10b60}', '\u{10b72}', WordCat::ALetter),
+	('\u{10b80}', '\u{10b91}', WordCat::ALetter),
+	('\u{10c00}', '\u{10c48}', WordCat::ALetter),
+	('\u{10c80}', '\u{10cb2}', WordCat::ALetter),
+	('\u{10cc0}', '\u{10cf2}', WordCat::ALetter),
+	('\u{10d00}', '\u{10d23}', WordCat::ALetter),
+	('\u{10d24}', '\u{10d27}', WordCat::Extend),
+	('\u{10d30}', '\u{10d39}', WordCat::Numeric),
+	('\u{10e80}', '\u{10ea9}', WordCat::ALetter),
+	('\u{10eab}', '\u{10eac}', WordCat::Extend),
+	('\u{10eb0}', '\u{10eb1}', WordCat::ALetter),
+	('\u{10f00}', '\u{10f1c}', WordCat::ALetter),
+	('\u{10f27}', '\u{10f27}', WordCat::ALetter),
+	('\u{10f30}', '\u{10f45}', WordCat::ALetter),
+	('\u{10f46}', '\u{10f50}', WordCat::Extend),
+	('\u{10f70}', '\u{10f81}', WordCat::ALetter),
+	('\u{10f82}', '\u{10f85}', WordCat::Extend),
+	('\u{10fb0}', '\u{10fc4}', WordCat::ALetter),
+	('\u{10fe0}', '\u{10ff6}', WordCat::ALetter),
+	('\u{11000}', '\u{11002}', WordCat::Extend),
+	('\u{11003}', '\u{11037}', WordCat::ALetter),
+	('\u{11038}', '\u{11046}', WordCat::Extend),
+	('\u{11066}', '\u{1106f}', WordCat::Numeric),
+	('\u{11070}', '\u{11070}', WordCat::Extend),
+	('\u{11071}', '\u{11072}', WordCat::ALetter),
+	('\u{11073}', '\u{11074}', WordCat::Extend),
+	('\u{11075}', '\u{11075}', WordCat::ALetter),
+	('\u{1107f}', '\u{11082}', WordCat::Extend),
+	('\u{11083}', '\u{110af}', WordCat::ALetter),
+	('\u{110b0}', '\u{110ba}', WordCat::Extend),
+	('\u{110bd}', '\u{110bd}', WordCat::Format),
+	('\u{110c2}', '\u{110c2}', WordCat::Extend),
+	('\u{110cd}', '\u{110cd}', WordCat::Format),
+	('\u{110d0}', '\u{110e8}', WordCat::ALetter),
+	('\u{110f0}', '\u{110f9}', WordCat::Numeric),
+	('\u{11100}', '\u{11102}', WordCat::Extend),
+	('\u{11103}', '\u{11126}', WordCat::ALetter),
+	('\u{11127}', '\u{11134}', WordCat::Extend),
+	('\u{11136}', '\u{1113f}', WordCat::Numeric),
+	('\u{11144}', '\u{11144}', WordCat::ALetter),
+	('\u{11145}', '\u{11146}', WordCat::Extend),
+	('\u{11147}', '\u{11147}', WordCat::ALetter),
+	('\u{11150}', '\u{11172}', WordCat::ALetter),
+	('\u{11173}', '\u{11173}', WordCat::Extend),
+	('\u{11176}', '\u{11176}', WordCat::ALetter),
+	('\u{11180}', '\u{11182}', WordCat::Extend),
+	('\u{11183}', '\u{111b2}', WordCat::ALetter),
+	('\u{111b3}', '\u{111c0}', WordCat::Extend),
+	('\u{111c1}', '\u{111c4}', WordCat::ALetter),
+	('\u{111c9}', '\u{111cc}', WordCat::Extend),
+	('\u{111ce}', '\u{111cf}', WordCat::Extend),
+	('\u{111d0}', '\u{111d9}', WordCat::Numeric),
+	('\u{111da}', '\u{111da}', WordCat::ALetter),
+	('\u{111dc}', '\u{111dc}', WordCat::ALetter),
+	('\u{11200}', '\u{11211}', WordCat::ALetter),
+	('\u{11213}', '\u{1122b}', WordCat::ALetter),
+	('\u{1122c}', '\u{11237}', WordCat::Extend),
+	('\u{1123e}', '\u{1123e}', WordCat::Extend),
+	('\u{11280}', '\u{11286}', WordCat::ALetter),
+	('\u{11288}', '\u{11288}', WordCat::ALetter),
+	('\u{1128a}', '\u{1128d}', WordCat::ALetter),
+	('\u{1128f}', '\u{1129d}', WordCat::ALetter),
+	('\u{1129f}', '\u{112a8}', WordCat::ALetter),
+	('\u{112b0}', '\u{112de}', WordCat::ALetter),
+	('\u{112df}', '\u{112ea}', WordCat::Extend),
+	('\u{112f0}', '\u{112f9}', WordCat::Numeric),
+	('\u{11300}', '\u{11303}', WordCat::Extend),
+	('\u{11305}', '\u{1130c}', WordCat::ALetter),
+	('\u{1130f}', '\u{11310}', WordCat::ALetter),
+	('\u{11313}', '\u{11328}', WordCat::ALetter),
+	('\u{1132a}', '\u{11330}', WordCat::ALetter),
+	('\u{11332}', '\u{11333}', WordCat::ALetter),
+	('\u{11335}', '\u{11339}', WordCat::ALetter),
+	('\u{1133b}', '\u{1133c}', WordCat::Extend),
+	('\u{1133d}', '\u{1133d}', WordCat::ALetter),
+	('\u{1133e}', '\u{11344}', WordCat::Extend),
+	('\u{11347}', '\u{11348}', WordCat::Extend),
+	('\u{1134b}', '\u{1134d}', WordCat::Extend),
+	('\u{11350}', '\u{11350}', WordCat::ALetter),
+	('\u{11357}', '\u{11357}', WordCat::Extend),
+	('\u{1135d}', '\u{11361}', WordCat::ALetter),
+	('\u{11362}', '\u{11363}', WordCat::Extend),
+	('\u{11366}', '\u{1136c}', WordCat::Extend),
+	('\u{11370}', '\u{11374}', WordCat::Extend),
+	('\u{11400}', '\u{11434}', WordCat::ALetter),
+	('\u{11435}', '\u{11446}', WordCat::Extend),
+	('\u{11447}', '\u{1144a}', WordCat::ALetter),
+	('\u{11450}', '\u{11459}', WordCat::Numeric),
+	('\u{1145e}', '\u{1145e}', WordCat::Extend),
+	('\u{1145f}', '\u{11461}', WordCat::ALetter),
+	('\u{11480}', '\u{114af}', WordCat::ALetter),
+	('\u{114b0}', '\u{114c3}', WordCat::Extend),
+	('\u{114c4}', '\u{114c5}', WordCat::ALetter),
+	('\u{114c7}', '\u{114c7}', WordCat::ALetter),
+	('\u{114d0}', '\u{114d9}', WordCat::Numeric),
+	('\u{11580}', '\u{115ae}', WordCat::ALetter),
+	('\u{115af}', '\u{115b5}', WordCat::Extend),
+	('\u{115b8}', '\u{115c0}', WordCat::Extend),
+	('\u{115d8}', '\u{115db}', WordCat::ALetter),
+	('\u{115dc}', '\u{115dd}', WordCat::Extend),
+	('\u{11600}', '\u{1162f}', WordCat::ALetter),
+	('\u{11630}', '\u{11640}', WordCat::Extend),
+	('\u{11644}', '\u{11644}', WordCat::ALetter),
+	('\u{11650}', '\u{11659}', WordCat::Numeric),
+	('\u{11680}', '\u{116aa}', WordCat::ALetter),
+	('\u{116ab}', '\u{116b7}', WordCat::Extend),
+	('\u{116b8}', '\u{116b8}', WordCat::ALetter),
+	('\u{116c0}', '\u{116c9}', WordCat::Numeric),
+	('\u{1171d}', '\u{1172b}', WordCat::Extend),
+	('\u{11730}', '\u{11739}', WordCat::Numeric),
+	('\u{11800}', '\u{1182b}', WordCat::ALetter),
+	('\u{1182c}', '\u{1183a}', WordCat::Extend),
+	('\u{118a0}', '\u{118df}', WordCat::ALetter),
+	('\u{118e0}', '\u{118e9}', WordCat::Numeric),
+	('\u{118ff}', '\u{11906}', WordCat::ALetter),
+	('\u{11909}', '\u{11909}', WordCat::ALetter),
+	('\u{1190c}', '\u{11913}', WordCat::ALetter),
+	('\u{11915}', '\u{11916}', WordCat::ALetter),
+	('\u{11918}', '\u{1192f}', WordCat::ALetter),
+	('\u{11930}', '\u{11935}', WordCat::Extend),
+	('\u{11937}', '\u{11938}', WordCat::Extend),
+	('\u{1193b}', '\u{1193e}', WordCat::Extend),
+	('\u{1193f}', '\u{1193f}', WordCat::ALetter),
+	('\u{11940}', '\u{11940}', WordCat::Extend),
+	('\u{11941}', '\u{11941}', WordCat::ALetter),
+	('\u{11942}', '\u{11943}', WordCat::Extend),
+	('\u{11950}', '\u{11959}', WordCat::Numeric),
+	('\u{119a0}', '\u{119a7}', WordCat::ALetter),
+	('\u{119aa}', '\u{119d0}', WordCat::ALetter),
+	('\u{119d1}', '\u{119d7}', WordCat::Extend),
+	('\u{119da}', '\u{119e0}', WordCat::Extend),
+	('\u{119e1}', '\u{119e1}', WordCat::ALetter),
+	('\u{119e3}', '\u{119e3}', WordCat::ALetter),
+	('\u{119e4}', '\u{119e4}', WordCat::Extend),
+	('\u{11a00}', '\u{11a00}', WordCat::ALetter),
+	('\u{11a01}', '\u{11a0a}', WordCat::Extend),
+	('\u{11a0b}', '\u{11a32}', WordCat::ALetter),
+	('\u{11a33}', '\u{11a39}', WordCat::Extend),
+	('\u{11a3a}', '\u{11a3a}', WordCat::ALetter),
+	('\u{11a3b}', '\u{11a3e}', WordCat::Extend),
+	('\u{11a47}', '\u{11a47}', WordCat::Extend),
+	('\u{11a50}', '\u{11a50}', WordCat::ALetter),
+	('\u{11a51}', '\u{11a5b}', WordCat::Extend),
+	('\u{11a5c}', '\u{11a89}', WordCat::ALetter),
+	('\u{11a8a}', '\u{11a99}', WordCat::Extend),
+	('\u{11a9d}', '\u{11a9d}', WordCat::ALetter),
+	('\u{11ab0}', '\u{11af8}', WordCat::ALetter),
+	('\u{11c00}', '\u{11c08}', WordCat::ALetter),
+	('\u{11c0a}', '\u{11c2e}', WordCat::ALetter),
+	('\u{11c2f}', '\u{11c36}', WordCat::Extend),
+	('\u{11c38}', '\u{11c3f}', WordCat::Extend),
+	('\u{11c40}', '\u{11c40}', WordCat::ALetter),
+	('\u{11c50}', '\u{11c59}', WordCat::Numeric),
+	('\u{11c72}', '\u{11c8f}', WordCat::ALetter),
+	('\u{11c92}', '\u{11ca7}', WordCat::Extend),
+	('\u{11ca9}', '\u{11cb6}', WordCat::Extend),
+	('\u{11d00}', '\u{11d06}', WordCat::ALetter),
+	('\u{11d08}', '\u{11d09}', WordCat::ALetter),
+	('\u{11d0b}', '\u{11d30}', WordCat::ALetter),
+	('\u{11d31}', '\u{11d36}', WordCat::Extend),
+	('\u{11d3a}', '\u{11d3a}', WordCat::Extend),
+	('\u{11d3c}', '\u{11d3d}', WordCat::Extend),
+	('\u{11d3f}', '\u{11d45}', WordCat::Extend),
+	('\u{11d46}', '\u{11d46}', WordCat::ALetter),
+	('\u{11d47}', '\u{11d47}', WordCat::Extend),
+	('\u{11d50}', '\u{11d59}', WordCat::Numeric),
+	('\u{11d60}', '\u{11d65}', WordCat::ALetter),
+	('\u{11d67}', '\u{11d68}', WordCat::ALetter),
+	('\u{11d6a}', '\u{11d89}', WordCat::ALetter),
+	('\u{11d8a}', '\u{11d8e}', WordCat::Extend),
+	('\u{11d90}', '\u{11d91}', WordCat::Extend),
+	('\u{11d93}', '\u{11d97}', WordCat::Extend),
+	('\u{11d98}', '\u{11d98}', WordCat::ALetter),
+	('\u{11da0}', '\u{11da9}', WordCat::Numeric),
+	('\u{11ee0}', '\u{11ef2}', WordCat::ALetter),
+	('\u{11ef3}', '\u{11ef6}', WordCat::Extend),
+	('\u{11fb0}', '\u{11fb0}', WordCat::ALetter),
+	('\u{12000}', '\u{12399}', WordCat::ALetter),
+	('\u{12400}', '\u{1246e}', WordCat::ALetter),
+	('\u{12480}', '\u{12543}', WordCat::ALetter),
+	('\u{12f90}', '\u{12ff0}', WordCat::ALetter),
+	('\u{13000}', '\u{1342e}', WordCat::ALetter),
+	('\u{13430}', '\u{13438}', WordCat::Format),
+	('\u{14400}', '\u{14646}', WordCat::ALetter),
+	('\u{16800}', '\u{16a38}', WordCat::ALetter),
+	('\u{16a40}', '\u{16a5e}', WordCat::ALetter),
+	('\u{16a60}', '\u{16a69}', WordCat::Numeric),
+	('\u{16a70}', '\u{16abe}', WordCat::ALetter),
+	('\u{16ac0}', '\u{16ac9}', WordCat::Numeric),
+	('\u{16ad0}', '\u{16aed}', WordCat::ALetter),
+	('\u{16af0}', '\u{16af4}', WordCat::Extend),
+	('\u{16b00}', '\u{16b2f}', WordCat::ALetter),
+	('\u{16b30}', '\u{16b36}', WordCat::Extend),
+	('\u{16b40}', '\u{16b43}', WordCat::ALetter),
+	('\u{16b50}', '\u{16b59}', WordCat::Numeric),
+	('\u{16b63}', '\u{16b77}', WordCat::ALetter),
+	('\u{16b7d}', '\u{16b8f}', WordCat::ALetter),
+	('\u{16e40}', '\u{16e7f}', WordCat::ALetter),
+	('\u{16f00}', '\u{16f4a}', WordCat::ALetter),
+	('\u{16f4f}', '\u{16f4f}', WordCat::Extend),
+	('\u{16f50}', '\u{16f50}', WordCat::ALetter),
+	('\u{16f51}', '\u{16f87}', WordCat::Extend),
+	('\u{16f8f}', '\u{16f92}', WordCat::Extend),
+	('\u{16f93}', '\u{16f9f}', WordCat::ALetter),
+	('\u{16fe0}', '\u{16fe1}', WordCat::ALetter),
+	('\u{16fe3}', '\u{16fe3}', WordCat::ALetter),
+	('\u{16fe4}', '\u{16fe4}', WordCat::Extend),
+	('\u{16ff0}', '\u{16ff1}', WordCat::Extend),
+	('\u{1aff0}', '\u{1aff3}', WordCat::Katakana),
+	('\u{1aff5}', '\u{1affb}', WordCat::Katakana),
+	('\u{1affd}', '\u{1affe}', WordCat::Katakana),
+	('\u{1b000}', '\u{1b000}', WordCat::Katakana),
+	('\u{1b120}', '\u{1b122}', WordCat::Katakana),
+	('\u{1b164}', '\u{1b167}', WordCat::Katakana),
+	('\u{1bc00}', '\u{1bc6a}', WordCat::ALetter),
+	('\u{1bc70}', '\u{1bc7c}', WordCat::ALetter),
+	('\u{1bc80}', '\u{1bc88}', WordCat::ALetter),
+	('\u{1bc90}', '\u{1bc99}', WordCat::ALetter),
+	('\u{1bc9d}', '\u{1bc9e}', WordCat::Extend),
+	('\u{1bca0}', '\u{1bca3}', WordCat::Format),
+	('\u{1cf00}', '\u{1cf2d}', WordCat::Extend),
+	('\u{1cf30}', '\u{1cf46}', WordCat::Extend),
+	('\u{1d165}', '\u{1d169}', WordCat::Extend),
+	('\u{1d16d}', '\u{1d172}', WordCat::Extend),
+	('\u{1d173}', '\u{1d17a}', WordCat::Format),
+	('\u{1d17b}', '\u{1d182}', WordCat::Extend),
+	('\u{1d185}', '\u{1d18b}', WordCat::Extend),
+	('\u{1d1aa}', '\u{1d1ad}', WordCat::Extend),
+	('\u{1d242}', '\u{1d244}', WordCat::Extend),
+	('\u{1d400}', '\u{1d454}', WordCat::ALetter),
+	('\u{1d456}', '\u{1d49c}', WordCat::ALetter),
+	('\u{1d49e}', '\u{1d49f}', WordCat::ALetter),
+	('\u{1d4a2}', '\u{1d4a2}', WordCat::ALetter),
+	('\u{1d4a5}', '\u{1d4a6}', WordCat::ALetter),
+	('\u{1d4a9}', '\u{1d4ac}', WordCat::ALetter),
+	('\u{1d4ae}', '\u{1d4b9}', WordCat::ALetter),
+	('\u{1d4bb}', '\u{1d4bb}', WordCat::ALetter),
+	('\u{1d4bd}', '\u{1d4c3}', WordCat::ALetter),
+	('\u{1d4c5}', '\u{1d505}', WordCat::ALetter),
+	('\u{1d507}', '\u{1d50a}', WordCat::ALetter),
+	('\u{1d50d}', '\u{1d514}', WordCat::ALetter),
+	('\u{1d516}', '\u{1d51c}', WordCat::ALetter),
+	('\u{1d51e}', '\u{1d539}', WordCat::ALetter),
+	('\u{1d53b}', '\u{1d53e}', WordCat::ALetter),
+	('\u{1d540}', '\u{1d544}', WordCat::ALetter),
+	('\u{1d546}', '\u{1d546}', WordCat::ALetter),
+	('\u{1d54a}', '\u{1d550}', WordCat::ALetter),
+	('\u{1d552}', '\u{1d6a5}', WordCat::ALetter),
+	('\u{1d6a8}', '\u{1d6c0}', WordCat::ALetter),
+	('\u{1d6c2}', '\u{1d6da}', WordCat::ALetter),
+	('\u{1d6dc}', '\u{1d6fa}', WordCat::ALetter),
+	('\u{1d6fc}', '\u{1d714}', WordCat::ALetter),
+	('\u{1d716}', '\u{1d734}', WordCat::ALetter),
+	('\u{1d736}', '\u{1d74e}', WordCat::ALetter),
+	('\u{1d750}', '\u{1d76e}', WordCat::ALetter),
+	('\u{1d770}', '\u{1d788}', WordCat::ALetter),
+	('\u{1d78a}', '\u{1d7a8}', WordCat::ALetter),
+	('\u{1d7aa}', '\u{1d7c2}', WordCat::ALetter),
+	('\u{1d7c4}', '\u{1d7cb}', WordCat::ALetter),
+	('\u{1d7ce}', '\u{1d7ff}', WordCat::Numeric),
+	('\u{1da00}', '\u{1da36}', WordCat::Extend),
+	('\u{1da3b}', '\u{1da6c}', WordCat::Extend),
+	('\u{1da75}', '\u{1da75}', WordCat::Extend),
+	('\u{1da84}', '\u{1da84}', WordCat::Extend),
+	('\u{1da9b}', '\u{1da9f}', WordCat::Extend),
+	('\u{1daa1}', '\u{1daaf}', WordCat::Extend),
+	('\u{1df00}', '\u{1df1e}', WordCat::ALetter),
+	('\u{1e000}', '\u{1e006}', WordCat::Extend),
+	('\u{1e008}', '\u{1e018}', WordCat::Extend),
+	('\u{1e01b}', '\u{1e021}', WordCat::Extend),
+	('\u{1e023}', '\u{1e024}', WordCat::Extend),
+	('\u{1e026}', '\u{1e02a}', WordCat::Extend),
+	('\u{1e100}', '\u{1e12c}', WordCat::ALetter),
+	('\u{1e130}', '\u{1e136}', WordCat::Extend),
+	('\u{1e137}', '\u{1e13d}', WordCat::ALetter),
+	('\u{1e140}', '\u{1e149}', WordCat::Numeric),
+	('\u{1e14e}', '\u{1e14e}', WordCat::ALetter),
+	('\u{1e290}', '\u{1e2ad}', WordCat::ALetter),
+	('\u{1e2ae}', '\u{1e2ae}', WordCat::Extend),
+	('\u{1e2c0}', '\u{1e2eb}', WordCat::ALetter),
+	('\u{1e2ec}', '\u{1e2ef}', WordCat::Extend),
+	('\u{1e2f0}', '\u{1e2f9}', WordCat::Numeric),
+	('\u{1e7e0}', '\u{1e7e6}', WordCat::ALetter),
+	('\u{1e7e8}', '\u{1e7eb}', WordCat::ALetter),
+	('\u{1e7ed}', '\u{1e7ee}', WordCat::ALetter),
+	('\u{1e7f0}', '\u{1e7fe}', WordCat::ALetter),
+	('\u{1e800}', '\u{1e8c4}', WordCat::ALetter),
+	('\u{1e8d0}', '\u{1e8d6}', WordCat::Extend),
+	('\u{1e900}', '\u{1e943}', WordCat::ALetter),
+	('\u{1e944}', '\u{1e94a}', WordCat::Extend),
+	('\u{1e94b}', '\u{1e94b}', WordCat::ALetter),
+	('\u{1e950}', '\u{1e959}', WordCat::Numeric),
+	('\u{1ee00}', '\u{1ee03}', WordCat::ALetter),
+	('\u{1ee05}', '\u{1ee1f}', WordCat::ALetter),
+	('\u{1ee21}', '\u{1ee22}', WordCat::ALetter),
+	('\u{1ee24}', '\u{1ee24}', WordCat::ALetter),
+	('\u{1ee27}', '\u{1ee27}', WordCat::ALetter),
+	('\u{1ee29}', '\u{1ee32}', WordCat::ALetter),
+	('\u{1ee34}', '\u{1ee37}', WordCat::ALetter),
+	('\u{1ee39}', '\u{1ee39}', WordCat::ALetter),
+	('\u{1ee3b}', '\u{1ee3b}', WordCat::ALetter),
+	('\u{1ee42}', '\u{1ee42}', WordCat::ALetter),
+	('\u{1ee47}', '\u{1ee47}', WordCat::ALetter),
+	('\u{1ee49}', '\u{1ee49}', WordCat::ALetter),
+	('\u{1ee4b}', '\u{1ee4b}', WordCat::ALetter),
+	('\u{1ee4d}', '\u{1ee4f}', WordCat::ALetter),
+	('\u{1ee51}', '\u{1ee52}', WordCat::ALetter),
+	('\u{1ee54}', '\u{1ee54}', WordCat::ALetter),
+	('\u{1ee57}', '\u{1ee57}', WordCat::ALetter),
+	('\u{1ee59}', '\u{1ee59}', WordCat::ALetter),
+	('\u{1ee5b}', '\u{1ee5b}', WordCat::ALetter),
+	('\u{1ee5d}', '\u{1ee5d}', WordCat::ALetter),
+	('\u{1ee5f}', '\u{1ee5f}', WordCat::ALetter),
+	('\u{1ee61}', '\u{1ee62}', WordCat::ALetter),
+	('\u{1ee64}', '\u{1ee64}', WordCat::ALetter),
+	('\u{1ee67}', '\u{1ee6a}', WordCat::ALetter),
+	('\u{1ee6c}', '\u{1ee72}', WordCat::ALetter),
+	('\u{1ee74}', '\u{1ee77}', WordCat::ALetter),
+	('\u{1ee79}', '\u{1ee7c}', WordCat::ALetter),
+	('\u{1ee7e}', '\u{1ee7e}', WordCat::ALetter),
+	('\u{1ee80}', '\u{1ee89}', WordCat::ALetter),
+	('\u{1ee8b}', '\u{1ee9b}', WordCat::ALetter),
+	('\u{1eea1}', '\u{1eea3}', WordCat::ALetter),
+	('\u{1eea5}', '\u{1eea9}', WordCat::ALetter),
+	('\u{1eeab}', '\u{1eebb}', WordCat::ALetter),
+	('\u{1f130}', '\u{1f149}', WordCat::ALetter),
+	('\u{1f150}', '\u{1f169}', WordCat::ALetter),
+	('\u{1f170}', '\u{1f189}', WordCat::ALetter),
+	('\u{1f1e6}', '\u{1f1ff}', WordCat::RegionalIndicator),
+	('\u{1f3fb}', '\u{1f3ff}', WordCat::Extend),
+	('\u{1fbf0}', '\u{1fbf9}', WordCat::Numeric),
+	('\u{e0001}', '\u{e0001}', WordCat::Format),
+	('\u{e0020}', '\u{e007f}', WordCat::Extend),
+	('\u{e0100}', '\u{e01ef}', WordCat::Extend),
+];
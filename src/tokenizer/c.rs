@@ -0,0 +1,185 @@
+/*!
+This module provides a tokeniser which follows the lexical grammar of C (and, by extension, much of C++ and Java).
+
+It should be paired with the `whitespace::CComments` policy, so that comments are skipped along with whitespace.
+*/
+use super::{Punctuators, TokenKind, Tokenizer};
+use super::quoted::quoted_len_at;
+use super::super::len_while;
+
+/**
+The multi-character operators and punctuators of C.  This includes the digraphs (`<:`, `%>`, and so on), as well as `::`, which is shared by C++ and C23.  `CTokens` matches these using a `Punctuators` tokeniser.
+*/
+pub static C_PUNCTUATION: &'static [&'static str] = &[
+	"%:%:",
+	"<<=", ">>=", "...",
+	"->", "++", "--", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||",
+	"*=", "/=", "%=", "+=", "-=", "&=", "^=", "|=", "##", "::",
+	"<:", ":>", "<%", "%>", "%:",
+];
+
+/**
+Tokenises a string according to the lexical grammar of C.  Specifically, this recognises:
+
+- identifiers,
+- numeric literals, including hexadecimal, octal and floating point literals with exponents and suffixes (`0x1Fu`, `100ULL`, `1.5e-3f`, `.5`, `0x1p-3`),
+- character literals with escapes and optional encoding prefixes (`'a'`, `'\n'`, `L'x'`, `u8'x'`),
+- string literals with escapes and optional encoding prefixes (`"a\"b"`, `L"wide"`, `u8"utf-8"`),
+- multi-character operators (`->`, `<<=`, `...`, and so on; see `C_PUNCTUATION`), using longest match.
+
+Numeric literals follow the "preprocessing number" rule, which is deliberately permissive: a numeric literal is a digit (optionally preceded by a `.`) followed by any run of letters, digits, underscores, periods, and signs which immediately follow an exponent marker (`e`, `E`, `p` or `P`).
+
+Single-character operators are left to the usual single code point fallback.  Comments are *not* handled here; use `whitespace::CComments` to skip them.  Unterminated string and character literals are not tokens; the opening quote will become a single code point token instead.
+*/
+#[derive(Clone, Eq, PartialEq, Show)]
+pub struct CTokens {
+	punct: Punctuators,
+}
+
+impl CTokens {
+	/**
+Construct a C tokeniser.
+	*/
+	pub fn new() -> CTokens {
+		CTokens {
+			punct: Punctuators::new(C_PUNCTUATION),
+		}
+	}
+}
+
+impl Default for CTokens {
+	fn default() -> CTokens {
+		CTokens::new()
+	}
+}
+
+impl Tokenizer for CTokens {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.typed_token_len(s).map(|(n,_)| n)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		if s.len() == 0 {
+			return None;
+		}
+
+		let ch0 = s.char_at(0);
+
+		if let Some(tk) = prefixed_literal_len(s) {
+			Some(tk)
+		} else if ch0 == '_' || ch0.is_xid_start() {
+			len_while(s, |ch| ch == '_' || ch.is_xid_continue()).map(|n| (n, TokenKind::Ident))
+		} else if ch0.is_digit(10) || (ch0 == '.' && s.len() > 1 && s.char_at(1).is_digit(10)) {
+			Some((pp_number_len(s), TokenKind::Number))
+		} else if ch0 == '\'' || ch0 == '"' {
+			literal_len(s, 0)
+		} else {
+			self.punct.typed_token_len(s)
+		}
+	}
+}
+
+/// Handles character and string literals with an encoding prefix.
+fn prefixed_literal_len(s: &str) -> Option<(uint, TokenKind)> {
+	for prefix in ["u8", "u", "U", "L"].iter() {
+		if s.starts_with(*prefix) {
+			let rest = s.slice_from(prefix.len());
+			if rest.starts_with("'") || rest.starts_with("\"") {
+				return literal_len(s, prefix.len());
+			}
+		}
+	}
+	None
+}
+
+/// Scans a character or string literal whose opening quote is at `start`.  Literals may not span lines.
+fn literal_len(s: &str, start: uint) -> Option<(uint, TokenKind)> {
+	let kind = if s.char_at(start) == '\'' { TokenKind::Char } else { TokenKind::Str };
+	quoted_len_at(s, start, false).map(|n| (n, kind))
+}
+
+fn pp_number_len(s: &str) -> uint {
+	let mut prev = s.char_at(0);
+	for (i, ch) in s.char_indices().skip(1) {
+		let sign = (ch == '+' || ch == '-') && (prev == 'e' || prev == 'E' || prev == 'p' || prev == 'P');
+		if !(sign || ch.is_alphanumeric() || ch == '_' || ch == '.') {
+			return i;
+		}
+		prev = ch;
+	}
+	s.len()
+}
+
+#[test]
+fn test_c_tokens() {
+	let tok = CTokens::new();
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" x"), None);
+	assert_eq!(tl("+"), None);
+	assert_eq!(tl("."), None);
+	assert_eq!(tl("#"), None);
+
+	// Identifiers.
+	assert_eq!(tl("foo bar"), Some(3));
+	assert_eq!(tl("_foo_1("), Some(6));
+	assert_eq!(tl("L"), Some(1));
+	assert_eq!(tl("Lx"), Some(2));
+	assert_eq!(tl("u8x"), Some(3));
+
+	// Numbers.
+	assert_eq!(tl("0"), Some(1));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("123u"), Some(4));
+	assert_eq!(tl("100ULL;"), Some(6));
+	assert_eq!(tl("0x1Fu)"), Some(5));
+	assert_eq!(tl("0777"), Some(4));
+	assert_eq!(tl("1.5"), Some(3));
+	assert_eq!(tl("1.5e-3f"), Some(7));
+	assert_eq!(tl("1E+10L"), Some(6));
+	assert_eq!(tl(".5f"), Some(3));
+	assert_eq!(tl("0x1p-3"), Some(6));
+	assert_eq!(tl("1-2"), Some(1));
+	assert_eq!(tl("1+2"), Some(1));
+	assert_eq!(tl("1é"), Some(3));
+	assert_eq!(tl("1© x"), Some(1));
+
+	// Characters and strings.
+	assert_eq!(tl("'a'"), Some(3));
+	assert_eq!(tl("'\\n' x"), Some(4));
+	assert_eq!(tl("'\\'' x"), Some(4));
+	assert_eq!(tl("'\\x41'"), Some(6));
+	assert_eq!(tl("L'x'"), Some(4));
+	assert_eq!(tl("u8'x'"), Some(5));
+	assert_eq!(tl("'ab'"), Some(4));
+	assert_eq!(tl("'a"), None);
+	assert_eq!(tl("\"\""), Some(2));
+	assert_eq!(tl("\"a\\\"b\" x"), Some(6));
+	assert_eq!(tl("L\"wide\""), Some(7));
+	assert_eq!(tl("u8\"utf\""), Some(7));
+	assert_eq!(tl("U\"x\""), Some(4));
+	assert_eq!(tl("\"a\nb\""), None);
+	assert_eq!(tl("\"abc"), None);
+
+	// Operators.
+	assert_eq!(tl("->x"), Some(2));
+	assert_eq!(tl("++x"), Some(2));
+	assert_eq!(tl("+=x"), Some(2));
+	assert_eq!(tl("<<=x"), Some(3));
+	assert_eq!(tl("<<x"), Some(2));
+	assert_eq!(tl("<x"), None);
+	assert_eq!(tl("...x"), Some(3));
+	assert_eq!(tl("..x"), None);
+	assert_eq!(tl("##x"), Some(2));
+	assert_eq!(tl("%:%:x"), Some(4));
+	assert_eq!(tl("::x"), Some(2));
+
+	let ttl = |s:&str| tok.typed_token_len(s);
+
+	assert_eq!(ttl("foo bar"), Some((3, TokenKind::Ident)));
+	assert_eq!(ttl("0x1Fu)"), Some((5, TokenKind::Number)));
+	assert_eq!(ttl("L'x'"), Some((4, TokenKind::Char)));
+	assert_eq!(ttl("u8\"x\""), Some((5, TokenKind::Str)));
+	assert_eq!(ttl("<<= 1"), Some((3, TokenKind::Punct)));
+}
@@ -0,0 +1,219 @@
+/*!
+This module provides combinators for building new tokenisers out of existing ones.
+
+For example, a tokeniser for identifiers, integers and punctuation separated by commas could be written as:
+
+```ignore
+Or(IdentsAndInts, Except { inner: SpaceDelimited, chars: vec![','] })
+```
+*/
+use std::fmt::{self, Show, Formatter};
+
+use super::{TokenKind, Tokenizer};
+use super::super::len_while;
+
+/**
+Ordered choice: tries the first tokeniser and, only if it does not produce a token, tries the second.
+
+Nest this to choose between more than two tokenisers; for example, `Or(a, Or(b, c))`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Or<A, B>(pub A, pub B);
+
+impl<A: Tokenizer, B: Tokenizer> Tokenizer for Or<A, B> {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.0.token_len(s).or_else(|| self.1.token_len(s))
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		self.0.typed_token_len(s).or_else(|| self.1.typed_token_len(s))
+	}
+}
+
+#[test]
+fn test_or() {
+	use tokenizer::{SpaceDelimited, WordsAndInts};
+
+	let tok = Or(WordsAndInts, SpaceDelimited);
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc_def"), Some(3));
+	assert_eq!(tl("_abc def"), Some(4));
+	assert_eq!(tl("123.456"), Some(3));
+	assert_eq!(tl(".456"), Some(4));
+
+	let ttl = |s:&str| tok.typed_token_len(s);
+
+	assert_eq!(ttl("abc"), Some((3, TokenKind::Word)));
+	assert_eq!(ttl("123.456"), Some((3, TokenKind::Number)));
+	assert_eq!(ttl(".456"), Some((4, TokenKind::Other)));
+}
+
+/**
+Longest match: tries both tokenisers and uses whichever produces the longer token.  Ties go to the first tokeniser.
+
+Nest this to choose between more than two tokenisers; for example, `Longest(a, Longest(b, c))`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Longest<A, B>(pub A, pub B);
+
+impl<A: Tokenizer, B: Tokenizer> Tokenizer for Longest<A, B> {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		match (self.0.token_len(s), self.1.token_len(s)) {
+			(Some(a), Some(b)) => Some(if b > a { b } else { a }),
+			(a, b) => a.or(b)
+		}
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		match (self.0.typed_token_len(s), self.1.typed_token_len(s)) {
+			(Some(a), Some(b)) => Some(if b.0 > a.0 { b } else { a }),
+			(a, b) => a.or(b)
+		}
+	}
+}
+
+#[test]
+fn test_longest() {
+	use tokenizer::{SpaceDelimited, WordsAndInts};
+
+	let tok = Longest(WordsAndInts, SpaceDelimited);
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc_def"), Some(7));
+	assert_eq!(tl("123.456 x"), Some(7));
+
+	let ttl = |s:&str| tok.typed_token_len(s);
+
+	assert_eq!(ttl("abc"), Some((3, TokenKind::Word)));
+	assert_eq!(ttl("abc_def"), Some((7, TokenKind::Other)));
+}
+
+/**
+Restricts another tokeniser such that its tokens never contain any of the given code points.  Tokens produced by the inner tokeniser are cut short just before the first excluded code point; if that would leave an empty token, there is no token.
+
+For example, `Except { inner: SpaceDelimited, chars: vec![',', ';'] }` tokenises `"a,b;c"` as `"a"`, `","`, `"b"`, `";"`, `"c"` (the punctuation being produced by the usual single code point fallback).
+*/
+#[derive(Clone, Default, Eq, PartialEq, Show)]
+pub struct Except<Tok> {
+	pub inner: Tok,
+	pub chars: Vec<char>,
+}
+
+impl<Tok: Tokenizer> Tokenizer for Except<Tok> {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.typed_token_len(s).map(|(n,_)| n)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		let (end, kind) = match self.inner.typed_token_len(s) {
+			Some(tk) => tk,
+			None => return None
+		};
+
+		match s.slice_to(end).char_indices().find(|&(_, ch)| self.chars.contains(&ch)) {
+			Some((0, _)) => None,
+			Some((i, _)) => Some((i, kind)),
+			None => Some((end, kind))
+		}
+	}
+}
+
+#[test]
+fn test_except() {
+	use tokenizer::SpaceDelimited;
+
+	let tok = Except { inner: SpaceDelimited, chars: vec![',', ';'] };
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl(","), None);
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc,def"), Some(3));
+	assert_eq!(tl("abc;def,ghi"), Some(3));
+	assert_eq!(tl("abc def,ghi"), Some(3));
+	assert_eq!(tl("日本,語"), Some(6));
+}
+
+/**
+Turns a code point predicate into a tokeniser: a token is a run of one or more code points which satisfy the predicate.
+
+For example, `CharClass(is_hex_digit)` (given a suitable `fn is_hex_digit(char) -> bool`) tokenises runs of hexadecimal digits.
+*/
+#[derive(Copy)]
+pub struct CharClass(pub fn(char) -> bool);
+
+impl Clone for CharClass {
+	fn clone(&self) -> CharClass {
+		*self
+	}
+}
+
+impl PartialEq for CharClass {
+	fn eq(&self, other: &CharClass) -> bool {
+		self.0 as uint == other.0 as uint
+	}
+}
+
+impl Eq for CharClass {}
+
+impl Show for CharClass {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+		write!(f, "CharClass({:x})", self.0 as uint)
+	}
+}
+
+impl Tokenizer for CharClass {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		let CharClass(pred) = *self;
+		len_while(s, |ch| pred(ch))
+	}
+}
+
+#[test]
+fn test_char_class() {
+	fn is_hex_digit(ch: char) -> bool {
+		ch.is_digit(16)
+	}
+
+	fn is_vowel(ch: char) -> bool {
+		"aeiou".contains_char(ch)
+	}
+
+	let hex = CharClass(is_hex_digit);
+	assert_eq!(hex.token_len(""), None);
+	assert_eq!(hex.token_len("xyz"), None);
+	assert_eq!(hex.token_len("dead beef"), Some(4));
+	assert_eq!(hex.token_len("0x1f"), Some(1));
+	assert_eq!(hex.token_len("1fz"), Some(2));
+
+	assert!(hex == CharClass(is_hex_digit));
+	assert!(hex != CharClass(is_vowel));
+}
+
+#[test]
+fn test_combined() {
+	use tokenizer::IdentsAndInts;
+
+	fn is_decimal_part(ch: char) -> bool {
+		ch.is_digit(10) || ch == '.'
+	}
+
+	// Identifiers, and decimal numbers like `1.5`; but never let a decimal swallow a comma.
+	let tok = Longest(IdentsAndInts, Except { inner: CharClass(is_decimal_part), chars: vec![','] });
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("+"), None);
+	assert_eq!(tl("abc1.5"), Some(4));
+	assert_eq!(tl("1.5,x"), Some(3));
+	assert_eq!(tl("10"), Some(2));
+	assert_eq!(tl("x_1 y"), Some(3));
+}
@@ -0,0 +1,353 @@
+/*!
+This module provides the `Tokenizer` trait and its implementations.
+*/
+use super::len_while;
+use segment::word_len;
+
+pub use self::c::CTokens;
+pub use self::combinators::{CharClass, Except, Longest, Or};
+pub use self::numeric::{Numbers, SignedNumbers};
+pub use self::punct::{Punctuated, Punctuators};
+pub use self::quoted::{Quoted, QuotedOrSpaceDelimited};
+pub use self::shell::ShellWords;
+#[cfg(feature = "regex")] pub use self::regex::{MatchMode, RegexTokenizer};
+pub use self::rust::RustTokens;
+
+pub mod c;
+pub mod combinators;
+pub mod numeric;
+pub mod punct;
+pub mod quoted;
+pub mod shell;
+#[cfg(feature = "regex")] pub mod regex;
+pub mod rust;
+
+/**
+This trait is used to tokenise both input strings fed to a `scan*` macro *and* string literals in scan patterns.
+*/
+pub trait Tokenizer: Eq + ::std::fmt::Show {
+	/**
+If there is a valid token present at the start of the given string, return its length.  Otherwise, return `None`.  Note that `ScanCursor::pop_token` will automatically turn the next single code point (or grapheme cluster, depending on the cursor's `Fallback` mode) into a token if this method returns `None`.  When implementing this function, you may rely on this behaviour.
+	*/
+	fn token_len(&self, s: &str) -> Option<uint>;
+
+	/**
+Like `token_len`, but also returns what kind of token was found.  This is what `ScanCursor::pop_typed_token` uses.
+
+The default implementation defers to `token_len` and reports every token as `TokenKind::Other`.  This means that tokenisers which only implement `token_len` continue to work unchanged; implementations which *can* tell different kinds of token apart should override this, and make sure it agrees with `token_len` on lengths.
+	*/
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		self.token_len(s).map(|n| (n, TokenKind::Other))
+	}
+}
+
+/**
+Describes what kind of token was produced by a tokeniser.  Which kinds a given tokeniser produces is up to the tokeniser; for example, `WordsAndInts` only ever produces `Word` and `Number`.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub enum TokenKind {
+	/**
+A natural language word.
+	*/
+	Word,
+	/**
+An identifier, in the programming language sense.
+	*/
+	Ident,
+	/**
+A lifetime, such as Rust's `'a`.
+	*/
+	Lifetime,
+	/**
+A numeric literal, whether integer or floating point.
+	*/
+	Number,
+	/**
+A quoted string literal.
+	*/
+	Str,
+	/**
+A quoted character literal.
+	*/
+	Char,
+	/**
+Punctuation or an operator recognised by the tokeniser.
+	*/
+	Punct,
+	/**
+An explicit whitespace token produced by the `Whitespace` policy.
+	*/
+	Space,
+	/**
+A single code point or grapheme cluster produced because the tokeniser did not recognise anything.
+	*/
+	Fallback,
+	/**
+Any other kind of token; this is what tokenisers which do not distinguish kinds report.
+	*/
+	Other,
+}
+
+impl TokenKind {
+	/**
+Returns a short, human-readable description of this kind of token, suitable for use in error messages.
+	*/
+	pub fn description(&self) -> &'static str {
+		match *self {
+			TokenKind::Word => "word",
+			TokenKind::Ident => "identifier",
+			TokenKind::Lifetime => "lifetime",
+			TokenKind::Number => "number",
+			TokenKind::Str => "string literal",
+			TokenKind::Char => "character literal",
+			TokenKind::Punct => "punctuation",
+			TokenKind::Space => "whitespace",
+			TokenKind::Fallback => "character",
+			TokenKind::Other => "token",
+		}
+	}
+}
+
+/**
+Tokenises a string into words and integers.  Specifically, a word is a sequence of one or more code points which have the `Alphabetic` property; an integer is a sequence of one or more code points which are in the `N*` general category.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct WordsAndInts;
+
+impl Tokenizer for WordsAndInts {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.typed_token_len(s).map(|(n,_)| n)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		if s.len() == 0 {
+			return None;
+		}
+
+		let ch0 = s.char_at(0);
+
+		if ch0.is_alphabetic() {
+			len_while(s, |ch| ch.is_alphabetic()).map(|n| (n, TokenKind::Word))
+		} else if ch0.is_digit(10) {
+			len_while(s, |ch| ch.is_digit(10)).map(|n| (n, TokenKind::Number))
+		} else {
+			None
+		}
+	}
+}
+
+#[test]
+fn test_words_and_ints() {
+	let tl = |s:&str| WordsAndInts.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("_"), None);
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc def"), Some(3));
+	assert_eq!(tl("abc123"), Some(3));
+	assert_eq!(tl("abc_def"), Some(3));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("123abc"), Some(3));
+	assert_eq!(tl("123_456"), Some(3));
+	assert_eq!(tl("123.456"), Some(3));
+
+	let ttl = |s:&str| WordsAndInts.typed_token_len(s);
+
+	assert_eq!(ttl(""), None);
+	assert_eq!(ttl("_"), None);
+	assert_eq!(ttl("abc123"), Some((3, TokenKind::Word)));
+	assert_eq!(ttl("123abc"), Some((3, TokenKind::Number)));
+}
+
+/**
+Tokenises a string according to the default word boundary rules of Unicode Standard Annex #29.  This handles a number of cases `WordsAndInts` does not: words containing apostrophes (`don't`), numbers with decimal points or separators (`3.14`, `1,000`), words mixing letters and digits (`abc123`), Katakana runs, and letters followed by combining marks.
+
+Text in scripts which do not separate words with spaces (such as Chinese and Japanese) is split into individual ideographs, as dictionary-based segmentation is not performed.
+
+Any other code point which is not part of a word (such as punctuation) becomes a token on its own.
+
+Segments which start with a digit are reported as `TokenKind::Number`, those which start with any other alphanumeric code point or an underscore as `TokenKind::Word`, runs of whitespace as `TokenKind::Space`, and everything else as `TokenKind::Punct`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct UnicodeWords;
+
+impl Tokenizer for UnicodeWords {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		word_len(s)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		word_len(s).map(|n| {
+			let ch0 = s.char_at(0);
+			let kind = if ch0.is_digit(10) {
+				TokenKind::Number
+			} else if ch0.is_alphanumeric() || ch0 == '_' {
+				TokenKind::Word
+			} else if ch0.is_whitespace() {
+				TokenKind::Space
+			} else {
+				TokenKind::Punct
+			};
+			(n, kind)
+		})
+	}
+}
+
+#[test]
+fn test_unicode_words() {
+	let tl = |s:&str| UnicodeWords.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("_"), Some(1));
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc def"), Some(3));
+	assert_eq!(tl("abc123"), Some(6));
+	assert_eq!(tl("abc_def"), Some(7));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("123abc"), Some(6));
+	assert_eq!(tl("123.456"), Some(7));
+	assert_eq!(tl("123.abc"), Some(3));
+	assert_eq!(tl("don't"), Some(5));
+	assert_eq!(tl("'quoted'"), Some(1));
+	assert_eq!(tl("日本語"), Some(3));
+	assert_eq!(tl("Ελληνικά."), Some(16));
+
+	let ttl = |s:&str| UnicodeWords.typed_token_len(s);
+
+	assert_eq!(ttl(""), None);
+	assert_eq!(ttl("don't"), Some((5, TokenKind::Word)));
+	assert_eq!(ttl("3.14"), Some((4, TokenKind::Number)));
+	assert_eq!(ttl("_x"), Some((2, TokenKind::Word)));
+	assert_eq!(ttl("  x"), Some((2, TokenKind::Space)));
+	assert_eq!(ttl("..."), Some((1, TokenKind::Punct)));
+}
+
+/**
+Tokenises a string into identifiers and integers.  Specifically, an identifier is a sequence of one code point which is either an underscore or satisfies the `XID_Start` property, followed by zero or more code points which satisfy the `XID_Continue` property; an integer is a sequence of one or more code points which are in the `N*` general category.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct IdentsAndInts;
+
+impl Tokenizer for IdentsAndInts {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.typed_token_len(s).map(|(n,_)| n)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		if s.len() == 0 {
+			return None;
+		}
+
+		let ch0 = s.char_at(0);
+
+		if ch0 == '_' || ch0.is_xid_start() {
+			len_while(s, |ch| ch.is_xid_continue()).map(|n| (n, TokenKind::Ident))
+		} else if ch0.is_digit(10) {
+			len_while(s, |ch| ch.is_digit(10)).map(|n| (n, TokenKind::Number))
+		} else {
+			None
+		}
+	}
+}
+
+#[test]
+fn test_idents_and_ints() {
+	let tl = |s:&str| IdentsAndInts.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("_"), Some(1));
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc def"), Some(3));
+	assert_eq!(tl("abc123"), Some(6));
+	assert_eq!(tl("abc_def"), Some(7));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123abc"), Some(3));
+	assert_eq!(tl("_123abc"), Some(7));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("123abc"), Some(3));
+	assert_eq!(tl("123_456"), Some(3));
+	assert_eq!(tl("123.456"), Some(3));
+
+	let ttl = |s:&str| IdentsAndInts.typed_token_len(s);
+
+	assert_eq!(ttl(""), None);
+	assert_eq!(ttl("+"), None);
+	assert_eq!(ttl("_123abc"), Some((7, TokenKind::Ident)));
+	assert_eq!(ttl("123abc"), Some((3, TokenKind::Number)));
+}
+
+/**
+Tokenises a string into space-delimited tokens.  Specifically, a token will be a sequence of one or more code points which *do not* satisfy the `White_Space` property.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct SpaceDelimited;
+
+impl Tokenizer for SpaceDelimited {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		if s.len() == 0 {
+			return None;
+		}
+
+		len_while(s, |ch| !ch.is_whitespace())
+	}
+}
+
+#[test]
+fn test_space_delimited() {
+	let tl = |s:&str| SpaceDelimited.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("_"), Some(1));
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc def"), Some(3));
+	assert_eq!(tl("abc123"), Some(6));
+	assert_eq!(tl("abc_def"), Some(7));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123abc"), Some(6));
+	assert_eq!(tl("_123abc"), Some(7));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("123abc"), Some(6));
+	assert_eq!(tl("123_456"), Some(7));
+	assert_eq!(tl("123.456"), Some(7));
+}
+
+/**
+This tokeniser interprets the entire input string as a single token *unless* the input string is empty, in which case it returns `None`.
+
+As a result, this tokeniser is almost totally useless at runtime.  If you *do* use this tokeniser for a pattern, you will almost certainly want to specify a different runtime tokeniser using `#[runtime_tok="..."]`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Explicit;
+
+impl Tokenizer for Explicit {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		if s.len() == 0 {
+			return None;
+		}
+
+		Some(s.len())
+	}
+}
+
+#[test]
+fn test_explicit() {
+	let tl = |s:&str| Explicit.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), Some(4));
+	assert_eq!(tl("_"), Some(1));
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc def"), Some(7));
+	assert_eq!(tl("abc123"), Some(6));
+	assert_eq!(tl("abc_def"), Some(7));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123abc"), Some(6));
+	assert_eq!(tl("_123abc"), Some(7));
+	assert_eq!(tl("123 456"), Some(7));
+	assert_eq!(tl("123abc"), Some(6));
+	assert_eq!(tl("123_456"), Some(7));
+	assert_eq!(tl("123.456"), Some(7));
+}
@@ -0,0 +1,201 @@
+/*!
+This module provides tokenisers which recognise complete numeric literals.
+*/
+use super::{TokenKind, Tokenizer};
+
+/**
+Tokenises numeric literals as single tokens.  A numeric literal is one of:
+
+- a decimal literal, with an optional fractional part (`1.5`) and an optional exponent (`1e5`, `1.5e-3`),
+- a hexadecimal (`0x1f`), octal (`0o17`) or binary (`0b101`) literal,
+
+where digits may be separated by underscores (`1_000_000`), and the whole may be followed by a type suffix: one of `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize` or, for decimal literals, `f32` or `f64`.
+
+The fractional part and exponent follow `scan_float`: a decimal point is included even if no digits follow it, as is an exponent marker and its sign.  This means `1..2` is tokenised as `1.`, `.`, `2`.  A radix prefix, on the other hand, is only included if at least one digit follows it.
+
+This does not accept a leading sign; see `SignedNumbers` for that.  Other than the sign, anything `scan_int`, `scan_uint` or `scan_float` would accept forms a single token under this tokeniser.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Numbers;
+
+impl Tokenizer for Numbers {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		number_len(s, false)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		number_len(s, false).map(|n| (n, TokenKind::Number))
+	}
+}
+
+#[test]
+fn test_numbers() {
+	let tl = |s:&str| Numbers.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("x"), None);
+	assert_eq!(tl("_1"), None);
+	assert_eq!(tl(".5"), None);
+	assert_eq!(tl("-1"), None);
+	assert_eq!(tl("+1"), None);
+	assert_eq!(tl("0"), Some(1));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("1_234_567"), Some(9));
+	assert_eq!(tl("123.456"), Some(7));
+	assert_eq!(tl("123."), Some(4));
+	assert_eq!(tl("1..2"), Some(2));
+	assert_eq!(tl("1.max(2)"), Some(2));
+	assert_eq!(tl("1e5"), Some(3));
+	assert_eq!(tl("1e"), Some(2));
+	assert_eq!(tl("1e+"), Some(3));
+	assert_eq!(tl("1.e5"), Some(4));
+	assert_eq!(tl("1E+5"), Some(4));
+	assert_eq!(tl("1.5e-3"), Some(6));
+	assert_eq!(tl("1.5e-"), Some(5));
+	assert_eq!(tl("1.5ex"), Some(4));
+	assert_eq!(tl("0x1f"), Some(4));
+	assert_eq!(tl("0xDEAD_beef"), Some(11));
+	assert_eq!(tl("0x"), Some(1));
+	assert_eq!(tl("0xg"), Some(1));
+	assert_eq!(tl("0o17"), Some(4));
+	assert_eq!(tl("0o18"), Some(3));
+	assert_eq!(tl("0b1010_0101"), Some(11));
+	assert_eq!(tl("0b12"), Some(3));
+	assert_eq!(tl("42u32"), Some(5));
+	assert_eq!(tl("42usize"), Some(7));
+	assert_eq!(tl("42i8"), Some(4));
+	assert_eq!(tl("1.5f32"), Some(6));
+	assert_eq!(tl("1e5f64"), Some(6));
+	assert_eq!(tl("0xffu8"), Some(6));
+	assert_eq!(tl("42u33"), Some(2));
+	assert_eq!(tl("42u32x"), Some(2));
+	assert_eq!(tl("42abc"), Some(2));
+	assert_eq!(tl("0x1f32"), Some(6));
+
+	assert_eq!(Numbers.typed_token_len("1.5e3 x"), Some((5, TokenKind::Number)));
+}
+
+/**
+Tokenises numeric literals as single tokens, including an optional leading `+` or `-` sign.  Other than that, this is identical to `Numbers`.
+
+Note that this means `x-1` will be tokenised as `x`, `-1`; use this only where a minus sign cannot appear as a binary operator.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct SignedNumbers;
+
+impl Tokenizer for SignedNumbers {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		number_len(s, true)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		number_len(s, true).map(|n| (n, TokenKind::Number))
+	}
+}
+
+#[test]
+fn test_signed_numbers() {
+	let tl = |s:&str| SignedNumbers.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("-"), None);
+	assert_eq!(tl("-x"), None);
+	assert_eq!(tl("- 1"), None);
+	assert_eq!(tl("--1"), None);
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("-123"), Some(4));
+	assert_eq!(tl("+123"), Some(4));
+	assert_eq!(tl("-1.5e-3"), Some(7));
+	assert_eq!(tl("-0x1f"), Some(5));
+	assert_eq!(tl("-42i64"), Some(6));
+}
+
+/**
+Returns the length of the numeric literal at the start of `s`, if there is one.  If `signed` is `true`, a leading `+` or `-` is permitted.
+
+See `Numbers` for a description of what constitutes a numeric literal.
+*/
+pub fn number_len(s: &str, signed: bool) -> Option<uint> {
+	let b = s.as_bytes();
+	let mut i = 0;
+
+	if signed && b.len() > 0 && (b[0] == b'-' || b[0] == b'+') {
+		i += 1;
+	}
+
+	if i >= b.len() || !is_digit(b[i], 10) {
+		return None;
+	}
+
+	// Radix prefix.
+	if b[i] == b'0' && i + 1 < b.len() {
+		let radix = match b[i+1] {
+			b'x' => 16,
+			b'o' => 8,
+			b'b' => 2,
+			_ => 10
+		};
+		if radix != 10 {
+			let end = digits_len(b, i + 2, radix);
+			if end > i + 2 {
+				return Some(suffix_len(b, end, false));
+			}
+		}
+	}
+
+	i = digits_len(b, i, 10);
+
+	// Fractional part and exponent.  As with `scan_float`, neither needs any digits.
+	if i < b.len() && b[i] == b'.' {
+		i = digits_len(b, i + 1, 10);
+	}
+
+	if i < b.len() && (b[i] == b'e' || b[i] == b'E') {
+		i += 1;
+		if i < b.len() && (b[i] == b'+' || b[i] == b'-') {
+			i += 1;
+		}
+		i = digits_len(b, i, 10);
+	}
+
+	Some(suffix_len(b, i, true))
+}
+
+fn is_digit(b: u8, radix: uint) -> bool {
+	(b as char).is_digit(radix)
+}
+
+/// Returns the offset of the end of the run of digits (and underscores) starting at `i`.  The run must begin with a digit.
+fn digits_len(b: &[u8], i: uint, radix: uint) -> uint {
+	if i >= b.len() || !is_digit(b[i], radix) {
+		return i;
+	}
+	let mut i = i + 1;
+	while i < b.len() && (is_digit(b[i], radix) || b[i] == b'_') {
+		i += 1;
+	}
+	i
+}
+
+/// Returns the offset of the end of the type suffix starting at `i`, or `i` if there isn't a valid one.
+fn suffix_len(b: &[u8], i: uint, allow_float: bool) -> uint {
+	static INT_SUFFIXES: &'static [&'static str] = &["i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize"];
+	static FLOAT_SUFFIXES: &'static [&'static str] = &["f32", "f64"];
+
+	let is_ident_byte = |&: b: u8| (b as char).is_alphanumeric() || b == b'_' || b >= 0x80;
+	let rest = b.slice_from(i);
+
+	let check = |&: suf: &&str| {
+		let suf = suf.as_bytes();
+		rest.starts_with(suf) && !(rest.len() > suf.len() && is_ident_byte(rest[suf.len()]))
+	};
+
+	let found = INT_SUFFIXES.iter().find(|suf| check(*suf))
+		.or_else(|| if allow_float { FLOAT_SUFFIXES.iter().find(|suf| check(*suf)) } else { None });
+
+	match found {
+		Some(suf) => i + suf.len(),
+		None => i
+	}
+}
@@ -0,0 +1,199 @@
+/*!
+This module provides tokenisers for multi-character punctuation, such as `<=`, `->`, `::` and `...`.
+*/
+use std::borrow::ToOwned;
+use std::fmt::{self, Show, Formatter};
+
+use super::{TokenKind, Tokenizer};
+
+/**
+Tokenises a string into punctuators drawn from a fixed set, always preferring the longest one which matches.  For example, given the set `["<", "<=", "<<", "<<="]`, the input `"<<= x"` produces a single `"<<="` token.
+
+If no punctuator matches, this returns `None`; `ScanCursor::pop_token` will then produce a single code point token, as usual.  As such, there is no need to include single-character punctuators in the set.
+
+The set is stored as a trie, so matching is proportional to the length of the longest match, not the size of the set.
+*/
+#[derive(Clone, Eq, PartialEq)]
+pub struct Punctuators {
+	puncts: Vec<String>,
+	nodes: Vec<Node>,
+}
+
+#[derive(Clone, Eq, PartialEq, Show)]
+struct Node {
+	/// Is there a punctuator which ends at this node?
+	terminal: bool,
+	/// Outgoing edges, sorted by code point.
+	children: Vec<(char, uint)>,
+}
+
+impl Punctuators {
+	/**
+Construct a tokeniser from a set of punctuators.  Empty strings and duplicates are ignored.
+	*/
+	pub fn new(puncts: &[&str]) -> Punctuators {
+		let mut puncts: Vec<String> = puncts.iter()
+			.filter(|p| p.len() > 0)
+			.map(|&p| p.to_owned())
+			.collect();
+		puncts.sort();
+		puncts.dedup();
+
+		let mut nodes = vec![Node { terminal: false, children: vec![] }];
+		for p in puncts.iter() {
+			let mut at = 0;
+			for ch in p.chars() {
+				let found = nodes[at].children.iter().find(|&&(c,_)| c == ch).map(|&(_, next)| next);
+				at = match found {
+					Some(next) => next,
+					None => {
+						let next = nodes.len();
+						nodes.push(Node { terminal: false, children: vec![] });
+						let children = &mut nodes[at].children;
+						let pos = children.iter().position(|&(c,_)| c > ch).unwrap_or(children.len());
+						children.insert(pos, (ch, next));
+						next
+					}
+				};
+			}
+			nodes[at].terminal = true;
+		}
+
+		Punctuators {
+			puncts: puncts,
+			nodes: nodes,
+		}
+	}
+
+	/**
+Returns the set of punctuators, sorted.
+	*/
+	pub fn puncts(&self) -> &[String] {
+		self.puncts.as_slice()
+	}
+}
+
+impl Show for Punctuators {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+		write!(f, "Punctuators({})", self.puncts)
+	}
+}
+
+impl Tokenizer for Punctuators {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		let mut at = 0;
+		let mut longest = None;
+
+		for (i, ch) in s.char_indices() {
+			let children = &self.nodes[at].children;
+			match children.iter().find(|&&(c,_)| c == ch) {
+				Some(&(_, next)) => at = next,
+				None => break
+			}
+			if self.nodes[at].terminal {
+				longest = Some(i + ch.len_utf8());
+			}
+		}
+
+		longest
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		self.token_len(s).map(|n| (n, TokenKind::Punct))
+	}
+}
+
+#[test]
+fn test_punctuators() {
+	let tok = Punctuators::new(&["<=", "<<", "<<=", "->", "::", "...", "..", "", "<="]);
+	let tl = |s:&str| tok.token_len(s);
+
+	let puncts: Vec<&str> = tok.puncts().iter().map(|p| p.as_slice()).collect();
+	assert_eq!(puncts, vec!["->", "..", "...", "::", "<<", "<<=", "<="]);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("<"), None);
+	assert_eq!(tl("."), None);
+	assert_eq!(tl(" <="), None);
+	assert_eq!(tl("<="), Some(2));
+	assert_eq!(tl("<= x"), Some(2));
+	assert_eq!(tl("<<"), Some(2));
+	assert_eq!(tl("<<="), Some(3));
+	assert_eq!(tl("<<=="), Some(3));
+	assert_eq!(tl("<-"), None);
+	assert_eq!(tl("->x"), Some(2));
+	assert_eq!(tl("::<"), Some(2));
+	assert_eq!(tl(".."), Some(2));
+	assert_eq!(tl("..."), Some(3));
+	assert_eq!(tl("...."), Some(3));
+	assert_eq!(tl("..="), Some(2));
+}
+
+#[test]
+fn test_punctuators_eq() {
+	assert!(Punctuators::new(&["->", "::"]) == Punctuators::new(&["::", "->", "::"]));
+	assert!(Punctuators::new(&["->", "::"]) != Punctuators::new(&["->"]));
+}
+
+/**
+Combines another tokeniser with a set of punctuators.  At each position, both are tried, and whichever produces the longer token is used; ties go to the inner tokeniser.
+
+For example, `Punctuated::new(IdentsAndInts, &["<=", "->", "::"])` will tokenise `"a::b<=10"` as `"a"`, `"::"`, `"b"`, `"<="`, `"10"`.
+*/
+#[derive(Clone, Eq, PartialEq, Show)]
+pub struct Punctuated<Tok> {
+	pub inner: Tok,
+	pub punct: Punctuators,
+}
+
+impl<Tok: Tokenizer> Punctuated<Tok> {
+	/**
+Construct a tokeniser from an inner tokeniser and a set of punctuators.
+	*/
+	pub fn new(inner: Tok, puncts: &[&str]) -> Punctuated<Tok> {
+		Punctuated {
+			inner: inner,
+			punct: Punctuators::new(puncts),
+		}
+	}
+}
+
+impl<Tok: Tokenizer> Tokenizer for Punctuated<Tok> {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		match (self.inner.token_len(s), self.punct.token_len(s)) {
+			(Some(a), Some(b)) => Some(::std::cmp::max(a, b)),
+			(a, b) => a.or(b)
+		}
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		match (self.inner.typed_token_len(s), self.punct.typed_token_len(s)) {
+			(Some(a), Some(b)) => Some(if b.0 > a.0 { b } else { a }),
+			(a, b) => a.or(b)
+		}
+	}
+}
+
+#[test]
+fn test_punctuated() {
+	use tokenizer::IdentsAndInts;
+
+	let tok = Punctuated::new(IdentsAndInts, &["<=", "->", "::", "..."]);
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("+"), None);
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc::def"), Some(3));
+	assert_eq!(tl("::def"), Some(2));
+	assert_eq!(tl("<=10"), Some(2));
+	assert_eq!(tl("10<=x"), Some(2));
+	assert_eq!(tl("...x"), Some(3));
+	assert_eq!(tl("->"), Some(2));
+
+	let ttl = |s:&str| tok.typed_token_len(s);
+
+	assert_eq!(ttl("abc::def"), Some((3, TokenKind::Ident)));
+	assert_eq!(ttl("::def"), Some((2, TokenKind::Punct)));
+	assert_eq!(ttl("10<=x"), Some((2, TokenKind::Number)));
+}
@@ -0,0 +1,186 @@
+/*!
+This module provides tokenisers which treat quoted strings as single tokens, along with a routine for recovering the contents of such a token.
+*/
+use super::{TokenKind, Tokenizer};
+
+/**
+Tokenises single- or double-quoted strings.  A quoted string begins with either `'` or `"`, and ends with the next unescaped occurrence of the same character.  Within the string, a backslash escapes the following code point, so `"say \"hi\""` is a single token.
+
+The resulting token *includes* the quotes; use `unquote` to recover the contents.  An unterminated quoted string is not a token.
+
+Anything other than a quoted string is not a token; combine this with another tokeniser using `Or` to handle everything else.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct Quoted;
+
+impl Tokenizer for Quoted {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		quoted_len(s)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		quoted_len(s).map(|n| (n, TokenKind::Str))
+	}
+}
+
+#[test]
+fn test_quoted() {
+	let tl = |s:&str| Quoted.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl("abc"), None);
+	assert_eq!(tl(" \"abc\""), None);
+	assert_eq!(tl("\""), None);
+	assert_eq!(tl("\"abc"), None);
+	assert_eq!(tl("\"\""), Some(2));
+	assert_eq!(tl("''"), Some(2));
+	assert_eq!(tl("\"hello world\" x"), Some(13));
+	assert_eq!(tl("'hello world' x"), Some(13));
+	assert_eq!(tl("'it\"s' x"), Some(6));
+	assert_eq!(tl("\"it's\" x"), Some(6));
+	assert_eq!(tl(r#""say \"hi\"" x"#), Some(12));
+	assert_eq!(tl(r#"'it\'s' x"#), Some(7));
+	assert_eq!(tl(r#""a\\" b""#), Some(5));
+	assert_eq!(tl(r#""a\""#), None);
+	assert_eq!(tl("\"日本語\""), Some(11));
+
+	assert_eq!(Quoted.typed_token_len("'abc' x"), Some((5, TokenKind::Str)));
+}
+
+/**
+Tokenises a string into quoted strings (as with `Quoted`) and runs of code points which do not satisfy the `White_Space` property and are not quotes (as with `SpaceDelimited`).
+
+For example, `say "hello world" 'and goodbye'` is tokenised as `say`, `"hello world"`, `'and goodbye'`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct QuotedOrSpaceDelimited;
+
+impl Tokenizer for QuotedOrSpaceDelimited {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.typed_token_len(s).map(|(n,_)| n)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		match quoted_len(s) {
+			Some(n) => Some((n, TokenKind::Str)),
+			None => super::super::len_while(s, |ch| !(ch.is_whitespace() || ch == '"' || ch == '\''))
+				.map(|n| (n, TokenKind::Other))
+		}
+	}
+}
+
+#[test]
+fn test_quoted_or_space_delimited() {
+	let tl = |s:&str| QuotedOrSpaceDelimited.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("\"abc"), None);
+	assert_eq!(tl("abc def"), Some(3));
+	assert_eq!(tl("abc\"def\""), Some(3));
+	assert_eq!(tl("\"hello world\" x"), Some(13));
+	assert_eq!(tl("'a b'c"), Some(5));
+
+	let ttl = |s:&str| QuotedOrSpaceDelimited.typed_token_len(s);
+
+	assert_eq!(ttl("abc def"), Some((3, TokenKind::Other)));
+	assert_eq!(ttl("'a b'c"), Some((5, TokenKind::Str)));
+}
+
+/**
+Returns the length of the quoted string at the start of `s`, if there is one.  See `Quoted` for details.
+*/
+pub fn quoted_len(s: &str) -> Option<uint> {
+	quoted_len_at(s, 0, true)
+}
+
+/**
+Returns the offset just past the quoted string whose opening quote is at `start` in `s`, if there is one.  Quoted strings are recognised as for `Quoted`, except that if `multiline` is `false`, an unescaped line feed means the string is unterminated.
+
+This is intended for tokenisers of programming languages, where a quoted literal may have a prefix (such as `b"abc"`) or may not be allowed to span lines.
+*/
+pub fn quoted_len_at(s: &str, start: uint, multiline: bool) -> Option<uint> {
+	let mut chars = s.slice_from(start).char_indices();
+	let quote = match chars.next() {
+		Some((_, q @ '"')) | Some((_, q @ '\'')) => q,
+		_ => return None
+	};
+
+	let mut escaped = false;
+	for (i, ch) in chars {
+		if escaped {
+			escaped = false;
+		} else if ch == '\\' {
+			escaped = true;
+		} else if ch == '\n' && !multiline {
+			return None;
+		} else if ch == quote {
+			return Some(start + i + 1);
+		}
+	}
+
+	None
+}
+
+#[test]
+fn test_quoted_len_at() {
+	assert_eq!(quoted_len_at("x", 0, true), None);
+	assert_eq!(quoted_len_at("b\"abc\" x", 0, true), None);
+	assert_eq!(quoted_len_at("b\"abc\" x", 1, true), Some(6));
+	assert_eq!(quoted_len_at("L'x'", 1, false), Some(4));
+	assert_eq!(quoted_len_at("\"a\nb\"", 0, true), Some(5));
+	assert_eq!(quoted_len_at("\"a\nb\"", 0, false), None);
+	assert_eq!(quoted_len_at("\"a\\\nb\"", 0, false), Some(6));
+}
+
+/**
+If `tok` is a complete quoted string (as recognised by `Quoted`), returns its contents with escapes processed.  Otherwise, returns `None`.
+
+The recognised escapes are `\n`, `\r`, `\t` and `\0`; a backslash followed by any other code point produces that code point, so `\\`, `\'` and `\"` work as expected.
+*/
+pub fn unquote(tok: &str) -> Option<String> {
+	match quoted_len(tok) {
+		Some(n) if n == tok.len() => (),
+		_ => return None
+	}
+
+	let mut out = String::with_capacity(tok.len() - 2);
+	let mut escaped = false;
+	for ch in tok.slice(1, tok.len() - 1).chars() {
+		if escaped {
+			out.push(match ch {
+				'n' => '\n',
+				'r' => '\r',
+				't' => '\t',
+				'0' => '\0',
+				ch => ch
+			});
+			escaped = false;
+		} else if ch == '\\' {
+			escaped = true;
+		} else {
+			out.push(ch);
+		}
+	}
+
+	Some(out)
+}
+
+#[test]
+fn test_unquote() {
+	use std::borrow::ToOwned;
+
+	let some = |s:&str| Some(s.to_owned());
+
+	assert_eq!(unquote(""), None);
+	assert_eq!(unquote("abc"), None);
+	assert_eq!(unquote("\"abc"), None);
+	assert_eq!(unquote("\"abc\" "), None);
+	assert_eq!(unquote("\"abc\"def\""), None);
+	assert_eq!(unquote("\"\""), some(""));
+	assert_eq!(unquote("\"hello world\""), some("hello world"));
+	assert_eq!(unquote("'hello world'"), some("hello world"));
+	assert_eq!(unquote(r#""say \"hi\"""#), some("say \"hi\""));
+	assert_eq!(unquote(r#"'it\'s'"#), some("it's"));
+	assert_eq!(unquote(r#""a\tb\nc\\d\qe""#), some("a\tb\nc\\dqe"));
+}
@@ -0,0 +1,142 @@
+/*!
+This module provides a `Tokenizer` driven by regular expressions.
+
+It is only available when the `regex` feature is enabled.
+*/
+use std::fmt::{self, Show, Formatter};
+
+use regex::{self, Regex};
+
+use super::Tokenizer;
+
+/**
+Determines how a `RegexTokenizer` chooses between several patterns which match at the same position.
+*/
+#[derive(Clone, Copy, Eq, PartialEq, Show)]
+pub enum MatchMode {
+	/**
+Use the first pattern (in the order they were given) which matches.
+	*/
+	First,
+	/**
+Use whichever pattern produces the longest match.  Ties are broken in favour of the pattern given first.
+	*/
+	Longest,
+}
+
+/**
+Tokenises a string using an ordered list of regular expressions.  Each pattern is anchored to the start of the input, so `[A-Za-z_][\w-]*` will match an identifier at the current position, never one further along.
+
+Patterns which match the empty string are treated as not having matched at all.  If no pattern matches, `token_len` returns `None`, and `ScanCursor::pop_token` will fall back to producing a single code point token, as usual.
+
+Note that this cannot be used as a compile-time tokeniser; it exists so that token classes can be configured at runtime.
+*/
+#[derive(Clone)]
+pub struct RegexTokenizer {
+	regexes: Vec<Regex>,
+	mode: MatchMode,
+}
+
+impl RegexTokenizer {
+	/**
+Construct a tokeniser from a list of patterns.  This will fail if any of the patterns is not a valid regular expression.
+	*/
+	pub fn new(patterns: &[&str], mode: MatchMode) -> Result<RegexTokenizer, regex::Error> {
+		let mut regexes = Vec::with_capacity(patterns.len());
+		for pat in patterns.iter() {
+			regexes.push(try!(Regex::new(format!("^(?:{})", pat).as_slice())));
+		}
+
+		Ok(RegexTokenizer {
+			regexes: regexes,
+			mode: mode,
+		})
+	}
+}
+
+impl PartialEq for RegexTokenizer {
+	fn eq(&self, other: &RegexTokenizer) -> bool {
+		self.mode == other.mode
+			&& self.regexes.len() == other.regexes.len()
+			&& self.regexes.iter().zip(other.regexes.iter()).all(|(a, b)| a.as_str() == b.as_str())
+	}
+}
+
+impl Eq for RegexTokenizer {}
+
+impl Show for RegexTokenizer {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+		try!(write!(f, "RegexTokenizer {{ patterns: ["));
+		for (i, re) in self.regexes.iter().enumerate() {
+			if i > 0 { try!(write!(f, ", ")); }
+			try!(write!(f, "{}", re.as_str().escape_default()));
+		}
+		write!(f, "], mode: {} }}", self.mode)
+	}
+}
+
+impl Tokenizer for RegexTokenizer {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		let mut best = None;
+
+		for re in self.regexes.iter() {
+			match re.find(s) {
+				Some((_, end)) if end > 0 => match self.mode {
+					MatchMode::First => return Some(end),
+					MatchMode::Longest => if best.map(|b| end > b).unwrap_or(true) {
+						best = Some(end);
+					}
+				},
+				_ => ()
+			}
+		}
+
+		best
+	}
+}
+
+#[test]
+fn test_regex_tokenizer_first() {
+	let tok = RegexTokenizer::new(&[r"\d+", r"\d+\.\d+", r"[A-Za-z_][\w-]*"], MatchMode::First).unwrap();
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("-"), None);
+	assert_eq!(tl("abc"), Some(3));
+	assert_eq!(tl("abc-def ghi"), Some(7));
+	assert_eq!(tl("_a1"), Some(3));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123.456"), Some(3));
+}
+
+#[test]
+fn test_regex_tokenizer_longest() {
+	let tok = RegexTokenizer::new(&[r"\d+", r"\d+\.\d+", r"[A-Za-z_][\w-]*", r"x*"], MatchMode::Longest).unwrap();
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("abc-def ghi"), Some(7));
+	assert_eq!(tl("123"), Some(3));
+	assert_eq!(tl("123.456"), Some(7));
+	assert_eq!(tl("123.x"), Some(3));
+	assert_eq!(tl("xx"), Some(2));
+}
+
+#[test]
+fn test_regex_tokenizer_invalid() {
+	assert!(RegexTokenizer::new(&[r"\d+", r"(unclosed"], MatchMode::First).is_err());
+}
+
+#[test]
+fn test_regex_tokenizer_eq() {
+	let a = RegexTokenizer::new(&[r"\d+", r"\w+"], MatchMode::First).unwrap();
+	let b = RegexTokenizer::new(&[r"\d+", r"\w+"], MatchMode::First).unwrap();
+	let c = RegexTokenizer::new(&[r"\d+", r"\w+"], MatchMode::Longest).unwrap();
+	let d = RegexTokenizer::new(&[r"\w+", r"\d+"], MatchMode::First).unwrap();
+
+	assert!(a == b);
+	assert!(a != c);
+	assert!(a != d);
+}
@@ -0,0 +1,293 @@
+/*!
+This module provides a tokeniser which follows the lexical grammar of Rust.
+
+It should be paired with the `whitespace::RustComments` policy, so that comments are skipped along with whitespace.
+*/
+use super::{Punctuators, TokenKind, Tokenizer};
+use super::quoted::quoted_len_at;
+use super::super::len_while;
+
+/**
+The multi-character punctuation tokens of Rust.  `RustTokens` matches these using a `Punctuators` tokeniser.
+*/
+pub static RUST_PUNCTUATION: &'static [&'static str] = &[
+	"<<=", ">>=", "...", "..=",
+	"::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+/**
+Tokenises a string according to the lexical grammar of Rust.  Specifically, this recognises:
+
+- identifiers, including raw identifiers (`r#match`),
+- lifetimes (`'a`, `'static`),
+- character and byte literals (`'x'`, `'\n'`, `b'\x7f'`),
+- string, byte string and raw string literals (`"..."`, `b"..."`, `r#"..."#`, `br"..."`),
+- integer and floating point literals, including radix prefixes, underscores, exponents and suffixes (`0xff_u8`, `1.5e-3f64`),
+- multi-character punctuation (`::`, `->`, `<<=`, and so on; see `RUST_PUNCTUATION`), using longest match.
+
+Single-character punctuation is left to the usual single code point fallback.  Comments are *not* handled here; use `whitespace::RustComments` to skip them.
+
+Unterminated string and character literals are not tokens; the opening quote will become a single code point token instead.
+*/
+#[derive(Clone, Eq, PartialEq, Show)]
+pub struct RustTokens {
+	punct: Punctuators,
+}
+
+impl RustTokens {
+	/**
+Construct a Rust tokeniser.
+	*/
+	pub fn new() -> RustTokens {
+		RustTokens {
+			punct: Punctuators::new(RUST_PUNCTUATION),
+		}
+	}
+}
+
+impl Default for RustTokens {
+	fn default() -> RustTokens {
+		RustTokens::new()
+	}
+}
+
+impl Tokenizer for RustTokens {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		self.typed_token_len(s).map(|(n,_)| n)
+	}
+
+	fn typed_token_len(&self, s: &str) -> Option<(uint, TokenKind)> {
+		if s.len() == 0 {
+			return None;
+		}
+
+		let ch0 = s.char_at(0);
+
+		if ch0 == 'r' || ch0 == 'b' {
+			if let Some(tk) = raw_or_byte_literal_len(s) {
+				return Some(tk);
+			}
+		}
+
+		if ch0 == '_' || ch0.is_xid_start() {
+			ident_len(s).map(|n| (n, TokenKind::Ident))
+		} else if ch0.is_digit(10) {
+			Some((number_len(s), TokenKind::Number))
+		} else if ch0 == '\'' {
+			char_literal_len(s, 1).map(|n| (n, TokenKind::Char))
+				.or_else(|| lifetime_len(s).map(|n| (n, TokenKind::Lifetime)))
+		} else if ch0 == '"' {
+			quoted_len_at(s, 0, true).map(|n| (n, TokenKind::Str))
+		} else {
+			self.punct.typed_token_len(s)
+		}
+	}
+}
+
+fn ident_len(s: &str) -> Option<uint> {
+	len_while(s, |ch| ch == '_' || ch.is_xid_continue())
+}
+
+fn is_ident_start(s: &str) -> bool {
+	s.len() > 0 && {
+		let ch = s.char_at(0);
+		ch == '_' || ch.is_xid_start()
+	}
+}
+
+/// Handles raw identifiers, raw strings, byte literals and byte strings.
+fn raw_or_byte_literal_len(s: &str) -> Option<(uint, TokenKind)> {
+	if s.starts_with("r#") && is_ident_start(s.slice_from(2)) {
+		ident_len(s.slice_from(2)).map(|n| (n + 2, TokenKind::Ident))
+	} else if s.starts_with("r\"") || s.starts_with("r#") {
+		raw_string_len(s, 1).map(|n| (n, TokenKind::Str))
+	} else if s.starts_with("br\"") || s.starts_with("br#") {
+		raw_string_len(s, 2).map(|n| (n, TokenKind::Str))
+	} else if s.starts_with("b'") {
+		char_literal_len(s, 2).map(|n| (n, TokenKind::Char))
+	} else if s.starts_with("b\"") {
+		quoted_len_at(s, 1, true).map(|n| (n, TokenKind::Str))
+	} else {
+		None
+	}
+}
+
+/// Scans a raw string whose hashes start at `start`.
+fn raw_string_len(s: &str, start: uint) -> Option<uint> {
+	let hashes = s.slice_from(start).chars().take_while(|&ch| ch == '#').count();
+	let body = start + hashes;
+	if !s.slice_from(body).starts_with("\"") {
+		return None;
+	}
+
+	let close: String = Some('"').into_iter().chain(range(0, hashes).map(|_| '#')).collect();
+	s.slice_from(body + 1).find_str(close.as_slice()).map(|i| body + 1 + i + close.len())
+}
+
+/// Scans a character literal whose contents start at `start`.  The contents must be either a single code point or an escape sequence.
+fn char_literal_len(s: &str, start: uint) -> Option<uint> {
+	let body = s.slice_from(start);
+	if body.starts_with("\\") {
+		// An escape sequence; these never contain a quote, other than `\'` itself.
+		let end = if body.starts_with("\\'") { 2 } else { 1 };
+		body.slice_from(end).find('\'').map(|i| start + end + i + 1)
+			.and_then(|n| if s.slice(start, n).contains_char('\n') { None } else { Some(n) })
+	} else if body.len() > 0 {
+		let ch = body.char_at(0);
+		let n = ch.len_utf8();
+		if ch != '\'' && ch != '\n' && body.slice_from(n).starts_with("'") {
+			Some(start + n + 1)
+		} else {
+			None
+		}
+	} else {
+		None
+	}
+}
+
+fn lifetime_len(s: &str) -> Option<uint> {
+	if is_ident_start(s.slice_from(1)) {
+		ident_len(s.slice_from(1)).map(|n| n + 1)
+	} else {
+		None
+	}
+}
+
+/// Scans a numeric literal, which must start with a decimal digit.
+fn number_len(s: &str) -> uint {
+	let b = s.as_bytes();
+	let digits = |&: i: uint, radix: uint| {
+		let mut i = i;
+		while i < b.len() && ((b[i] as char).is_digit(radix) || b[i] == b'_') {
+			i += 1;
+		}
+		i
+	};
+
+	let mut i;
+	let mut decimal = true;
+
+	if b.len() > 2 && b[0] == b'0' && (b[1] == b'x' || b[1] == b'o' || b[1] == b'b') {
+		let radix = match b[1] { b'x' => 16, b'o' => 8, _ => 2 };
+		i = digits(2, radix);
+		decimal = false;
+	} else {
+		i = digits(0, 10);
+	}
+
+	if decimal {
+		// A decimal point is only part of the literal if it isn't the start of a range or a field/method access.
+		if i < b.len() && b[i] == b'.' && !(i + 1 < b.len() && (b[i+1] == b'.' || is_ident_start(s.slice_from(i+1)))) {
+			i = digits(i + 1, 10);
+		}
+
+		if i < b.len() && (b[i] == b'e' || b[i] == b'E') {
+			let mut j = i + 1;
+			if j < b.len() && (b[j] == b'+' || b[j] == b'-') {
+				j += 1;
+			}
+			if j < b.len() && (b[j] as char).is_digit(10) {
+				i = digits(j, 10);
+			}
+		}
+	}
+
+	// Any identifier immediately following is a suffix.
+	if is_ident_start(s.slice_from(i)) {
+		i += ident_len(s.slice_from(i)).unwrap_or(0);
+	}
+
+	i
+}
+
+#[test]
+fn test_rust_tokens() {
+	let tok = RustTokens::new();
+	let tl = |s:&str| tok.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" x"), None);
+	assert_eq!(tl("+"), None);
+	assert_eq!(tl("#"), None);
+
+	// Identifiers.
+	assert_eq!(tl("foo bar"), Some(3));
+	assert_eq!(tl("_foo_1"), Some(6));
+	assert_eq!(tl("_"), Some(1));
+	assert_eq!(tl("r#match"), Some(7));
+	assert_eq!(tl("r #match"), Some(1));
+	assert_eq!(tl("b"), Some(1));
+	assert_eq!(tl("bar"), Some(3));
+	assert_eq!(tl("rust"), Some(4));
+	assert_eq!(tl("日本語 x"), Some(9));
+
+	// Lifetimes and characters.
+	assert_eq!(tl("'a"), Some(2));
+	assert_eq!(tl("'static T"), Some(7));
+	assert_eq!(tl("'a'"), Some(3));
+	assert_eq!(tl("'a' x"), Some(3));
+	assert_eq!(tl("'日'"), Some(5));
+	assert_eq!(tl("'\\n'"), Some(4));
+	assert_eq!(tl("'\\''"), Some(4));
+	assert_eq!(tl("'\\\\'"), Some(4));
+	assert_eq!(tl("'\\u{1f600}'"), Some(11));
+	assert_eq!(tl("' '"), Some(3));
+	assert_eq!(tl("'"), None);
+	assert_eq!(tl("''"), None);
+	assert_eq!(tl("'1"), None);
+	assert_eq!(tl("b'x'"), Some(4));
+	assert_eq!(tl("b'\\x7f'"), Some(7));
+
+	// Strings.
+	assert_eq!(tl("\"\""), Some(2));
+	assert_eq!(tl("\"abc\" x"), Some(5));
+	assert_eq!(tl("\"a\\\"b\" x"), Some(6));
+	assert_eq!(tl("\"a\nb\""), Some(5));
+	assert_eq!(tl("\"abc"), None);
+	assert_eq!(tl("b\"abc\""), Some(6));
+	assert_eq!(tl("r\"a\\b\" x"), Some(6));
+	assert_eq!(tl("r#\"a\"b\"# x"), Some(8));
+	assert_eq!(tl("r##\"a\"#b\"## x"), Some(11));
+	assert_eq!(tl("r#\"abc\""), Some(1));
+	assert_eq!(tl("br\"abc\""), Some(7));
+	assert_eq!(tl("br#\"abc\"#"), Some(9));
+
+	// Numbers.
+	assert_eq!(tl("0"), Some(1));
+	assert_eq!(tl("123 456"), Some(3));
+	assert_eq!(tl("1_000_000"), Some(9));
+	assert_eq!(tl("0xff_u8"), Some(7));
+	assert_eq!(tl("0o777"), Some(5));
+	assert_eq!(tl("0b1010i32"), Some(9));
+	assert_eq!(tl("1.5"), Some(3));
+	assert_eq!(tl("1.5e-3f64"), Some(9));
+	assert_eq!(tl("1e10"), Some(4));
+	assert_eq!(tl("1."), Some(2));
+	assert_eq!(tl("1. "), Some(2));
+	assert_eq!(tl("1..2"), Some(1));
+	assert_eq!(tl("1.foo()"), Some(1));
+	assert_eq!(tl("1.0.foo()"), Some(3));
+	assert_eq!(tl("42usize"), Some(7));
+	assert_eq!(tl("tup.0.1"), Some(3));
+
+	// Punctuation.
+	assert_eq!(tl("::x"), Some(2));
+	assert_eq!(tl("->x"), Some(2));
+	assert_eq!(tl("<<=x"), Some(3));
+	assert_eq!(tl("<<x"), Some(2));
+	assert_eq!(tl("<x"), None);
+	assert_eq!(tl("..=x"), Some(3));
+	assert_eq!(tl("...x"), Some(3));
+	assert_eq!(tl("..x"), Some(2));
+	assert_eq!(tl("&&x"), Some(2));
+
+	let ttl = |s:&str| tok.typed_token_len(s);
+
+	assert_eq!(ttl("r#match"), Some((7, TokenKind::Ident)));
+	assert_eq!(ttl("'static T"), Some((7, TokenKind::Lifetime)));
+	assert_eq!(ttl("'a'"), Some((3, TokenKind::Char)));
+	assert_eq!(ttl("b'x'"), Some((4, TokenKind::Char)));
+	assert_eq!(ttl("br\"abc\""), Some((7, TokenKind::Str)));
+	assert_eq!(ttl("0xff_u8"), Some((7, TokenKind::Number)));
+	assert_eq!(ttl("::x"), Some((2, TokenKind::Punct)));
+}
@@ -0,0 +1,264 @@
+/*!
+This module provides a tokeniser which follows POSIX shell word-splitting rules, along with routines for turning such words into arguments.
+*/
+use super::Tokenizer;
+use super::super::{ScanResult, OtherScanError};
+
+/**
+Tokenises a string into words according to the quoting rules of the POSIX shell.  A word is a sequence of any of the following, ending at the first unquoted code point which satisfies the `White_Space` property:
+
+- an unquoted code point,
+- a backslash followed by any code point,
+- a single-quoted string, in which backslashes have no special meaning,
+- a double-quoted string, in which a backslash escapes the following code point.
+
+Adjacent segments belong to the same word, so `'a b'"c"d` is a single token.  The resulting token is the raw text of the word, quotes and all; use `unquote_word` to recover the argument it denotes.
+
+If a quoted string is not terminated, the word extends to the end of the input.
+
+Note that no other shell syntax (such as variable expansion, globbing or operators like `|` and `;`) is interpreted; all of these are treated as ordinary code points.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct ShellWords;
+
+impl Tokenizer for ShellWords {
+	fn token_len(&self, s: &str) -> Option<uint> {
+		match word_len(s) {
+			(0, _) => None,
+			(n, _) => Some(n)
+		}
+	}
+}
+
+#[test]
+fn test_shell_words() {
+	let tl = |s:&str| ShellWords.token_len(s);
+
+	assert_eq!(tl(""), None);
+	assert_eq!(tl(" abc"), None);
+	assert_eq!(tl("abc def"), Some(3));
+	assert_eq!(tl("a|b c"), Some(3));
+	assert_eq!(tl("'a b' c"), Some(5));
+	assert_eq!(tl("\"a b\" c"), Some(5));
+	assert_eq!(tl("'a b'\"c d\"e f"), Some(11));
+	assert_eq!(tl(r"a\ b c"), Some(4));
+	assert_eq!(tl(r#""a\" b" c"#), Some(7));
+	assert_eq!(tl(r"'a\' b"), Some(4));
+	assert_eq!(tl("'a b"), Some(4));
+	assert_eq!(tl("\"\" x"), Some(2));
+	assert_eq!(tl("\\"), Some(1));
+	assert_eq!(tl("a\\\nb c"), Some(4));
+	assert_eq!(tl("a\\\r\nb c"), Some(5));
+}
+
+/**
+Returns the length of the word at the start of `s`, and whether all quotes in it were terminated.
+*/
+fn word_len(s: &str) -> (uint, bool) {
+	#[derive(Copy, Eq, PartialEq)]
+	enum State { Bare, Escape, Single, Double, DoubleEscape }
+
+	let mut state = State::Bare;
+	let mut chars = s.char_indices().peekable();
+
+	while let Some((i, ch)) = chars.next() {
+		// An escaped CRLF is a line continuation, just like an escaped line feed, so the line feed is part of the escape.
+		if ch == '\r' && (state == State::Escape || state == State::DoubleEscape) && chars.peek().map(|&(_, ch)| ch) == Some('\n') {
+			chars.next();
+		}
+
+		state = match (state, ch) {
+			(State::Bare, '\\') => State::Escape,
+			(State::Bare, '\'') => State::Single,
+			(State::Bare, '"') => State::Double,
+			(State::Bare, ch) if ch.is_whitespace() => return (i, true),
+			(State::Bare, _) => State::Bare,
+			(State::Escape, _) => State::Bare,
+			(State::Single, '\'') => State::Bare,
+			(State::Single, _) => State::Single,
+			(State::Double, '\\') => State::DoubleEscape,
+			(State::Double, '"') => State::Bare,
+			(State::Double, _) => State::Double,
+			(State::DoubleEscape, _) => State::Double,
+		};
+	}
+
+	(s.len(), state == State::Bare || state == State::Escape)
+}
+
+/**
+Returns the argument denoted by a shell word, with quotes removed and escapes processed according to POSIX shell rules.  Returns `None` if the word contains an unterminated quoted string.
+
+Outside of quotes, a backslash escapes any following code point; inside double quotes, it only escapes `$`, `` ` ``, `"`, `\` and a line feed, and is otherwise kept.  In both cases, a backslash followed by a line feed (or by a carriage return and line feed) is removed entirely.
+*/
+pub fn unquote_word(word: &str) -> Option<String> {
+	let mut out = String::with_capacity(word.len());
+	let mut chars = word.chars();
+
+	loop {
+		let ch = match chars.next() {
+			Some(ch) => ch,
+			None => return Some(out)
+		};
+
+		match ch {
+			'\\' => match chars.next() {
+				Some('\n') => (),
+				Some('\r') if chars.clone().next() == Some('\n') => { chars.next(); },
+				Some(ch) => out.push(ch),
+				None => out.push('\\')
+			},
+			'\'' => loop {
+				match chars.next() {
+					Some('\'') => break,
+					Some(ch) => out.push(ch),
+					None => return None
+				}
+			},
+			'"' => loop {
+				match chars.next() {
+					Some('"') => break,
+					Some('\\') => match chars.next() {
+						Some('\n') => (),
+						Some('\r') if chars.clone().next() == Some('\n') => { chars.next(); },
+						Some(ch @ '$') | Some(ch @ '`') | Some(ch @ '"') | Some(ch @ '\\') => out.push(ch),
+						Some(ch) => {
+							out.push('\\');
+							out.push(ch);
+						},
+						None => return None
+					},
+					Some(ch) => out.push(ch),
+					None => return None
+				}
+			},
+			ch => out.push(ch)
+		}
+	}
+}
+
+#[test]
+fn test_unquote_word() {
+	use std::borrow::ToOwned;
+
+	let some = |s:&str| Some(s.to_owned());
+
+	assert_eq!(unquote_word(""), some(""));
+	assert_eq!(unquote_word("abc"), some("abc"));
+	assert_eq!(unquote_word("''"), some(""));
+	assert_eq!(unquote_word("'a b'\"c d\"e"), some("a bc de"));
+	assert_eq!(unquote_word(r"a\ b"), some("a b"));
+	assert_eq!(unquote_word(r"a\\b"), some(r"a\b"));
+	assert_eq!(unquote_word(r"'a\b'"), some(r"a\b"));
+	assert_eq!(unquote_word(r#""a\"b\\c\$d\e""#), some(r#"a"b\c$d\e"#));
+	assert_eq!(unquote_word("a\\\nb"), some("ab"));
+	assert_eq!(unquote_word("\"a\\\nb\""), some("ab"));
+	assert_eq!(unquote_word("'a\\\nb'"), some("a\\\nb"));
+	assert_eq!(unquote_word("a\\\r\nb"), some("ab"));
+	assert_eq!(unquote_word("\"a\\\r\nb\""), some("ab"));
+	assert_eq!(unquote_word("a\\\rb"), some("a\rb"));
+	assert_eq!(unquote_word("\\"), some("\\"));
+	assert_eq!(unquote_word("'abc"), None);
+	assert_eq!(unquote_word("\"abc"), None);
+	assert_eq!(unquote_word("\"abc\\\""), None);
+}
+
+/**
+Splits a line into arguments according to POSIX shell word-splitting rules; that is, into words as recognised by `ShellWords`, each of which is then passed through `unquote_word`.  Line continuations (a backslash followed by a line feed, or by a carriage return and line feed) between words are skipped.
+
+Fails if the line contains an unterminated quoted string.
+*/
+pub fn split_words(line: &str) -> ScanResult<Vec<String>> {
+	use std::borrow::ToOwned;
+
+	let mut args = vec![];
+	let mut at = 0;
+
+	loop {
+		// Skip whitespace and line continuations between words.
+		loop {
+			let rest = line.slice_from(at);
+			if rest.starts_with("\\\n") {
+				at += 2;
+			} else if rest.starts_with("\\\r\n") {
+				at += 3;
+			} else if rest.len() > 0 && rest.char_at(0).is_whitespace() {
+				at += rest.char_at(0).len_utf8();
+			} else {
+				break;
+			}
+		}
+
+		let rest = line.slice_from(at);
+		let (n, complete) = word_len(rest);
+		if n == 0 {
+			return Ok(args);
+		}
+
+		match unquote_word(rest.slice_to(n)) {
+			Some(ref arg) if complete => args.push(arg.clone()),
+			_ => return Err(OtherScanError("unterminated quoted string".to_owned(), at))
+		}
+		at += n;
+	}
+}
+
+/**
+Returns `true` if the given line ends part-way through a shell word; that is, inside a quoted string, or with a line continuation.  This is used to decide whether a command continues onto another line.
+*/
+pub fn is_incomplete(line: &str) -> bool {
+	let mut at = 0;
+	loop {
+		let rest = line.slice_from(at);
+		let ws = rest.char_indices().find(|&(_, ch)| !ch.is_whitespace()).map(|(i, _)| i).unwrap_or(rest.len());
+		let rest = rest.slice_from(ws);
+		if rest.len() == 0 {
+			return false;
+		}
+		match word_len(rest) {
+			(n, true) if n == rest.len() => return rest.ends_with("\\\n") || rest.ends_with("\\\r\n") || rest == "\\",
+			(n, true) => at += ws + n,
+			(_, false) => return true
+		}
+	}
+}
+
+#[test]
+fn test_split_words() {
+	use std::borrow::ToOwned;
+
+	fn ok(v: &[&str]) -> ScanResult<Vec<String>> {
+		Ok(v.iter().map(|&s| s.to_owned()).collect())
+	}
+
+	let split = |s: &str| split_words(s);
+
+	assert_eq!(split(""), ok(&[]));
+	assert_eq!(split("   \t "), ok(&[]));
+	assert_eq!(split("ls -l"), ok(&["ls", "-l"]));
+	assert_eq!(split("  set name 'John Smith'\n"), ok(&["set", "name", "John Smith"]));
+	assert_eq!(split("echo \"a b\"'c d'e f"), ok(&["echo", "a bc de", "f"]));
+	assert_eq!(split("echo a\\ b"), ok(&["echo", "a b"]));
+	assert_eq!(split("echo '' \"\""), ok(&["echo", "", ""]));
+	assert_eq!(split("echo a \\\n b"), ok(&["echo", "a", "b"]));
+	assert_eq!(split("echo a \\\r\n b"), ok(&["echo", "a", "b"]));
+	assert_eq!(split("echo a\\\r\nb\r\n"), ok(&["echo", "ab"]));
+	assert_eq!(split("echo 'a"), Err(OtherScanError("unterminated quoted string".to_owned(), 5)));
+	assert_eq!(split("echo \"a\\\""), Err(OtherScanError("unterminated quoted string".to_owned(), 5)));
+}
+
+#[test]
+fn test_is_incomplete() {
+	assert_eq!(is_incomplete(""), false);
+	assert_eq!(is_incomplete("ls -l\n"), false);
+	assert_eq!(is_incomplete("echo 'a b'\n"), false);
+	assert_eq!(is_incomplete("echo 'a b\n"), true);
+	assert_eq!(is_incomplete("echo \"a b\n"), true);
+	assert_eq!(is_incomplete("echo a \\\n"), true);
+	assert_eq!(is_incomplete("echo a\\\n"), true);
+	assert_eq!(is_incomplete("echo a\\\\\n"), false);
+	assert_eq!(is_incomplete("echo a \\\r\n"), true);
+	assert_eq!(is_incomplete("echo a\\\r\n"), true);
+	assert_eq!(is_incomplete("echo a\\\\\r\n"), false);
+	assert_eq!(is_incomplete("echo 'a b'\r\n"), false);
+}
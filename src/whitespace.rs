@@ -195,3 +195,110 @@ fn test_ws_exact() {
 	assert_eq!(sp("\r\n"), (0, Some((2, "\r\n"))));
 	assert_eq!(sp(" \t\r\n  x "), (0, Some((1, " "))));
 }
+
+/**
+This policy skips over all codepoints that satisfy the `White_Space` property, as well as Rust comments.  That is, line comments (`// ...`) up to the end of the line, and block comments (`/* ... */`), which may be nested.  Doc comments are treated as ordinary comments.
+
+An unterminated block comment extends to the end of the input.
+
+This is intended to be used with `tokenizer::RustTokens`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct RustComments;
+
+impl Whitespace for RustComments {
+	fn strip_len(&self, s: &str) -> uint {
+		comments_len(s, true)
+	}
+}
+
+#[test]
+fn test_ws_rust_comments() {
+	fn sp<'a>(s: &'a str) -> (uint, Option<(uint, &'a str)>) {
+		(RustComments.strip_len(s), RustComments.token_len(s))
+	}
+
+	assert_eq!(sp(""), (0, None));
+	assert_eq!(sp(" "), (1, None));
+	assert_eq!(sp(" \t\r\n  x "), (6, None));
+	assert_eq!(sp("x // y"), (0, None));
+	assert_eq!(sp("/ x"), (0, None));
+	assert_eq!(sp("// comment\nx"), (11, None));
+	assert_eq!(sp("/// doc\r\n  // more\nx"), (19, None));
+	assert_eq!(sp("// comment"), (10, None));
+	assert_eq!(sp("/* a */x"), (7, None));
+	assert_eq!(sp("/* a */ /* b */ x"), (16, None));
+	assert_eq!(sp("/* a /* b */ c */x"), (17, None));
+	assert_eq!(sp("/**/x"), (4, None));
+	assert_eq!(sp("/*/ x */x"), (8, None));
+	assert_eq!(sp("/* a /* b */ x"), (14, None));
+}
+
+/**
+This policy skips over all codepoints that satisfy the `White_Space` property, as well as C comments.  That is, line comments (`// ...`) up to the end of the line, and block comments (`/* ... */`), which *do not* nest.
+
+An unterminated block comment extends to the end of the input.
+
+This is intended to be used with `tokenizer::CTokens`.
+*/
+#[derive(Clone, Copy, Default, Eq, PartialEq, Show)]
+pub struct CComments;
+
+impl Whitespace for CComments {
+	fn strip_len(&self, s: &str) -> uint {
+		comments_len(s, false)
+	}
+}
+
+#[test]
+fn test_ws_c_comments() {
+	fn sp<'a>(s: &'a str) -> (uint, Option<(uint, &'a str)>) {
+		(CComments.strip_len(s), CComments.token_len(s))
+	}
+
+	assert_eq!(sp(""), (0, None));
+	assert_eq!(sp(" \t\r\n  x "), (6, None));
+	assert_eq!(sp("x // y"), (0, None));
+	assert_eq!(sp("// comment\nx"), (11, None));
+	assert_eq!(sp("/* a */ /* b */ x"), (16, None));
+	assert_eq!(sp("/* a /* b */ c */x"), (13, None));
+	assert_eq!(sp("/*/ x */x"), (8, None));
+	assert_eq!(sp("/* a"), (4, None));
+}
+
+/**
+Returns the length of the leading run of whitespace and C-style comments.  If `nested` is `true`, block comments may be nested.
+*/
+fn comments_len(s: &str, nested: bool) -> uint {
+	let mut at = 0;
+
+	loop {
+		at += len_while(s.slice_from(at), |ch| ch.is_whitespace()).unwrap_or(0);
+		let rest = s.slice_from(at);
+
+		if rest.starts_with("//") {
+			at += rest.find('\n').unwrap_or(rest.len());
+		} else if rest.starts_with("/*") {
+			let mut depth = 0u;
+			let mut i = 0;
+			let b = rest.as_bytes();
+			loop {
+				if i >= b.len() {
+					break;
+				} else if b.slice_from(i).starts_with(b"/*") && (nested || depth == 0) {
+					depth += 1;
+					i += 2;
+				} else if b.slice_from(i).starts_with(b"*/") {
+					depth -= 1;
+					i += 2;
+					if depth == 0 { break; }
+				} else {
+					i += 1;
+				}
+			}
+			at += i;
+		} else {
+			return at;
+		}
+	}
+}